@@ -10,6 +10,7 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
+use futures::stream::{self, Stream, StreamExt};
 use parking_lot::Mutex;
 use prometheus::{
     Counter, Gauge, Histogram, HistogramOpts, IntCounter, IntGauge, Opts, Registry,
@@ -17,10 +18,11 @@ use prometheus::{
 use pyo3::prelude::*;
 use pyo3::types::PyModule;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock};
+use tokio_stream::wrappers::ReceiverStream;
 use tracing::{debug, error, info, warn};
 
 // ============================================================================
@@ -32,7 +34,7 @@ pub enum AgentError {
     #[error("Python runtime error: {0}")]
     PythonError(String),
 
-    #[error("Agent pool exhausted: no agents available")]
+    #[error("Agent pool exhausted: no agents available for checkout")]
     PoolExhausted,
 
     #[error("Circuit breaker open: {0}")]
@@ -82,9 +84,12 @@ pub struct CircuitBreaker {
     state: Mutex<CircuitState>,
     failure_count: Mutex<usize>,
     last_failure_time: Mutex<Option<Instant>>,
+    half_open_calls_in_flight: Mutex<usize>,
+    half_open_successes: Mutex<usize>,
     threshold: usize,
     timeout: Duration,
     half_open_max_calls: usize,
+    half_open_success_threshold: usize,
 }
 
 impl CircuitBreaker {
@@ -93,12 +98,31 @@ impl CircuitBreaker {
             state: Mutex::new(CircuitState::Closed),
             failure_count: Mutex::new(0),
             last_failure_time: Mutex::new(None),
+            half_open_calls_in_flight: Mutex::new(0),
+            half_open_successes: Mutex::new(0),
             threshold,
             timeout,
             half_open_max_calls: 3,
+            half_open_success_threshold: 1,
         }
     }
 
+    /// Cap on concurrent probe calls admitted while the breaker is
+    /// half-open (default 3). Callers beyond the cap are rejected with
+    /// `CircuitBreakerOpen` without touching the underlying operation.
+    pub fn half_open_max_calls(mut self, max_calls: usize) -> Self {
+        self.half_open_max_calls = max_calls.max(1);
+        self
+    }
+
+    /// Number of consecutive successful half-open probes required before
+    /// the breaker closes (default 1). Any probe failure while half-open
+    /// re-opens the breaker immediately, regardless of this threshold.
+    pub fn half_open_success_threshold(mut self, threshold: usize) -> Self {
+        self.half_open_success_threshold = threshold.max(1);
+        self
+    }
+
     pub fn call<F, T>(&self, operation: F) -> Result<T, AgentError>
     where
         F: FnOnce() -> Result<T, AgentError>,
@@ -114,7 +138,7 @@ impl CircuitBreaker {
                     "Circuit breaker is open".to_string(),
                 ))
             }
-            CircuitState::Closed | CircuitState::HalfOpen => {
+            CircuitState::Closed => {
                 match operation() {
                     Ok(result) => {
                         self.on_success();
@@ -126,6 +150,38 @@ impl CircuitBreaker {
                     }
                 }
             }
+            CircuitState::HalfOpen => self.call_half_open_probe(operation),
+        }
+    }
+
+    /// Admits at most `half_open_max_calls` concurrent probes while the
+    /// breaker is half-open; callers over the limit are rejected up front.
+    fn call_half_open_probe<F, T>(&self, operation: F) -> Result<T, AgentError>
+    where
+        F: FnOnce() -> Result<T, AgentError>,
+    {
+        {
+            let mut in_flight = self.half_open_calls_in_flight.lock();
+            if *in_flight >= self.half_open_max_calls {
+                return Err(AgentError::CircuitBreakerOpen(
+                    "Circuit breaker half-open probe limit reached".to_string(),
+                ));
+            }
+            *in_flight += 1;
+        }
+
+        let result = operation();
+        *self.half_open_calls_in_flight.lock() -= 1;
+
+        match result {
+            Ok(value) => {
+                self.on_success();
+                Ok(value)
+            }
+            Err(err) => {
+                self.on_failure();
+                Err(err)
+            }
         }
     }
 
@@ -138,6 +194,8 @@ impl CircuitBreaker {
                 if last_fail.elapsed() > self.timeout {
                     info!("Circuit breaker transitioning to half-open");
                     *state = CircuitState::HalfOpen;
+                    *self.half_open_successes.lock() = 0;
+                    *self.half_open_calls_in_flight.lock() = 0;
                     return Ok(());
                 }
             }
@@ -151,16 +209,24 @@ impl CircuitBreaker {
 
     fn on_success(&self) {
         let mut state = self.state.lock();
-        let mut failure_count = self.failure_count.lock();
 
         match *state {
             CircuitState::HalfOpen => {
-                info!("Circuit breaker transitioning to closed");
-                *state = CircuitState::Closed;
-                *failure_count = 0;
+                let mut successes = self.half_open_successes.lock();
+                *successes += 1;
+
+                if *successes >= self.half_open_success_threshold {
+                    info!(
+                        "Circuit breaker transitioning to closed after {} consecutive successful probe(s)",
+                        *successes
+                    );
+                    *state = CircuitState::Closed;
+                    *self.failure_count.lock() = 0;
+                    *successes = 0;
+                }
             }
             CircuitState::Closed => {
-                *failure_count = 0;
+                *self.failure_count.lock() = 0;
             }
             CircuitState::Open => {}
         }
@@ -174,7 +240,11 @@ impl CircuitBreaker {
         *failure_count += 1;
         *last_failure = Some(Instant::now());
 
-        if *failure_count >= self.threshold {
+        if *state == CircuitState::HalfOpen {
+            warn!("Circuit breaker re-opening after a failed half-open probe");
+            *state = CircuitState::Open;
+            *self.half_open_successes.lock() = 0;
+        } else if *failure_count >= self.threshold {
             warn!(
                 "Circuit breaker opening after {} failures",
                 *failure_count
@@ -192,10 +262,121 @@ impl CircuitBreaker {
         let mut failure_count = self.failure_count.lock();
         *state = CircuitState::Closed;
         *failure_count = 0;
+        *self.half_open_successes.lock() = 0;
+        *self.half_open_calls_in_flight.lock() = 0;
         info!("Circuit breaker manually reset");
     }
 }
 
+// ============================================================================
+// Retry / Backoff
+// ============================================================================
+
+/// Configuration for `retry_with_backoff`: exponential backoff between
+/// attempts, capped at `max_delay`, with optional jitter to avoid thundering
+/// herds when many callers retry at once.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: usize,
+    pub base_delay: Duration,
+    pub multiplier: f64,
+    pub max_delay: Duration,
+    /// Fraction (0.0-1.0) of the computed delay to randomize by.
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: usize) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+            jitter: 0.0,
+        }
+    }
+
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Delay to wait before retrying, for a 1-indexed attempt number.
+    fn delay_for_attempt(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1) as i32;
+        let scaled_secs = self.base_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        let capped_secs = scaled_secs.min(self.max_delay.as_secs_f64());
+
+        if self.jitter <= 0.0 {
+            return Duration::from_secs_f64(capped_secs);
+        }
+
+        let spread = capped_secs * self.jitter;
+        let random_fraction = jitter_fraction();
+        let jittered_secs = capped_secs - spread + (spread * 2.0 * random_fraction);
+        Duration::from_secs_f64(jittered_secs.max(0.0))
+    }
+}
+
+/// A cheap, non-cryptographic source of randomness for jitter, avoiding a
+/// dependency on the `rand` crate for this alone.
+fn jitter_fraction() -> f64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 1_000_000) as f64 / 1_000_000.0
+}
+
+/// Retry `op` under `policy` until it succeeds, `policy.max_attempts` is
+/// reached, or `is_retryable` rejects the error. Replaces the bespoke retry
+/// loops scattered across this service (and siblings) with one
+/// configurable, testable implementation.
+pub async fn retry_with_backoff<F, Fut, T, E>(
+    policy: RetryPolicy,
+    mut op: F,
+    is_retryable: impl Fn(&E) -> bool,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+{
+    let mut attempt = 0usize;
+    loop {
+        attempt += 1;
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                if attempt >= policy.max_attempts || !is_retryable(&err) {
+                    return Err(err);
+                }
+                let delay = policy.delay_for_attempt(attempt);
+                warn!(
+                    "Attempt {} failed: {}; retrying in {:?}",
+                    attempt, err, delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Metrics
 // ============================================================================
@@ -385,6 +566,170 @@ impl AgentMemory {
         let json = std::fs::read_to_string(path).context("Failed to read memory file")?;
         Self::from_json(&json)
     }
+
+    /// Merges `other`'s conversation history into `self`, appending only
+    /// turns whose timestamp isn't already present so re-importing the same
+    /// export doesn't duplicate history.
+    pub fn merge_from(&mut self, other: &AgentMemory) {
+        let existing: std::collections::HashSet<DateTime<Utc>> = self
+            .conversation_history
+            .iter()
+            .map(|turn| turn.timestamp)
+            .collect();
+
+        for turn in &other.conversation_history {
+            if !existing.contains(&turn.timestamp) {
+                self.conversation_history.push(turn.clone());
+            }
+        }
+
+        self.conversation_history.sort_by_key(|turn| turn.timestamp);
+        self.last_updated = self.last_updated.max(other.last_updated);
+    }
+}
+
+/// Outcome of [`ProductionAgentSystem::import_memories`]: how many records
+/// were merged in versus how many lines were corrupt and skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryImportSummary {
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+// ============================================================================
+// Memory Persistence Backends
+// ============================================================================
+
+/// Durable storage for [`AgentMemory`], abstracted so `ProductionAgentSystem`
+/// doesn't care whether conversation history lives on local disk or in a
+/// shared store. A single-replica deployment can use [`FilesystemMemoryStore`];
+/// a multi-replica deployment where any replica may serve a given user needs
+/// [`RedisMemoryStore`] (or another shared-store impl) instead, since local
+/// files aren't visible across replicas.
+#[async_trait::async_trait]
+pub trait MemoryStore: Send + Sync {
+    async fn load(&self, user_id: &str) -> Result<Option<AgentMemory>>;
+    async fn save(&self, memory: &AgentMemory) -> Result<()>;
+    async fn delete(&self, user_id: &str) -> Result<()>;
+}
+
+/// Stores one JSON file per user under `directory`, mirroring the layout
+/// `AgentMemory::save`/`load` already use.
+pub struct FilesystemMemoryStore {
+    directory: std::path::PathBuf,
+}
+
+impl FilesystemMemoryStore {
+    pub fn new(directory: impl Into<std::path::PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+
+    fn path_for(&self, user_id: &str) -> std::path::PathBuf {
+        self.directory.join(format!("{}.json", user_id))
+    }
+}
+
+#[async_trait::async_trait]
+impl MemoryStore for FilesystemMemoryStore {
+    async fn load(&self, user_id: &str) -> Result<Option<AgentMemory>> {
+        let path = self.path_for(user_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = tokio::fs::read_to_string(&path)
+            .await
+            .context("Failed to read memory file")?;
+        Ok(Some(AgentMemory::from_json(&contents)?))
+    }
+
+    async fn save(&self, memory: &AgentMemory) -> Result<()> {
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .context("Failed to create memory directory")?;
+
+        let json = memory.to_json()?;
+        tokio::fs::write(self.path_for(&memory.user_id), json)
+            .await
+            .context("Failed to write memory file")
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.path_for(user_id)).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err).context("Failed to delete memory file"),
+        }
+    }
+}
+
+/// Stores `AgentMemory` in Redis, keyed by user id, so any replica behind a
+/// load balancer can serve any user's conversation history.
+pub struct RedisMemoryStore {
+    client: redis::Client,
+}
+
+impl RedisMemoryStore {
+    pub fn new(redis_url: &str) -> Result<Self> {
+        let client = redis::Client::open(redis_url).context("Failed to create Redis client")?;
+        Ok(Self { client })
+    }
+
+    fn key_for(user_id: &str) -> String {
+        format!("agent:memory:{}", user_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl MemoryStore for RedisMemoryStore {
+    async fn load(&self, user_id: &str) -> Result<Option<AgentMemory>> {
+        use redis::AsyncCommands;
+
+        let mut conn = self
+            .client
+            .get_connection_manager()
+            .await
+            .context("Failed to get Redis connection")?;
+
+        let json: Option<String> = conn
+            .get(Self::key_for(user_id))
+            .await
+            .context("Redis GET failed")?;
+
+        match json {
+            Some(json) => Ok(Some(AgentMemory::from_json(&json)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save(&self, memory: &AgentMemory) -> Result<()> {
+        use redis::AsyncCommands;
+
+        let mut conn = self
+            .client
+            .get_connection_manager()
+            .await
+            .context("Failed to get Redis connection")?;
+
+        let json = memory.to_json()?;
+        conn.set::<_, _, ()>(Self::key_for(&memory.user_id), json)
+            .await
+            .context("Redis SET failed")
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<()> {
+        use redis::AsyncCommands;
+
+        let mut conn = self
+            .client
+            .get_connection_manager()
+            .await
+            .context("Failed to get Redis connection")?;
+
+        conn.del::<_, ()>(Self::key_for(user_id))
+            .await
+            .context("Redis DEL failed")
+    }
 }
 
 // ============================================================================
@@ -430,18 +775,125 @@ impl Default for ToolRegistry {
     }
 }
 
+// ============================================================================
+// Agent Pool
+// ============================================================================
+
+/// A fixed set of interpreter-bound agents with exclusive checkout, so two
+/// requests in flight at once are never handed the same underlying
+/// `Py<PyAny>` and able to call `forward` on it concurrently from different
+/// threads. Replaces a round-robin `agent_index % pool.len()` scheme that
+/// only returned `AgentError::PoolExhausted` when the pool was literally
+/// empty, not when every agent was already in use.
+struct AgentPool {
+    agents: RwLock<Vec<Py<PyAny>>>,
+    /// Indices into `agents` that are not currently checked out.
+    available: Mutex<VecDeque<usize>>,
+}
+
+impl AgentPool {
+    fn new(agents: Vec<Py<PyAny>>) -> Self {
+        let available = (0..agents.len()).collect();
+        Self {
+            agents: RwLock::new(agents),
+            available: Mutex::new(available),
+        }
+    }
+
+    /// Total number of agents in the pool, checked out or not.
+    async fn len(&self) -> usize {
+        self.agents.read().await.len()
+    }
+
+    /// Same as `len`, for callers (like `health_check`) that can't `.await`.
+    fn blocking_len(&self) -> usize {
+        // `blocking_read` panics when called from inside an async context
+        // (as the HTTP health handler does), so block on the read future
+        // with a runtime-agnostic executor instead.
+        futures::executor::block_on(self.agents.read()).len()
+    }
+
+    /// Add a newly-warmed agent (see `spawn_pool_growth`) and make it
+    /// immediately available for checkout. Returns the new pool size.
+    async fn push(&self, agent: Py<PyAny>) -> usize {
+        let mut agents = self.agents.write().await;
+        agents.push(agent);
+        let index = agents.len() - 1;
+        self.available.lock().push_back(index);
+        agents.len()
+    }
+
+    /// Check out an agent not currently in use by anyone else. Returns
+    /// `None` if every agent is already checked out (including when the
+    /// pool is empty) - callers map that to `AgentError::PoolExhausted`.
+    fn try_checkout(self: &Arc<Self>) -> Option<AgentCheckout> {
+        let index = self.available.lock().pop_front()?;
+        Some(AgentCheckout { pool: Arc::clone(self), index })
+    }
+
+    /// Same as `push`, for tests that build a pool outside `new`/
+    /// `new_with_memory_store` and can't `.await` (plain `#[test]` fns).
+    #[cfg(test)]
+    fn push_for_test(&self, agent: Py<PyAny>) {
+        let mut agents = self.agents.blocking_write();
+        let index = agents.len();
+        agents.push(agent);
+        self.available.lock().push_back(index);
+    }
+}
+
+/// Holds exclusive use of one agent until dropped, at which point its index
+/// goes back on `AgentPool::available`. Covers the success, error, and
+/// panic paths the same way - `forward` runs entirely inside
+/// `spawn_blocking`, where a panic would otherwise leak the slot forever.
+struct AgentCheckout {
+    pool: Arc<AgentPool>,
+    index: usize,
+}
+
+impl AgentCheckout {
+    /// Borrow the checked-out agent. `index` came from `available`, which
+    /// only ever holds indices of agents already pushed into `pool.agents`,
+    /// so the lookup can't be out of range.
+    fn agent(&self) -> Py<PyAny> {
+        self.pool.agents.blocking_read()[self.index].clone()
+    }
+}
+
+impl Drop for AgentCheckout {
+    fn drop(&mut self) {
+        self.pool.available.lock().push_back(self.index);
+    }
+}
+
 // ============================================================================
 // Production Agent System
 // ============================================================================
 
 pub struct ProductionAgentSystem {
-    agent_pool: Arc<RwLock<Vec<Py<PyAny>>>>,
+    agent_pool: Arc<AgentPool>,
+    /// Write-through cache in front of `memory_backend`: reads hit this map
+    /// first and only fall through to the backend on a miss, and
+    /// `execute_query`'s writes update both. The cache is per process: an
+    /// entry already cached here is not refreshed when another replica
+    /// writes to the backend, and `import_memories` writes only this map.
     memory_store: Arc<DashMap<String, AgentMemory>>,
+    memory_backend: Box<dyn MemoryStore>,
+    /// Per-`user_id` locks so concurrent requests for the same user
+    /// serialize their read-augment-execute-update cycle instead of racing
+    /// on `memory_store` and corrupting conversation history.
+    user_locks: Arc<DashMap<String, Arc<tokio::sync::Mutex<()>>>>,
     circuit_breaker: Arc<CircuitBreaker>,
     metrics: Arc<AgentMetrics>,
     tool_registry: Arc<Mutex<ToolRegistry>>,
     config: AgentConfig,
     start_time: Instant,
+    /// Set while a background task spawned by `create_agent_pool` is still
+    /// growing `agent_pool` up to `config.pool_size`; cleared once it
+    /// reaches that size (or gives up after a construction failure).
+    /// Surfaced by `health_check` so a service that's serving from a
+    /// still-growing pool doesn't look identical to a fully warmed one.
+    pool_warming: Arc<std::sync::atomic::AtomicBool>,
 }
 
 #[derive(Debug, Clone)]
@@ -451,7 +903,13 @@ pub struct AgentConfig {
     pub request_timeout: Duration,
     pub circuit_breaker_threshold: usize,
     pub circuit_breaker_timeout: Duration,
+    pub circuit_breaker_half_open_max_calls: usize,
+    pub circuit_breaker_half_open_success_threshold: usize,
     pub memory_context_turns: usize,
+    /// Directory used by the default `FilesystemMemoryStore` when
+    /// `ProductionAgentSystem::new` is called directly. Ignored if the
+    /// system was built with `new_with_memory_store` and a different backend.
+    pub memory_directory: String,
 }
 
 impl Default for AgentConfig {
@@ -462,7 +920,10 @@ impl Default for AgentConfig {
             request_timeout: Duration::from_secs(30),
             circuit_breaker_threshold: 5,
             circuit_breaker_timeout: Duration::from_secs(30),
+            circuit_breaker_half_open_max_calls: 3,
+            circuit_breaker_half_open_success_threshold: 1,
             memory_context_turns: 3,
+            memory_directory: "./agent_memories".to_string(),
         }
     }
 }
@@ -471,6 +932,11 @@ impl Default for AgentConfig {
 pub struct QueryRequest {
     pub user_id: String,
     pub question: String,
+    /// Extract the ReAct agent's step-by-step thoughts/actions/observations
+    /// and return them on `QueryResponse::trajectory`. Off by default since
+    /// most callers only need the final answer and extraction adds overhead.
+    #[serde(default)]
+    pub capture_trajectory: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -481,11 +947,108 @@ pub struct QueryResponse {
     pub reasoning_steps: usize,
     pub latency_ms: u128,
     pub request_id: String,
+    /// Present only when `QueryRequest::capture_trajectory` was set.
+    pub trajectory: Option<Vec<TrajectoryStep>>,
+}
+
+/// One step of a ReAct agent's trajectory, as captured from the Python
+/// `Prediction`'s `trajectory` dict (keys like `thought_0`, `tool_name_0`,
+/// `tool_args_0`, `observation_0`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrajectoryStep {
+    pub step: usize,
+    pub thought: Option<String>,
+    pub tool_name: Option<String>,
+    pub tool_args: Option<String>,
+    pub observation: Option<String>,
+}
+
+/// Observations beyond this size are truncated before being returned, so a
+/// verbose tool (e.g. a search result dump) can't bloat the response.
+const MAX_TRAJECTORY_OBSERVATION_BYTES: usize = 4096;
+
+/// Truncate `observation` to `MAX_TRAJECTORY_OBSERVATION_BYTES`, appending a
+/// marker noting how much was cut, if it exceeds the cap.
+fn redact_observation(observation: String) -> String {
+    if observation.len() <= MAX_TRAJECTORY_OBSERVATION_BYTES {
+        return observation;
+    }
+
+    let total_len = observation.len();
+    let mut truncated = observation;
+    truncated.truncate(MAX_TRAJECTORY_OBSERVATION_BYTES);
+    truncated.push_str(&format!(
+        "...[redacted, {} bytes total]",
+        total_len
+    ));
+    truncated
+}
+
+/// Extract the step-by-step trajectory from a ReAct `Prediction` object's
+/// `trajectory` dict. Returns `None` (rather than an error) if the
+/// prediction has no `trajectory` attribute, since not every agent
+/// signature produces one.
+fn extract_trajectory(prediction: &PyAny) -> Option<Vec<TrajectoryStep>> {
+    let trajectory = prediction.getattr("trajectory").ok()?;
+    let trajectory: HashMap<String, String> = trajectory.extract().ok()?;
+
+    let mut steps = Vec::new();
+    for step in 0.. {
+        let thought = trajectory.get(&format!("thought_{step}")).cloned();
+        let tool_name = trajectory.get(&format!("tool_name_{step}")).cloned();
+        let tool_args = trajectory.get(&format!("tool_args_{step}")).cloned();
+        let observation = trajectory
+            .get(&format!("observation_{step}"))
+            .cloned()
+            .map(redact_observation);
+
+        if thought.is_none() && tool_name.is_none() && tool_args.is_none() && observation.is_none() {
+            break;
+        }
+
+        steps.push(TrajectoryStep {
+            step,
+            thought,
+            tool_name,
+            tool_args,
+            observation,
+        });
+    }
+
+    Some(steps)
+}
+
+/// Events emitted by [`ProductionAgentSystem::execute_query_streaming`],
+/// mirroring the `StreamEvent` lifecycle used by the `token-streaming`
+/// example: chunks arrive as they're generated, then exactly one terminal
+/// event (`Done` or `Error`) closes the stream.
+#[derive(Debug, Clone)]
+pub enum QueryStreamEvent {
+    /// A chunk of the answer, in generation order.
+    Chunk(String),
+    /// The stream completed successfully. Carries the same metadata
+    /// `execute_query` returns in `QueryResponse`.
+    Done { reasoning_steps: usize, latency_ms: u128 },
+    /// The stream failed partway through; no further events follow.
+    Error(String),
 }
 
 impl ProductionAgentSystem {
-    /// Create a new production agent system with the given configuration
+    /// Create a new production agent system with the given configuration,
+    /// persisting memory to a [`FilesystemMemoryStore`] rooted at
+    /// `config.memory_directory`. Use [`Self::new_with_memory_store`] for a
+    /// shared backend (e.g. Redis) in multi-replica deployments.
     pub async fn new(config: AgentConfig) -> Result<Self> {
+        let memory_backend = Box::new(FilesystemMemoryStore::new(config.memory_directory.clone()));
+        Self::new_with_memory_store(config, memory_backend).await
+    }
+
+    /// Create a new production agent system backed by an arbitrary
+    /// [`MemoryStore`] implementation.
+    pub async fn new_with_memory_store(
+        config: AgentConfig,
+        memory_backend: Box<dyn MemoryStore>,
+    ) -> Result<Self> {
         info!("Initializing ProductionAgentSystem with pool_size={}", config.pool_size);
 
         // Initialize tool registry
@@ -493,46 +1056,124 @@ impl ProductionAgentSystem {
         Self::register_default_tools(&mut tool_registry);
         let tool_registry = Arc::new(Mutex::new(tool_registry));
 
-        // Initialize agent pool
-        let agent_pool = Self::create_agent_pool(config.pool_size).await?;
+        // Initialize agent pool: `create_agent_pool` only builds the first
+        // agent synchronously so `new` can return promptly, then hands back
+        // the remaining count so the rest can warm in the background below.
+        let (initial_pool, remaining) = Self::create_agent_pool(config.pool_size).await?;
+        let agent_pool = Arc::new(AgentPool::new(initial_pool));
 
         // Initialize circuit breaker
-        let circuit_breaker = Arc::new(CircuitBreaker::new(
-            config.circuit_breaker_threshold,
-            config.circuit_breaker_timeout,
-        ));
+        let circuit_breaker = Arc::new(
+            CircuitBreaker::new(config.circuit_breaker_threshold, config.circuit_breaker_timeout)
+                .half_open_max_calls(config.circuit_breaker_half_open_max_calls)
+                .half_open_success_threshold(config.circuit_breaker_half_open_success_threshold),
+        );
 
         // Initialize metrics
         let metrics = Arc::new(AgentMetrics::new()?);
-        metrics.update_pool_size(config.pool_size);
+        metrics.update_pool_size(agent_pool.len().await);
         metrics.update_circuit_breaker_state(CircuitState::Closed);
 
+        let pool_warming = Arc::new(std::sync::atomic::AtomicBool::new(remaining > 0));
+        if remaining > 0 {
+            Self::spawn_pool_growth(
+                agent_pool.clone(),
+                metrics.clone(),
+                pool_warming.clone(),
+                config.pool_size,
+            );
+        }
+
         Ok(Self {
-            agent_pool: Arc::new(RwLock::new(agent_pool)),
+            agent_pool,
             memory_store: Arc::new(DashMap::new()),
+            memory_backend,
+            user_locks: Arc::new(DashMap::new()),
             circuit_breaker,
             metrics,
             tool_registry,
             config,
             start_time: Instant::now(),
+            pool_warming,
         })
     }
 
-    /// Create a pool of pre-initialized agents
-    async fn create_agent_pool(pool_size: usize) -> Result<Vec<Py<PyAny>>> {
-        let mut pool = Vec::with_capacity(pool_size);
+    /// Lock serializing the memory read-execute-update cycle for a single
+    /// `user_id`, created lazily on first use.
+    fn user_lock(&self, user_id: &str) -> Arc<tokio::sync::Mutex<()>> {
+        self.user_locks
+            .entry(user_id.to_string())
+            .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(())))
+            .clone()
+    }
+
+    /// Build the first agent of the pool synchronously and report how many
+    /// more are still needed, so `new`/`new_with_memory_store` can return
+    /// (and the service can start serving) after just one agent is ready
+    /// instead of waiting on the whole `pool_size` under one held GIL — for
+    /// `pool_size=16` that used to add seconds to startup. The caller is
+    /// expected to warm the rest in the background via
+    /// [`Self::spawn_pool_growth`].
+    async fn create_agent_pool(pool_size: usize) -> Result<(Vec<Py<PyAny>>, usize)> {
+        if pool_size == 0 {
+            return Ok((Vec::new(), 0));
+        }
+
+        debug!("Creating first agent of {} synchronously", pool_size);
+        let first = Self::create_agent_blocking().await?;
+        info!(
+            "Pool ready to serve with 1/{} agents; remaining {} will warm in the background",
+            pool_size,
+            pool_size - 1
+        );
+        Ok((vec![first], pool_size - 1))
+    }
+
+    /// Construct a single agent on a blocking thread, so the GIL is only
+    /// held for the `dspy.ReAct(...)` call itself rather than for whatever
+    /// else is happening on the calling async task.
+    async fn create_agent_blocking() -> Result<Py<PyAny>> {
+        tokio::task::spawn_blocking(|| Python::with_gil(Self::create_agent))
+            .await
+            .context("agent construction task panicked")?
+            .map_err(Into::into)
+    }
+
+    /// Grow `agent_pool` from its current size up to `target_pool_size` on a
+    /// detached background task, building each agent on its own blocking
+    /// thread (see [`Self::create_agent_blocking`]) rather than holding the
+    /// GIL for the whole pool in one critical section. Runs independently of
+    /// callers so `new`/`new_with_memory_store` is never blocked on it;
+    /// `pool_warming` is cleared once the target is reached or the task
+    /// gives up after a construction failure.
+    fn spawn_pool_growth(
+        agent_pool: Arc<AgentPool>,
+        metrics: Arc<AgentMetrics>,
+        pool_warming: Arc<std::sync::atomic::AtomicBool>,
+        target_pool_size: usize,
+    ) {
+        tokio::spawn(async move {
+            loop {
+                if agent_pool.len().await >= target_pool_size {
+                    break;
+                }
 
-        Python::with_gil(|py| -> PyResult<()> {
-            for i in 0..pool_size {
-                debug!("Creating agent {}/{}", i + 1, pool_size);
-                let agent = Self::create_agent(py)?;
-                pool.push(agent);
+                match Self::create_agent_blocking().await {
+                    Ok(agent) => {
+                        let size = agent_pool.push(agent).await;
+                        metrics.update_pool_size(size);
+                        debug!("Warmed agent {}/{}", size, target_pool_size);
+                    }
+                    Err(err) => {
+                        warn!("Stopping agent pool warm-up early after a failure: {}", err);
+                        break;
+                    }
+                }
             }
-            Ok(())
-        })?;
 
-        info!("Created agent pool with {} agents", pool_size);
-        Ok(pool)
+            pool_warming.store(false, std::sync::atomic::Ordering::SeqCst);
+            info!("Agent pool warm-up finished");
+        });
     }
 
     /// Create a single DSPy ReAct agent
@@ -569,131 +1210,326 @@ impl ProductionAgentSystem {
 
         debug!("Processing request {} for user {}", request_id, request.user_id);
 
-        // Get or create memory
-        let memory = self.memory_store
-            .entry(request.user_id.clone())
-            .or_insert_with(|| AgentMemory::new(request.user_id.clone()))
-            .clone();
+        // Hold this user's lock across the whole read-augment-execute-update
+        // cycle so a concurrent request for the same user_id (see
+        // `execute_queries`) can't read memory before this one writes back,
+        // which would silently drop a turn from the conversation history.
+        let user_lock = self.user_lock(&request.user_id);
+        let _user_guard = user_lock.lock().await;
+
+        // Get memory: the in-memory cache serves most requests, but on a
+        // cache miss (this replica's first request for the user, or after a
+        // restart) fall through to the durable backend and backfill the
+        // cache so later requests don't repeat the round trip.
+        let memory = match self.memory_store.get(&request.user_id) {
+            Some(entry) => entry.clone(),
+            None => {
+                let loaded = self.memory_backend.load(&request.user_id).await?;
+                let memory = loaded.unwrap_or_else(|| AgentMemory::new(request.user_id.clone()));
+                self.memory_store.insert(request.user_id.clone(), memory.clone());
+                memory
+            }
+        };
 
         // Execute with circuit breaker and retry logic
-        let mut last_error = None;
-        for attempt in 1..=self.config.max_retries {
-            match self.execute_with_circuit_breaker(&request, &memory).await {
-                Ok(answer) => {
-                    let latency = start.elapsed();
-                    let reasoning_steps = 3; // This would be extracted from agent response
-
-                    // Update memory
-                    self.memory_store
-                        .get_mut(&request.user_id)
-                        .map(|mut mem| {
-                            mem.add_turn(
-                                request.question.clone(),
-                                answer.clone(),
-                                reasoning_steps,
-                            );
-                        });
-
-                    // Record metrics
-                    self.metrics.record_request(latency, true, reasoning_steps);
+        let policy = RetryPolicy::new(self.config.max_retries).base_delay(Duration::from_millis(100));
+
+        match retry_with_backoff(
+            policy,
+            || self.execute_with_circuit_breaker(&request, &memory),
+            |_: &AgentError| true,
+        )
+        .await
+        {
+            Ok((answer, trajectory)) => {
+                let latency = start.elapsed();
+                let reasoning_steps = 3; // This would be extracted from agent response
+
+                // Update memory: write through to the cache, then persist
+                // the updated snapshot to the durable backend outside the
+                // DashMap guard (holding one across an `.await` isn't safe).
+                let updated_memory = self.memory_store.get_mut(&request.user_id).map(|mut mem| {
+                    mem.add_turn(request.question.clone(), answer.clone(), reasoning_steps);
+                    mem.clone()
+                });
+
+                if let Some(memory) = updated_memory {
+                    if let Err(err) = self.memory_backend.save(&memory).await {
+                        warn!(
+                            "Failed to persist memory for user {} to backend: {}",
+                            request.user_id, err
+                        );
+                    }
+                }
 
-                    info!(
-                        "Request {} completed in {:?} (attempt {})",
-                        request_id, latency, attempt
-                    );
+                // Record metrics
+                self.metrics.record_request(latency, true, reasoning_steps);
+
+                info!("Request {} completed in {:?}", request_id, latency);
+
+                Ok(QueryResponse {
+                    answer,
+                    user_id: request.user_id,
+                    question: request.question,
+                    reasoning_steps,
+                    latency_ms: latency.as_millis(),
+                    request_id,
+                    trajectory,
+                })
+            }
+            Err(err) => {
+                let latency = start.elapsed();
+                self.metrics.record_request(latency, false, 0);
 
-                    return Ok(QueryResponse {
-                        answer,
-                        user_id: request.user_id,
-                        question: request.question,
+                error!("Request {} failed after {} retries", request_id, self.config.max_retries);
+
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Execute many queries concurrently, up to `config.pool_size` at a
+    /// time so each in-flight query gets a distinct pooled agent. Each
+    /// query still gets its own circuit-breaker and retry handling via
+    /// `execute_query`; per-`user_id` memory updates are serialized by
+    /// `user_lock` regardless of how many requests for that user are in
+    /// this batch. The output preserves `requests`' order, not completion
+    /// order.
+    pub async fn execute_queries(&self, requests: Vec<QueryRequest>) -> Vec<Result<QueryResponse>> {
+        let buffer_size = self.config.pool_size.max(1);
+
+        let mut results: Vec<(usize, Result<QueryResponse>)> = stream::iter(requests.into_iter().enumerate())
+            .map(|(index, request)| async move { (index, self.execute_query(request).await) })
+            .buffer_unordered(buffer_size)
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Stream an agent's answer chunk by chunk as DSPy generates it, instead
+    /// of waiting for the full response like `execute_query` does.
+    ///
+    /// # GIL and threading
+    ///
+    /// The Python call (and the GIL it holds) runs entirely on a
+    /// `spawn_blocking` thread, same as `execute_with_circuit_breaker`. Each
+    /// chunk the Python generator yields is forwarded through a bounded
+    /// `tokio::sync::mpsc` channel to the returned `Stream`; `blocking_send`
+    /// provides backpressure, so a slow consumer pauses the Python-side
+    /// generator rather than buffering unboundedly. The GIL is never held
+    /// across an `.await` — the blocking thread holds it only while pulling
+    /// the next chunk, and releases it between sends.
+    ///
+    /// Like `execute_query`, this reads the user's cached conversation
+    /// memory to prepend context to the question. Unlike `execute_query`, it
+    /// does not update memory: streamed answers aren't assembled into a
+    /// single string here, so there's nothing to hand to
+    /// `AgentMemory::add_turn`. Callers that need memory updated should
+    /// concatenate `QueryStreamEvent::Chunk` payloads themselves and do the
+    /// `execute_query`-style bookkeeping.
+    pub fn execute_query_streaming(
+        &self,
+        request: QueryRequest,
+    ) -> impl Stream<Item = QueryStreamEvent> {
+        let (tx, rx) = mpsc::channel(32);
+
+        let agent_pool = Arc::clone(&self.agent_pool);
+        let memory_store = Arc::clone(&self.memory_store);
+        let memory_context_turns = self.config.memory_context_turns;
+
+        tokio::task::spawn_blocking(move || {
+            let start = Instant::now();
+
+            let memory = memory_store
+                .get(&request.user_id)
+                .map(|entry| entry.clone())
+                .unwrap_or_else(|| AgentMemory::new(request.user_id.clone()));
+
+            let outcome = (|| -> PyResult<usize> {
+                let checkout = agent_pool.try_checkout().ok_or_else(|| {
+                    pyo3::exceptions::PyRuntimeError::new_err("agent pool exhausted")
+                })?;
+
+                Python::with_gil(|py| -> PyResult<usize> {
+                    let agent = checkout.agent();
+
+                    let context = memory.get_context(memory_context_turns);
+                    let augmented_question = if context.is_empty() {
+                        request.question.clone()
+                    } else {
+                        format!("{}\n\nCurrent Question: {}", context, request.question)
+                    };
+
+                    debug!("Streaming agent response for question: {}", augmented_question);
+
+                    let result = agent
+                        .as_ref(py)
+                        .call_method1("forward", ((augmented_question,),))?;
+
+                    // DSPy predictions expose a `stream` attribute when the
+                    // underlying LM supports token streaming; fall back to the
+                    // full answer as a single chunk otherwise.
+                    if let Ok(stream_attr) = result.getattr("stream") {
+                        if let Ok(stream_iter) = stream_attr.iter() {
+                            let mut chunks = 0usize;
+                            for chunk_result in stream_iter {
+                                let chunk: String = chunk_result?.extract()?;
+                                chunks += 1;
+                                let sent = py.allow_threads(|| {
+                                    tx.blocking_send(QueryStreamEvent::Chunk(chunk))
+                                });
+                                if sent.is_err() {
+                                    // Receiver dropped; stop pulling from Python.
+                                    return Ok(chunks);
+                                }
+                            }
+                            return Ok(chunks);
+                        }
+                    }
+
+                    let answer: String = result.getattr("answer")?.extract()?;
+                    let _ = py.allow_threads(|| tx.blocking_send(QueryStreamEvent::Chunk(answer)));
+                    Ok(1)
+                })
+            })();
+
+            match outcome {
+                Ok(reasoning_steps) => {
+                    let _ = tx.blocking_send(QueryStreamEvent::Done {
                         reasoning_steps,
-                        latency_ms: latency.as_millis(),
-                        request_id,
+                        latency_ms: start.elapsed().as_millis(),
                     });
                 }
                 Err(err) => {
-                    warn!("Request {} failed on attempt {}: {}", request_id, attempt, err);
-                    last_error = Some(err);
-
-                    if attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * attempt as u64)).await;
-                    }
+                    let _ = tx.blocking_send(QueryStreamEvent::Error(err.to_string()));
                 }
             }
-        }
-
-        // All retries failed
-        let latency = start.elapsed();
-        self.metrics.record_request(latency, false, 0);
-
-        error!("Request {} failed after {} retries", request_id, self.config.max_retries);
+        });
 
-        Err(last_error.unwrap_or_else(|| {
-            AgentError::ExecutionFailed("All retries exhausted".to_string()).into()
-        }))
+        ReceiverStream::new(rx)
     }
 
-    /// Execute query with circuit breaker protection
+    /// Execute query with circuit breaker protection and a request deadline.
+    ///
+    /// The GIL-bound agent call runs on a blocking-pool thread via
+    /// `spawn_blocking`, and the whole thing is raced against
+    /// `config.request_timeout`. If the deadline elapses first, this returns
+    /// `AgentError::Timeout` and counts the attempt as a circuit breaker
+    /// failure directly (the spawned blocking task itself is not cancelled —
+    /// Python calls can't be interrupted from outside — but the async runtime
+    /// is no longer pinned waiting on it).
     async fn execute_with_circuit_breaker(
         &self,
         request: &QueryRequest,
         memory: &AgentMemory,
-    ) -> Result<String, AgentError> {
+    ) -> Result<(String, Option<Vec<TrajectoryStep>>), AgentError> {
         // Update circuit breaker state in metrics
         self.metrics.update_circuit_breaker_state(self.circuit_breaker.state());
 
-        self.circuit_breaker.call(|| {
-            Python::with_gil(|py| -> Result<String, AgentError> {
-                // Get agent from pool
-                let pool = self.agent_pool.blocking_read();
-                let agent = pool.first()
-                    .ok_or(AgentError::PoolExhausted)?;
-
-                // Augment question with context
-                let context = memory.get_context(self.config.memory_context_turns);
-                let augmented_question = if context.is_empty() {
-                    request.question.clone()
-                } else {
-                    format!("{}\n\nCurrent Question: {}", context, request.question)
-                };
-
-                debug!("Executing agent with question: {}", augmented_question);
-
-                // Execute agent
-                let result = agent.as_ref(py)
-                    .call_method1("forward", ((augmented_question,),))
-                    .map_err(|e| AgentError::PythonError(e.to_string()))?;
-
-                let answer: String = result.getattr("answer")
-                    .and_then(|a| a.extract())
-                    .map_err(|e| AgentError::PythonError(e.to_string()))?;
-
-                Ok(answer)
+        let circuit_breaker = Arc::clone(&self.circuit_breaker);
+        let agent_pool = Arc::clone(&self.agent_pool);
+        let memory_context_turns = self.config.memory_context_turns;
+        let request = request.clone();
+        let memory = memory.clone();
+
+        let task = tokio::task::spawn_blocking(move || {
+            circuit_breaker.call(|| {
+                // Check out a distinct agent from the pool and hold it
+                // exclusively until this call returns, so no other in-flight
+                // request can be handed the same underlying Python object.
+                // Every agent already checked out (not just an empty pool)
+                // surfaces as `PoolExhausted` here.
+                let checkout = agent_pool.try_checkout().ok_or(AgentError::PoolExhausted)?;
+
+                Python::with_gil(|py| -> Result<(String, Option<Vec<TrajectoryStep>>), AgentError> {
+                    let agent = checkout.agent();
+
+                    // Augment question with context
+                    let context = memory.get_context(memory_context_turns);
+                    let augmented_question = if context.is_empty() {
+                        request.question.clone()
+                    } else {
+                        format!("{}\n\nCurrent Question: {}", context, request.question)
+                    };
+
+                    debug!("Executing agent with question: {}", augmented_question);
+
+                    // Execute agent
+                    let result = agent.as_ref(py)
+                        .call_method1("forward", ((augmented_question,),))
+                        .map_err(|e| AgentError::PythonError(e.to_string()))?;
+
+                    let answer: String = result.getattr("answer")
+                        .and_then(|a| a.extract())
+                        .map_err(|e| AgentError::PythonError(e.to_string()))?;
+
+                    let trajectory = if request.capture_trajectory {
+                        extract_trajectory(result)
+                    } else {
+                        None
+                    };
+
+                    Ok((answer, trajectory))
+                })
             })
-        })
+        });
+
+        match tokio::time::timeout(self.config.request_timeout, task).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(join_err)) => {
+                Err(AgentError::ExecutionFailed(format!("Agent task panicked: {}", join_err)))
+            }
+            Err(_) => {
+                warn!("Agent request exceeded timeout of {:?}", self.config.request_timeout);
+                self.circuit_breaker.on_failure();
+                Err(AgentError::Timeout(self.config.request_timeout))
+            }
+        }
     }
 
-    /// Get system health status
+    /// Get system health status.
+    ///
+    /// Actually probes the Python runtime (see [`probe_python_runtime`])
+    /// rather than assuming it's fine, so a broken interpreter shows up as
+    /// `unhealthy` instead of being masked by `python_runtime: ok`.
     pub fn health_check(&self) -> HealthStatus {
-        let pool_size = self.agent_pool.blocking_read().len();
+        let pool_size = self.agent_pool.blocking_len();
+        let pool_warming = self.pool_warming.load(std::sync::atomic::Ordering::SeqCst);
         let circuit_state = self.circuit_breaker.state();
         let uptime = self.start_time.elapsed();
 
         let mut checks = HashMap::new();
-        checks.insert("python_runtime".to_string(), "ok".to_string());
 
-        if pool_size > 0 {
-            checks.insert("agent_pool".to_string(), "ok".to_string());
-        } else {
-            checks.insert("agent_pool".to_string(), "failed: no agents available".to_string());
-        }
+        let python_ok = match probe_python_runtime() {
+            Ok(()) => {
+                checks.insert("python_runtime".to_string(), "ok".to_string());
+                true
+            }
+            Err(err) => {
+                checks.insert("python_runtime".to_string(), format!("failed: {}", err));
+                false
+            }
+        };
+
+        checks.insert(
+            "agent_pool".to_string(),
+            if pool_size == 0 {
+                "failed: no agents available".to_string()
+            } else if pool_warming {
+                format!("warming ({}/{})", pool_size, self.config.pool_size)
+            } else {
+                "ok".to_string()
+            },
+        );
 
         checks.insert(
             "circuit_breaker".to_string(),
             format!("{:?}", circuit_state).to_lowercase(),
         );
 
-        let status = if pool_size > 0 && circuit_state == CircuitState::Closed {
+        let status = if pool_size > 0 && circuit_state == CircuitState::Closed && python_ok {
             "healthy"
         } else {
             "unhealthy"
@@ -704,11 +1540,34 @@ impl ProductionAgentSystem {
             version: env!("CARGO_PKG_VERSION").to_string(),
             uptime_seconds: uptime.as_secs(),
             agent_pool_size: pool_size,
+            pool_warming,
             circuit_breaker_state: format!("{:?}", circuit_state).to_lowercase(),
             checks,
         }
     }
 
+    /// Liveness: is the process itself alive and able to respond at all.
+    /// Deliberately does not probe the Python runtime or agent pool — a
+    /// liveness probe that can fail on backend trouble causes Kubernetes to
+    /// restart a pod that a simple backend retry would have fixed, so this
+    /// must stay trivial and always succeed once the process is up.
+    pub fn liveness(&self) -> bool {
+        true
+    }
+
+    /// Readiness: can this instance actually serve a query right now.
+    /// Requires a non-empty agent pool and a working Python runtime; the
+    /// runtime probe is time-boxed (see [`probe_python_runtime`]) so a
+    /// stuck interpreter fails the probe instead of hanging it.
+    pub fn readiness(&self) -> Result<(), String> {
+        let pool_size = self.agent_pool.blocking_len();
+        if pool_size == 0 {
+            return Err("no agents available".to_string());
+        }
+
+        probe_python_runtime()
+    }
+
     /// Get Prometheus metrics
     pub fn get_metrics(&self) -> String {
         use prometheus::Encoder;
@@ -736,9 +1595,85 @@ impl ProductionAgentSystem {
         Ok(())
     }
 
+    /// Export all in-memory conversation histories to a single JSONL file
+    /// (one [`AgentMemory`] per line), which is easier to ship between
+    /// environments than the one-file-per-user layout `save_all_memories` uses.
+    pub async fn export_memories(&self, path: &str) -> Result<()> {
+        use std::io::Write;
+
+        let mut file = std::fs::File::create(path).context("Failed to create memory export file")?;
+        for entry in self.memory_store.iter() {
+            let line = entry.value().to_json()?;
+            writeln!(file, "{}", line).context("Failed to write memory export line")?;
+        }
+
+        info!("Exported {} memories to {}", self.memory_store.len(), path);
+        Ok(())
+    }
+
+    /// Import conversation histories from a JSONL file written by
+    /// [`export_memories`](Self::export_memories). Records are merged into
+    /// the existing `memory_store` by `user_id` (see
+    /// [`AgentMemory::merge_from`]) rather than clobbering it, so importing
+    /// into an environment that already has history for a user just fills
+    /// in the gaps. Lines that fail to parse are skipped with a warning and
+    /// counted separately rather than aborting the whole import.
+    pub async fn import_memories(&self, path: &str) -> Result<MemoryImportSummary> {
+        let contents = std::fs::read_to_string(path).context("Failed to read memory import file")?;
+
+        let mut summary = MemoryImportSummary { imported: 0, skipped: 0 };
+
+        for (line_number, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match AgentMemory::from_json(line) {
+                Ok(memory) => {
+                    self.memory_store
+                        .entry(memory.user_id.clone())
+                        .and_modify(|existing| existing.merge_from(&memory))
+                        .or_insert(memory);
+                    summary.imported += 1;
+                }
+                Err(err) => {
+                    warn!(
+                        "Skipping corrupt memory record at {}:{}: {}",
+                        path,
+                        line_number + 1,
+                        err
+                    );
+                    summary.skipped += 1;
+                }
+            }
+        }
+
+        info!(
+            "Imported {} memories from {} ({} skipped)",
+            summary.imported, path, summary.skipped
+        );
+        Ok(summary)
+    }
+
     /// Get agent pool size
     pub async fn pool_size(&self) -> usize {
-        self.agent_pool.read().await.len()
+        self.agent_pool.len().await
+    }
+
+    /// Returns `user_id`'s conversation context (`AgentMemory::get_context`,
+    /// using `config.memory_context_turns`) as of the latest committed
+    /// `execute_query` call for that user. Takes the same per-user lock
+    /// `execute_query` holds across its read-augment-execute-update cycle,
+    /// so a concurrent call for the same user can't observe a half-written
+    /// turn - only the state from before or after a write, never a race.
+    pub async fn memory_context_turns(&self, user_id: &str) -> String {
+        let user_lock = self.user_lock(user_id);
+        let _user_guard = user_lock.lock().await;
+
+        self.memory_store
+            .get(user_id)
+            .map(|entry| entry.get_context(self.config.memory_context_turns))
+            .unwrap_or_default()
     }
 
     /// Reset circuit breaker
@@ -758,10 +1693,42 @@ pub struct HealthStatus {
     pub version: String,
     pub uptime_seconds: u64,
     pub agent_pool_size: usize,
+    /// `true` while a background task is still growing the agent pool up to
+    /// its configured size (see [`ProductionAgentSystem::create_agent_pool`]).
+    pub pool_warming: bool,
     pub circuit_breaker_state: String,
     pub checks: HashMap<String, String>,
 }
 
+/// Time budget for [`probe_python_runtime`]; keeps health/readiness probes
+/// fast even if the interpreter is somehow stuck.
+const PYTHON_PROBE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Confirm the embedded Python interpreter is actually usable by running a
+/// trivial eval and a throwaway `dspy` import, rather than assuming it's
+/// fine because the process is up. Runs on its own thread so a hang in the
+/// interpreter can't block the caller past `PYTHON_PROBE_TIMEOUT`.
+fn probe_python_runtime() -> Result<(), String> {
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        let result = Python::with_gil(|py| -> PyResult<()> {
+            py.eval("1 + 1", None, None)?;
+            PyModule::import(py, "dspy")?;
+            Ok(())
+        });
+        // The receiver may already have timed out and dropped; a failed
+        // send just means no one is listening anymore.
+        let _ = tx.send(result.map_err(|e| e.to_string()));
+    });
+
+    match rx.recv_timeout(PYTHON_PROBE_TIMEOUT) {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(err)) => Err(err),
+        Err(_) => Err(format!("probe exceeded {:?} timeout", PYTHON_PROBE_TIMEOUT)),
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -770,6 +1737,61 @@ pub struct HealthStatus {
 mod tests {
     use super::*;
 
+    /// No-op `MemoryStore` for tests that exercise the in-memory cache
+    /// directly and don't care about the durable backend behind it.
+    struct NullMemoryStore;
+
+    #[async_trait::async_trait]
+    impl MemoryStore for NullMemoryStore {
+        async fn load(&self, _user_id: &str) -> Result<Option<AgentMemory>> {
+            Ok(None)
+        }
+
+        async fn save(&self, _memory: &AgentMemory) -> Result<()> {
+            Ok(())
+        }
+
+        async fn delete(&self, _user_id: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Builds a `ProductionAgentSystem` without going through `new`, which
+    /// requires a live Python/dspy runtime to populate the agent pool. Tests
+    /// that only exercise memory persistence don't need a real agent pool.
+    fn test_system() -> ProductionAgentSystem {
+        ProductionAgentSystem {
+            agent_pool: Arc::new(AgentPool::new(Vec::new())),
+            memory_store: Arc::new(DashMap::new()),
+            memory_backend: Box::new(NullMemoryStore),
+            user_locks: Arc::new(DashMap::new()),
+            circuit_breaker: Arc::new(CircuitBreaker::new(5, Duration::from_secs(30))),
+            metrics: Arc::new(AgentMetrics::new().unwrap()),
+            tool_registry: Arc::new(Mutex::new(ToolRegistry::new())),
+            config: AgentConfig::default(),
+            start_time: Instant::now(),
+            pool_warming: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        }
+    }
+
+    #[test]
+    fn test_health_check_reports_pool_warming() {
+        let system = test_system();
+        Python::with_gil(|py| {
+            system.agent_pool.push_for_test(py.None().into());
+        });
+        system.pool_warming.store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let health = system.health_check();
+
+        assert!(health.pool_warming);
+        assert_eq!(health.agent_pool_size, 1);
+        assert_eq!(
+            health.checks.get("agent_pool").unwrap(),
+            &format!("warming (1/{})", system.config.pool_size),
+        );
+    }
+
     #[test]
     fn test_circuit_breaker_transitions() {
         let cb = CircuitBreaker::new(3, Duration::from_millis(100));
@@ -786,6 +1808,75 @@ mod tests {
         assert_eq!(cb.state(), CircuitState::Open);
     }
 
+    #[test]
+    fn test_half_open_closes_after_consecutive_successes() {
+        let cb = CircuitBreaker::new(1, Duration::from_millis(10))
+            .half_open_success_threshold(2);
+
+        cb.on_failure();
+        assert_eq!(cb.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(15));
+
+        // First probe succeeds: still half-open, only 1 of 2 required successes.
+        let result = cb.call(|| Ok::<_, AgentError>(()));
+        assert!(result.is_ok());
+        assert_eq!(cb.state(), CircuitState::HalfOpen);
+
+        // Second consecutive success reaches the threshold and closes.
+        let result = cb.call(|| Ok::<_, AgentError>(()));
+        assert!(result.is_ok());
+        assert_eq!(cb.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_reopens_immediately_on_probe_failure() {
+        let cb = CircuitBreaker::new(1, Duration::from_millis(10))
+            .half_open_success_threshold(5);
+
+        cb.on_failure();
+        assert_eq!(cb.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(15));
+
+        // A single failed probe re-opens the breaker even though the
+        // configured success threshold (5) was never reached.
+        let result = cb.call(|| Err::<(), _>(AgentError::ExecutionFailed("boom".to_string())));
+        assert!(result.is_err());
+        assert_eq!(cb.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_half_open_rejects_calls_beyond_probe_limit() {
+        let cb = Arc::new(CircuitBreaker::new(1, Duration::from_millis(10)).half_open_max_calls(1));
+
+        cb.on_failure();
+        assert_eq!(cb.state(), CircuitState::Open);
+        std::thread::sleep(Duration::from_millis(15));
+
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let (started_tx, started_rx) = std::sync::mpsc::channel::<()>();
+
+        let cb_clone = Arc::clone(&cb);
+        let handle = std::thread::spawn(move || {
+            cb_clone.call(|| {
+                started_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+                Ok::<_, AgentError>(())
+            })
+        });
+
+        // Wait until the first probe is actually in flight before firing the second.
+        started_rx.recv().unwrap();
+
+        let second = cb.call(|| Ok::<_, AgentError>(()));
+        assert!(matches!(second, Err(AgentError::CircuitBreakerOpen(_))));
+
+        release_tx.send(()).unwrap();
+        let first = handle.join().unwrap();
+        assert!(first.is_ok());
+    }
+
     #[test]
     fn test_agent_memory_context() {
         let mut memory = AgentMemory::new("test_user".to_string());
@@ -800,6 +1891,146 @@ mod tests {
         assert!(!context.contains("Q1"));
     }
 
+    /// Builds a stand-in agent with a `forward` method, so `execute_query`
+    /// can run against the pool without a real DSPy module. `forward`
+    /// mirrors the one-tuple-of-one-tuple calling convention
+    /// `execute_with_circuit_breaker` uses (`call_method1("forward",
+    /// ((question,),))`), and stamps `name` into the answer so tests can
+    /// tell which pooled agent actually handled a given call.
+    fn make_fake_agent(py: Python, name: &str) -> Py<PyAny> {
+        let module = PyModule::from_code(
+            py,
+            r#"
+class _Prediction:
+    def __init__(self, answer):
+        self.answer = answer
+
+
+class FakeAgent:
+    def __init__(self, name):
+        self.name = name
+
+    def forward(self, args):
+        question = args[0]
+        return _Prediction(f"{self.name}:{question}")
+"#,
+            "fake_agent.py",
+            "fake_agent",
+        )
+        .unwrap();
+        let cls = module.getattr("FakeAgent").unwrap();
+        cls.call1((name,)).unwrap().into()
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_queries_for_same_user_persist_both_turns() {
+        let system = test_system();
+        let agent = Python::with_gil(|py| make_fake_agent(py, "agent0"));
+        system.agent_pool.push(agent).await;
+        let system = Arc::new(system);
+
+        let request_one = QueryRequest {
+            user_id: "alice".to_string(),
+            question: "Q1".to_string(),
+            capture_trajectory: false,
+        };
+        let request_two = QueryRequest {
+            user_id: "alice".to_string(),
+            question: "Q2".to_string(),
+            capture_trajectory: false,
+        };
+
+        let system_one = system.clone();
+        let system_two = system.clone();
+        let (result_one, result_two) = tokio::join!(
+            tokio::spawn(async move { system_one.execute_query(request_one).await }),
+            tokio::spawn(async move { system_two.execute_query(request_two).await }),
+        );
+        result_one.unwrap().unwrap();
+        result_two.unwrap().unwrap();
+
+        // Both turns must have persisted - the user lock around the whole
+        // read-augment-execute-update cycle in `execute_query` prevents one
+        // concurrent request for the same user from reading a stale clone
+        // before the other's turn is written back.
+        let context = system.memory_context_turns("alice").await;
+        assert!(context.contains("Q1"), "missing first turn: {context}");
+        assert!(context.contains("Q2"), "missing second turn: {context}");
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_queries_use_distinct_pooled_agents() {
+        let mut system = test_system();
+        system.config.pool_size = 2;
+        let (agent_zero, agent_one) =
+            Python::with_gil(|py| (make_fake_agent(py, "agent0"), make_fake_agent(py, "agent1")));
+        system.agent_pool.push(agent_zero).await;
+        system.agent_pool.push(agent_one).await;
+        let system = Arc::new(system);
+
+        let request_one = QueryRequest {
+            user_id: "alice".to_string(),
+            question: "Q".to_string(),
+            capture_trajectory: false,
+        };
+        let request_two = QueryRequest {
+            user_id: "bob".to_string(),
+            question: "Q".to_string(),
+            capture_trajectory: false,
+        };
+
+        let system_one = system.clone();
+        let system_two = system.clone();
+        let (result_one, result_two) = tokio::join!(
+            tokio::spawn(async move { system_one.execute_query(request_one).await }),
+            tokio::spawn(async move { system_two.execute_query(request_two).await }),
+        );
+
+        let agent_for = |answer: String| answer.split(':').next().unwrap().to_string();
+        let agent_one = agent_for(result_one.unwrap().unwrap().answer);
+        let agent_two = agent_for(result_two.unwrap().unwrap().answer);
+
+        let used: std::collections::HashSet<String> = [agent_one, agent_two].into_iter().collect();
+        assert_eq!(
+            used,
+            std::collections::HashSet::from(["agent0".to_string(), "agent1".to_string()]),
+            "both pooled agents should have been used instead of one handling every request",
+        );
+    }
+
+    /// Regression test for a round-robin pool that only returned
+    /// `PoolExhausted` when the pool was literally empty - it would hand a
+    /// request the same `Py<PyAny>` agent another caller already had
+    /// in-flight once concurrent requests outnumbered `pool_size`. Holds the
+    /// pool's only agent checked out (as a concurrent in-flight request
+    /// would) and asserts the next caller is rejected rather than handed the
+    /// same agent.
+    #[tokio::test]
+    async fn test_execute_with_circuit_breaker_returns_pool_exhausted_when_every_agent_is_checked_out() {
+        let system = test_system();
+        let agent = Python::with_gil(|py| make_fake_agent(py, "agent0"));
+        system.agent_pool.push(agent).await;
+
+        let _checkout = system
+            .agent_pool
+            .try_checkout()
+            .expect("pool's one agent should be available before anyone else checks it out");
+
+        let request = QueryRequest {
+            user_id: "alice".to_string(),
+            question: "Q".to_string(),
+            capture_trajectory: false,
+        };
+        let memory = AgentMemory::new("alice".to_string());
+
+        let result = system.execute_with_circuit_breaker(&request, &memory).await;
+        assert!(
+            matches!(result, Err(AgentError::PoolExhausted)),
+            "expected PoolExhausted while the pool's only agent was already checked out, got {:?}",
+            result,
+        );
+    }
+
     #[test]
     fn test_tool_registry() {
         let mut registry = ToolRegistry::new();
@@ -812,6 +2043,41 @@ mod tests {
         assert_eq!(result, "Processed: hello");
     }
 
+    #[tokio::test]
+    async fn test_timeout_races_slow_operation_and_records_failure() {
+        // Mirrors the spawn_blocking + timeout race in
+        // `execute_with_circuit_breaker`, with a sleeping stand-in for the
+        // Python agent call so the test doesn't need a live dspy agent.
+        let circuit_breaker = Arc::new(CircuitBreaker::new(3, Duration::from_millis(100)));
+        let deadline = Duration::from_millis(50);
+
+        let cb = Arc::clone(&circuit_breaker);
+        let task = tokio::task::spawn_blocking(move || {
+            cb.call(|| {
+                std::thread::sleep(Duration::from_millis(500));
+                Ok::<String, AgentError>("too late".to_string())
+            })
+        });
+
+        let result = match tokio::time::timeout(deadline, task).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(join_err)) => {
+                Err(AgentError::ExecutionFailed(format!("Agent task panicked: {}", join_err)))
+            }
+            Err(_) => {
+                circuit_breaker.on_failure();
+                Err(AgentError::Timeout(deadline))
+            }
+        };
+
+        match result {
+            Err(AgentError::Timeout(d)) => assert_eq!(d, deadline),
+            other => panic!("expected AgentError::Timeout, got {:?}", other),
+        }
+        assert_eq!(*circuit_breaker.failure_count.lock(), 1);
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+    }
+
     #[test]
     fn test_memory_serialization() {
         let mut memory = AgentMemory::new("test_user".to_string());
@@ -823,4 +2089,199 @@ mod tests {
         assert_eq!(memory.user_id, restored.user_id);
         assert_eq!(memory.conversation_history.len(), restored.conversation_history.len());
     }
+
+    #[test]
+    fn test_merge_from_deduplicates_by_timestamp() {
+        let mut memory = AgentMemory::new("test_user".to_string());
+        memory.add_turn("Q1".to_string(), "A1".to_string(), 1);
+
+        let mut other = memory.clone();
+        other.add_turn("Q2".to_string(), "A2".to_string(), 1);
+
+        memory.merge_from(&other);
+        assert_eq!(memory.conversation_history.len(), 2);
+
+        // Merging the same export again shouldn't duplicate turns already present.
+        memory.merge_from(&other);
+        assert_eq!(memory.conversation_history.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_memory_store_round_trips_and_deletes() {
+        let dir = std::env::temp_dir().join(format!(
+            "production-agent-service-fs-store-test-{}",
+            std::process::id()
+        ));
+        let store = FilesystemMemoryStore::new(dir.clone());
+
+        assert!(store.load("carol").await.unwrap().is_none());
+
+        let mut memory = AgentMemory::new("carol".to_string());
+        memory.add_turn("Q1".to_string(), "A1".to_string(), 1);
+        store.save(&memory).await.unwrap();
+
+        let loaded = store.load("carol").await.unwrap().unwrap();
+        assert_eq!(loaded.conversation_history.len(), 1);
+
+        store.delete("carol").await.unwrap();
+        assert!(store.load("carol").await.unwrap().is_none());
+
+        // Deleting something that was never there is a no-op, not an error.
+        store.delete("carol").await.unwrap();
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_export_and_import_memories_round_trip() {
+        let system = test_system();
+        let mut memory = AgentMemory::new("alice".to_string());
+        memory.add_turn("Q1".to_string(), "A1".to_string(), 1);
+        system.memory_store.insert("alice".to_string(), memory);
+
+        let dir = std::env::temp_dir().join(format!(
+            "production-agent-service-memory-export-test-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("export.jsonl");
+
+        system.export_memories(path.to_str().unwrap()).await.unwrap();
+
+        let other_system = test_system();
+        let summary = other_system
+            .import_memories(path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(summary.imported, 1);
+        assert_eq!(summary.skipped, 0);
+        assert_eq!(
+            other_system
+                .memory_store
+                .get("alice")
+                .unwrap()
+                .conversation_history
+                .len(),
+            1
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_import_memories_skips_corrupt_lines() {
+        let system = test_system();
+
+        let dir = std::env::temp_dir().join(format!(
+            "production-agent-service-memory-import-test-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("import.jsonl");
+        std::fs::write(
+            &path,
+            "not valid json\n{\"user_id\":\"bob\",\"conversation_history\":[],\"created_at\":\"2024-01-01T00:00:00Z\",\"last_updated\":\"2024-01-01T00:00:00Z\"}\n",
+        )
+        .unwrap();
+
+        let summary = system.import_memories(path.to_str().unwrap()).await.unwrap();
+
+        assert_eq!(summary.imported, 1);
+        assert_eq!(summary.skipped, 1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_exhausts_max_attempts() {
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        let policy = RetryPolicy::new(3).base_delay(Duration::from_millis(1));
+
+        let result: Result<(), AgentError> = retry_with_backoff(
+            policy,
+            || {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async { Err(AgentError::ExecutionFailed("always fails".to_string())) }
+            },
+            |_| true,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_succeeds_on_third_attempt() {
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        let policy = RetryPolicy::new(5).base_delay(Duration::from_millis(1));
+
+        let result = retry_with_backoff(
+            policy,
+            || {
+                let attempt = attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                async move {
+                    if attempt < 3 {
+                        Err(AgentError::ExecutionFailed("not yet".to_string()))
+                    } else {
+                        Ok("success")
+                    }
+                }
+            },
+            |_| true,
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), "success");
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_stops_on_non_retryable_error() {
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        let policy = RetryPolicy::new(5).base_delay(Duration::from_millis(1));
+
+        let result: Result<(), AgentError> = retry_with_backoff(
+            policy,
+            || {
+                attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async { Err(AgentError::PoolExhausted) }
+            },
+            |err| !matches!(err, AgentError::PoolExhausted),
+        )
+        .await;
+
+        assert!(matches!(result, Err(AgentError::PoolExhausted)));
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_redact_observation_passes_through_short_text() {
+        let observation = "sunny, 72F".to_string();
+        assert_eq!(redact_observation(observation.clone()), observation);
+    }
+
+    #[test]
+    fn test_redact_observation_truncates_long_text() {
+        let observation = "x".repeat(MAX_TRAJECTORY_OBSERVATION_BYTES + 100);
+        let redacted = redact_observation(observation);
+
+        assert!(redacted.starts_with(&"x".repeat(MAX_TRAJECTORY_OBSERVATION_BYTES)));
+        assert!(redacted.contains("redacted"));
+        assert!(redacted.contains(&format!("{}", MAX_TRAJECTORY_OBSERVATION_BYTES + 100)));
+    }
+
+    #[test]
+    fn test_retry_policy_delay_grows_exponentially_and_caps() {
+        let policy = RetryPolicy::new(10)
+            .base_delay(Duration::from_millis(100))
+            .multiplier(2.0)
+            .max_delay(Duration::from_millis(500));
+
+        assert_eq!(policy.delay_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for_attempt(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for_attempt(3), Duration::from_millis(400));
+        assert_eq!(policy.delay_for_attempt(4), Duration::from_millis(500));
+    }
 }
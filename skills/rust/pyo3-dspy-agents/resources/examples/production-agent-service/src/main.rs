@@ -11,7 +11,8 @@ use axum::{
     Router,
 };
 use production_agent_service::{
-    AgentConfig, AgentError, HealthStatus, ProductionAgentSystem, QueryRequest, QueryResponse,
+    AgentConfig, AgentError, HealthStatus, MemoryStore, ProductionAgentSystem, QueryRequest,
+    QueryResponse, RedisMemoryStore,
 };
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
@@ -37,7 +38,17 @@ struct AppState {
 
 impl AppState {
     async fn new(config: AgentConfig) -> Result<Self> {
-        let agent_system = ProductionAgentSystem::new(config).await?;
+        // A REDIS_URL means this is a multi-replica deployment where any
+        // replica may serve a given user, so memory needs a shared backend
+        // rather than the local-filesystem default.
+        let agent_system = match std::env::var("REDIS_URL") {
+            Ok(redis_url) => {
+                let memory_backend: Box<dyn MemoryStore> =
+                    Box::new(RedisMemoryStore::new(&redis_url)?);
+                ProductionAgentSystem::new_with_memory_store(config, memory_backend).await?
+            }
+            Err(_) => ProductionAgentSystem::new(config).await?,
+        };
 
         Ok(Self {
             agent_system: Arc::new(agent_system),
@@ -147,19 +158,34 @@ async fn health_handler(State(state): State<AppState>) -> Json<HealthStatus> {
 }
 
 /// GET /api/v1/ready - Readiness probe
+///
+/// Unlike liveness, this actually probes whether the instance can serve a
+/// query right now (agent pool populated, Python runtime responsive), so
+/// Kubernetes can pull a backend-broken instance out of rotation without
+/// restarting the process.
 async fn readiness_handler(State(state): State<AppState>) -> impl IntoResponse {
-    let pool_size = state.agent_system.pool_size().await;
-
-    if pool_size > 0 {
-        (StatusCode::OK, "ready")
-    } else {
-        (StatusCode::SERVICE_UNAVAILABLE, "not ready")
+    let agent_system = state.agent_system.clone();
+
+    match tokio::task::spawn_blocking(move || agent_system.readiness()).await {
+        Ok(Ok(())) => (StatusCode::OK, "ready".to_string()),
+        Ok(Err(reason)) => (StatusCode::SERVICE_UNAVAILABLE, reason),
+        Err(join_err) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("readiness check panicked: {}", join_err),
+        ),
     }
 }
 
 /// GET /api/v1/live - Liveness probe
-async fn liveness_handler() -> impl IntoResponse {
-    (StatusCode::OK, "alive")
+///
+/// Deliberately doesn't probe backends: a slow/broken Python runtime
+/// should fail readiness, not get the whole pod restarted.
+async fn liveness_handler(State(state): State<AppState>) -> impl IntoResponse {
+    if state.agent_system.liveness() {
+        (StatusCode::OK, "alive")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "not alive")
+    }
 }
 
 /// GET /api/v1/info - Service information
@@ -414,10 +440,22 @@ fn load_agent_config() -> AgentConfig {
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(30),
         ),
+        circuit_breaker_half_open_max_calls: std::env::var("CIRCUIT_BREAKER_HALF_OPEN_MAX_CALLS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(3),
+        circuit_breaker_half_open_success_threshold: std::env::var(
+            "CIRCUIT_BREAKER_HALF_OPEN_SUCCESS_THRESHOLD",
+        )
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(1),
         memory_context_turns: std::env::var("MEMORY_CONTEXT_TURNS")
             .ok()
             .and_then(|s| s.parse().ok())
             .unwrap_or(3),
+        memory_directory: std::env::var("MEMORY_DIRECTORY")
+            .unwrap_or_else(|_| "./agent_memories".to_string()),
     }
 }
 
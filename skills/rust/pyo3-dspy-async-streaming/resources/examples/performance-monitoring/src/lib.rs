@@ -15,8 +15,15 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
 use tracing::{debug, info, instrument, warn};
 
+/// Capacity of the [`PerformanceReport`] broadcast channel. A subscriber that
+/// falls more than this many reports behind is dropped (its next `recv()`
+/// returns `RecvError::Lagged`) rather than slowing down the emitter.
+const REPORT_CHANNEL_CAPACITY: usize = 16;
+
 /// Performance monitor for tracking async operations
 #[derive(Clone)]
 pub struct PerformanceMonitor {
@@ -30,7 +37,9 @@ struct MonitorInner {
     latency_trackers: RwLock<HashMap<String, LatencyTracker>>,
     throughput_trackers: RwLock<HashMap<String, ThroughputTracker>>,
     task_trackers: RwLock<HashMap<String, TaskTracker>>,
+    custom_percentiles: RwLock<Vec<f64>>,
     start_time: Instant,
+    report_tx: broadcast::Sender<PerformanceReport>,
 }
 
 /// Metrics collection for async operations
@@ -98,7 +107,11 @@ pub struct LatencyStats {
     pub p95_ms: f64,
     pub p99_ms: f64,
     pub p99_9_ms: f64,
+    pub p99_99_ms: f64,
     pub stddev_ms: f64,
+    /// Additional percentiles requested via
+    /// [`PerformanceMonitor::set_custom_percentiles`], keyed by `"p{value}"`.
+    pub custom: HashMap<String, f64>,
 }
 
 /// Throughput statistics
@@ -134,6 +147,7 @@ impl PerformanceMonitor {
         let name = name.into();
         let registry = Registry::new();
         let metrics = AsyncMetrics::new(&registry).expect("Failed to create metrics");
+        let (report_tx, _) = broadcast::channel(REPORT_CHANNEL_CAPACITY);
 
         Self {
             inner: Arc::new(MonitorInner {
@@ -143,16 +157,54 @@ impl PerformanceMonitor {
                 latency_trackers: RwLock::new(HashMap::new()),
                 throughput_trackers: RwLock::new(HashMap::new()),
                 task_trackers: RwLock::new(HashMap::new()),
+                custom_percentiles: RwLock::new(Vec::new()),
                 start_time: Instant::now(),
+                report_tx,
             }),
         }
     }
 
+    /// Subscribe to live [`PerformanceReport`] snapshots emitted by
+    /// [`Self::start_reporting`].
+    ///
+    /// Subscribers that fall behind are dropped rather than backpressuring
+    /// the emitter: the underlying [`broadcast`] channel only retains the
+    /// last `REPORT_CHANNEL_CAPACITY` reports, so a lagging receiver's next
+    /// `recv()` returns `RecvError::Lagged` instead of stalling the task that
+    /// produces reports.
+    pub fn subscribe(&self) -> broadcast::Receiver<PerformanceReport> {
+        self.inner.report_tx.subscribe()
+    }
+
+    /// Spawn a background task that calls [`Self::report`] on `interval` and
+    /// broadcasts each snapshot to every [`Self::subscribe`] receiver.
+    ///
+    /// The returned [`JoinHandle`] can be aborted to stop reporting; dropping
+    /// it leaves the task running.
+    pub fn start_reporting(&self, interval: Duration) -> JoinHandle<()> {
+        let monitor = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                // Ignore the error returned when there are currently no
+                // subscribers; the report is simply dropped.
+                let _ = monitor.inner.report_tx.send(monitor.report());
+            }
+        })
+    }
+
     /// Get the Prometheus registry for metrics export
     pub fn registry(&self) -> &Registry {
         &self.inner.registry
     }
 
+    /// Configure additional percentiles (e.g. `[99.95, 99.995]`) to compute
+    /// for every task's `LatencyStats::custom` going forward.
+    pub fn set_custom_percentiles(&self, percentiles: Vec<f64>) {
+        *self.inner.custom_percentiles.write().unwrap() = percentiles;
+    }
+
     /// Track an async operation with automatic instrumentation
     #[instrument(skip(self, future))]
     pub async fn track_async<F, T, E>(
@@ -337,7 +389,10 @@ impl PerformanceMonitor {
         let throughput_trackers = self.inner.throughput_trackers.read().unwrap();
         let task_trackers = self.inner.task_trackers.read().unwrap();
 
-        let latency_stats = latency_trackers.get(task_name)?.get_stats();
+        let custom_percentiles = self.inner.custom_percentiles.read().unwrap();
+        let latency_stats = latency_trackers
+            .get(task_name)?
+            .get_stats(&custom_percentiles);
         let throughput_stats = throughput_trackers.get(task_name)?.get_stats();
         let task_stats = task_trackers.get(task_name)?.get_stats();
 
@@ -503,7 +558,28 @@ impl LatencyTracker {
         }
     }
 
-    fn get_stats(&self) -> LatencyStats {
+    /// Returns the latency, in milliseconds, at each of the given
+    /// percentiles (e.g. `[99.95, 99.995]`), reading straight from the
+    /// underlying HdrHistogram.
+    ///
+    /// Percentiles requested before any samples have been recorded return 0
+    /// rather than erroring.
+    fn value_at_percentiles(&self, percentiles: &[f64]) -> Vec<f64> {
+        if self.count.load(Ordering::Relaxed) == 0 {
+            return vec![0.0; percentiles.len()];
+        }
+
+        if let Ok(hist) = self.histogram.lock() {
+            percentiles
+                .iter()
+                .map(|&p| hist.value_at_percentile(p) as f64 / 1000.0)
+                .collect()
+        } else {
+            vec![0.0; percentiles.len()]
+        }
+    }
+
+    fn get_stats(&self, custom_percentiles: &[f64]) -> LatencyStats {
         let count = self.count.load(Ordering::Relaxed);
         let total = self.total_duration.load(Ordering::Relaxed);
         let min = self.min_duration.load(Ordering::Relaxed);
@@ -515,18 +591,25 @@ impl LatencyTracker {
             0.0
         };
 
-        let (p50, p95, p99, p99_9, stddev) = if let Ok(hist) = self.histogram.lock() {
+        let (p50, p95, p99, p99_9, p99_99, stddev) = if let Ok(hist) = self.histogram.lock() {
             (
                 hist.value_at_percentile(50.0),
                 hist.value_at_percentile(95.0),
                 hist.value_at_percentile(99.0),
                 hist.value_at_percentile(99.9),
+                hist.value_at_percentile(99.99),
                 hist.stdev(),
             )
         } else {
-            (0, 0, 0, 0, 0.0)
+            (0, 0, 0, 0, 0, 0.0)
         };
 
+        let custom = custom_percentiles
+            .iter()
+            .zip(self.value_at_percentiles(custom_percentiles))
+            .map(|(p, value_ms)| (format!("p{}", p), value_ms))
+            .collect();
+
         LatencyStats {
             count,
             mean_ms: mean_micros / 1000.0,
@@ -536,7 +619,9 @@ impl LatencyTracker {
             p95_ms: p95 as f64 / 1000.0,
             p99_ms: p99 as f64 / 1000.0,
             p99_9_ms: p99_9 as f64 / 1000.0,
+            p99_99_ms: p99_99 as f64 / 1000.0,
             stddev_ms: stddev / 1000.0,
+            custom,
         }
     }
 }
@@ -670,6 +755,10 @@ impl std::fmt::Display for PerformanceReport {
             writeln!(f, "    p95: {:.2}ms", stats.latency_ms.p95_ms)?;
             writeln!(f, "    p99: {:.2}ms", stats.latency_ms.p99_ms)?;
             writeln!(f, "    p99.9: {:.2}ms", stats.latency_ms.p99_9_ms)?;
+            writeln!(f, "    p99.99: {:.2}ms", stats.latency_ms.p99_99_ms)?;
+            for (label, value_ms) in &stats.latency_ms.custom {
+                writeln!(f, "    {}: {:.2}ms", label, value_ms)?;
+            }
             writeln!(f, "  Throughput:")?;
             writeln!(f, "    Current: {:.2} req/s", stats.throughput.current_rps)?;
             writeln!(f, "    Total: {}", stats.throughput.total_requests)?;
@@ -740,13 +829,79 @@ mod tests {
             }
         }
 
-        let stats = tracker.get_stats();
+        let stats = tracker.get_stats(&[]);
         assert_eq!(stats.count, 800);
         assert!(stats.p50_ms <= stats.p95_ms);
         assert!(stats.p95_ms <= stats.p99_ms);
+        assert!(stats.p99_ms <= stats.p99_9_ms);
+        assert!(stats.p99_9_ms <= stats.p99_99_ms);
         assert!(stats.min_ms <= stats.max_ms);
     }
 
+    #[test]
+    fn test_custom_percentiles() {
+        let tracker = LatencyTracker::new(2);
+
+        for ms in 1..=100 {
+            tracker.record(Duration::from_millis(ms));
+        }
+
+        let stats = tracker.get_stats(&[99.95, 50.0]);
+        assert_eq!(stats.custom.len(), 2);
+        assert!(stats.custom.contains_key("p99.95"));
+        assert!(stats.custom.contains_key("p50"));
+        assert!((stats.custom["p50"] - stats.p50_ms).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_percentile_with_no_samples_returns_zero() {
+        let tracker = LatencyTracker::new(2);
+
+        let stats = tracker.get_stats(&[99.99]);
+        assert_eq!(stats.count, 0);
+        assert_eq!(stats.p99_99_ms, 0.0);
+        assert_eq!(stats.custom["p99.99"], 0.0);
+    }
+
+    #[test]
+    fn test_set_custom_percentiles_applies_to_reported_stats() {
+        let monitor = PerformanceMonitor::new("test");
+        monitor.set_custom_percentiles(vec![99.9]);
+        monitor.record_latency("custom_task", Duration::from_millis(10));
+
+        let stats = monitor.get_stats("custom_task").unwrap();
+        assert!(stats.latency_ms.custom.contains_key("p99.9"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_receives_reports_from_start_reporting() {
+        let monitor = PerformanceMonitor::new("test");
+        let mut rx = monitor.subscribe();
+        let handle = monitor.start_reporting(Duration::from_millis(10));
+
+        let report = rx.recv().await.unwrap();
+        assert_eq!(report.service_name, "test");
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber_is_dropped_not_backpressured() {
+        let monitor = PerformanceMonitor::new("test");
+        let mut rx = monitor.subscribe();
+
+        // Send more reports than the channel can hold without anyone
+        // draining `rx`; the emitter must not block on this.
+        for _ in 0..(REPORT_CHANNEL_CAPACITY * 2) {
+            let _ = monitor.inner.report_tx.send(monitor.report());
+        }
+
+        match rx.recv().await {
+            Err(broadcast::error::RecvError::Lagged(_)) => {}
+            other => panic!("expected Lagged error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_throughput_tracking() {
         let tracker = ThroughputTracker::new(Duration::from_secs(60));
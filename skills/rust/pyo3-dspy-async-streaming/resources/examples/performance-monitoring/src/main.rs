@@ -8,6 +8,7 @@
 
 use anyhow::Result;
 use axum::{
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
     extract::State,
     http::StatusCode,
     response::{IntoResponse, Response},
@@ -17,8 +18,9 @@ use axum::{
 use performance_monitoring::{PerformanceMonitor, PerformanceReport};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use tokio::sync::broadcast;
 use tokio::time::sleep;
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Application state shared across handlers
@@ -124,6 +126,7 @@ async fn main() -> Result<()> {
         .route("/health", get(health_handler))
         .route("/predict", get(predict_handler))
         .route("/load", get(load_handler))
+        .route("/ws", get(ws_handler))
         .with_state(state);
 
     // Start background monitoring task
@@ -132,11 +135,15 @@ async fn main() -> Result<()> {
         background_monitoring(monitor_clone).await;
     });
 
+    // Periodically broadcast performance reports to `/ws` subscribers
+    monitor.start_reporting(Duration::from_secs(5));
+
     // Start server
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
     info!("Server listening on http://0.0.0.0:3000");
     info!("Metrics available at http://0.0.0.0:3000/metrics");
     info!("Dashboard available at http://0.0.0.0:3000/dashboard");
+    info!("Live metrics feed available at ws://0.0.0.0:3000/ws");
 
     axum::serve(listener, app).await?;
 
@@ -152,7 +159,8 @@ async fn root_handler() -> Json<serde_json::Value> {
             "dashboard": "/dashboard",
             "health": "/health",
             "predict": "/predict",
-            "load": "/load?count=100"
+            "load": "/load?count=100",
+            "ws": "/ws"
         },
         "grafana": "http://localhost:3001",
         "prometheus": "http://localhost:9090"
@@ -183,6 +191,43 @@ async fn dashboard_handler(State(state): State<AppState>) -> Json<DashboardData>
     })
 }
 
+/// Upgrades to a WebSocket and pushes a JSON-encoded [`PerformanceReport`]
+/// to the client every time [`PerformanceMonitor::start_reporting`] emits
+/// one. A client that reads too slowly is disconnected by the broadcast
+/// channel (its `recv()` returns `Lagged`) rather than slowing everyone else
+/// down.
+#[instrument(skip(state, ws))]
+async fn ws_handler(State(state): State<AppState>, ws: WebSocketUpgrade) -> Response {
+    ws.on_upgrade(move |socket| stream_reports(socket, state.monitor.subscribe()))
+}
+
+async fn stream_reports(mut socket: WebSocket, mut reports: broadcast::Receiver<PerformanceReport>) {
+    loop {
+        match reports.recv().await {
+            Ok(report) => {
+                let payload = match serde_json::to_string(&report) {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        warn!("Failed to serialize performance report: {}", e);
+                        continue;
+                    }
+                };
+                if socket.send(Message::Text(payload)).await.is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!(
+                    "WebSocket subscriber lagged, skipped {} reports; disconnecting",
+                    skipped
+                );
+                break;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
 /// Health check endpoint
 async fn health_handler() -> Json<serde_json::Value> {
     Json(serde_json::json!({
@@ -496,6 +541,7 @@ fn print_help() {
     println!("  http://localhost:3000/dashboard        Dashboard data (JSON)");
     println!("  http://localhost:3000/predict          Simulated prediction");
     println!("  http://localhost:3000/load?count=100   Load test");
+    println!("  ws://localhost:3000/ws                 Live metrics feed (WebSocket)");
     println!();
     println!("Monitoring:");
     println!("  http://localhost:9090                  Prometheus UI");
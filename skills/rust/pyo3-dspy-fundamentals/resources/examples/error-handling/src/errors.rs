@@ -5,7 +5,7 @@
 //! context preservation, and structured error types.
 
 use pyo3::exceptions::{PyException, PyRuntimeError, PyTypeError, PyValueError};
-use pyo3::types::PyTypeMethods;
+use pyo3::types::{PyAnyMethods, PyTypeMethods};
 use pyo3::{PyErr, Python};
 use std::fmt;
 use thiserror::Error;
@@ -138,11 +138,42 @@ impl From<DSpyError> for PyErr {
             DSpyError::Timeout { operation, timeout_ms } => {
                 PyRuntimeError::new_err(format!("Timeout after {}ms: {}", timeout_ms, operation))
             }
-            _ => PyRuntimeError::new_err(err.to_string()),
+            _ => anyhow_to_pyerr(err.into()),
         }
     }
 }
 
+/// Converts an `anyhow::Error` into a `PyErr`, preserving the full cause
+/// chain instead of flattening it to a single string.
+///
+/// Each layer of `e.chain()` becomes its own Python exception, linked via
+/// `__cause__`, so `traceback.print_exc()` on the Python side shows every
+/// layer of Rust context. `DSpyError`'s `From<DSpyError> for PyErr` impl
+/// falls back to this for every variant it doesn't map to a specific
+/// PyO3 exception type; reach for it the same way anywhere else in this
+/// crate an `anyhow::Error` would otherwise cross into Python via
+/// `.map_err(|e| PyRuntimeError::new_err(e.to_string()))`, which flattens
+/// the chain to a single string.
+pub fn anyhow_to_pyerr(e: anyhow::Error) -> PyErr {
+    Python::with_gil(|py| {
+        let mut causes = e.chain().map(|cause| cause.to_string());
+        let top_message = causes
+            .next()
+            .unwrap_or_else(|| "unknown error".to_string());
+        let top_err = PyRuntimeError::new_err(top_message);
+
+        let mut current = top_err.value_bound(py).clone();
+        for cause in causes {
+            let cause_err = PyRuntimeError::new_err(cause);
+            let cause_value = cause_err.value_bound(py).clone();
+            let _ = current.setattr("__cause__", &cause_value);
+            current = cause_value;
+        }
+
+        top_err
+    })
+}
+
 /// Result type alias for DSPy operations
 pub type DSpyResult<T> = Result<T, DSpyError>;
 
@@ -6,10 +6,12 @@
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounter, IntGauge, Opts, Registry, TextEncoder};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
@@ -47,6 +49,52 @@ impl PipelineStage {
             Self::Deployment => "deployment",
         }
     }
+
+    /// The default stage graph: the same linear DataPrep -> Training ->
+    /// Validation -> Deployment chain `all_stages` describes, expressed as
+    /// dependency edges so `OptimizationPipeline::execute` can run it
+    /// through the same DAG scheduler as a custom graph.
+    pub fn linear_graph() -> Vec<StageNode> {
+        Self::all_stages()
+            .into_iter()
+            .scan(None, |prev, stage| {
+                let node = match *prev {
+                    Some(dep) => StageNode::new(stage).depends_on([dep]),
+                    None => StageNode::new(stage),
+                };
+                *prev = Some(stage);
+                Some(node)
+            })
+            .collect()
+    }
+}
+
+/// A node in a pipeline's stage dependency graph: a stage plus the other
+/// stages that must complete successfully before it may run. Stages with no
+/// unmet dependency are eligible to run concurrently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageNode {
+    /// The stage this node runs
+    pub stage: PipelineStage,
+    /// Stages that must complete (successfully) before this one can start
+    #[serde(default)]
+    pub depends_on: Vec<PipelineStage>,
+}
+
+impl StageNode {
+    /// Create a node with no dependencies
+    pub fn new(stage: PipelineStage) -> Self {
+        Self {
+            stage,
+            depends_on: Vec::new(),
+        }
+    }
+
+    /// Add dependencies on the given stages
+    pub fn depends_on(mut self, stages: impl IntoIterator<Item = PipelineStage>) -> Self {
+        self.depends_on.extend(stages);
+        self
+    }
 }
 
 /// Quality gate threshold
@@ -102,6 +150,396 @@ impl QualityGate {
     }
 }
 
+/// An expression-based quality gate: a small boolean predicate evaluated
+/// over the validation stage's metrics. Complements [`QualityGate`] for
+/// conditions a single min/max bound can't express, e.g.
+/// `"accuracy >= 0.9 AND f1_score >= 0.85"` or, via `baseline.<metric>`
+/// references to the last successful run's metrics,
+/// `"accuracy - baseline.accuracy >= 0.02"`.
+///
+/// Grammar (case-insensitive `AND`/`OR`, `AND` binds tighter than `OR`,
+/// left-associative, no parentheses):
+///
+/// ```text
+/// expr       := and_expr (OR and_expr)*
+/// and_expr   := comparison (AND comparison)*
+/// comparison := sum (">=" | "<=" | "==" | ">" | "<") sum
+/// sum        := term (("+" | "-") term)*
+/// term       := NUMBER | METRIC | "baseline." METRIC
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpressionGate {
+    /// Gate name
+    pub name: String,
+    /// The predicate expression to evaluate
+    pub expression: String,
+    /// Whether this gate is required
+    pub required: bool,
+}
+
+impl ExpressionGate {
+    /// Create a new expression gate
+    pub fn new(name: impl Into<String>, expression: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            expression: expression.into(),
+            required: true,
+        }
+    }
+
+    /// Make gate optional
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    /// Evaluate this gate's expression against `metrics`, with `baseline`
+    /// available for `baseline.<metric>` references. Returns an error
+    /// naming which sub-condition failed if the expression didn't pass.
+    pub fn check(
+        &self,
+        metrics: &HashMap<String, f64>,
+        baseline: &HashMap<String, f64>,
+    ) -> Result<()> {
+        let expr = gate_expr::parse(&self.expression)
+            .with_context(|| format!("Failed to parse expression gate '{}'", self.name))?;
+
+        let outcome = expr.evaluate(metrics, baseline);
+        if outcome.passed {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "Quality gate '{}' failed: {}",
+                self.name,
+                outcome.detail
+            ))
+        }
+    }
+}
+
+/// Parser and evaluator for [`ExpressionGate`]'s predicate DSL.
+mod gate_expr {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Token {
+        Number(f64),
+        Ident(String),
+        Ge,
+        Le,
+        Eq,
+        Gt,
+        Lt,
+        Plus,
+        Minus,
+        And,
+        Or,
+    }
+
+    fn tokenize(input: &str) -> Result<Vec<Token>> {
+        let chars: Vec<char> = input.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            if c.is_whitespace() {
+                i += 1;
+            } else if c == '>' {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(Token::Ge);
+                    i += 2;
+                } else {
+                    tokens.push(Token::Gt);
+                    i += 1;
+                }
+            } else if c == '<' {
+                if chars.get(i + 1) == Some(&'=') {
+                    tokens.push(Token::Le);
+                    i += 2;
+                } else {
+                    tokens.push(Token::Lt);
+                    i += 1;
+                }
+            } else if c == '=' && chars.get(i + 1) == Some(&'=') {
+                tokens.push(Token::Eq);
+                i += 2;
+            } else if c == '+' {
+                tokens.push(Token::Plus);
+                i += 1;
+            } else if c == '-' {
+                tokens.push(Token::Minus);
+                i += 1;
+            } else if c.is_ascii_digit() {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = text
+                    .parse::<f64>()
+                    .with_context(|| format!("Invalid number '{}' in expression", text))?;
+                tokens.push(Token::Number(value));
+            } else if c.is_alphabetic() || c == '_' {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                match word.to_uppercase().as_str() {
+                    "AND" => tokens.push(Token::And),
+                    "OR" => tokens.push(Token::Or),
+                    _ => tokens.push(Token::Ident(word)),
+                }
+            } else {
+                return Err(anyhow::anyhow!(
+                    "Unexpected character '{}' in expression '{}'",
+                    c,
+                    input
+                ));
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    /// A numeric operand: a literal, a metric reference, a
+    /// `baseline.<metric>` reference, or a sum/difference of those.
+    #[derive(Debug, Clone)]
+    pub(super) enum Value {
+        Number(f64),
+        Metric(String),
+        Baseline(String),
+        Add(Box<Value>, Box<Value>),
+        Sub(Box<Value>, Box<Value>),
+    }
+
+    impl Value {
+        fn resolve(&self, metrics: &HashMap<String, f64>, baseline: &HashMap<String, f64>) -> Option<f64> {
+            match self {
+                Value::Number(n) => Some(*n),
+                Value::Metric(name) => metrics.get(name).copied(),
+                Value::Baseline(name) => baseline.get(name).copied(),
+                Value::Add(l, r) => Some(l.resolve(metrics, baseline)? + r.resolve(metrics, baseline)?),
+                Value::Sub(l, r) => Some(l.resolve(metrics, baseline)? - r.resolve(metrics, baseline)?),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Value {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Value::Number(n) => write!(f, "{}", n),
+                Value::Metric(name) => write!(f, "{}", name),
+                Value::Baseline(name) => write!(f, "baseline.{}", name),
+                Value::Add(l, r) => write!(f, "{} + {}", l, r),
+                Value::Sub(l, r) => write!(f, "{} - {}", l, r),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    pub(super) enum CompOp {
+        Ge,
+        Le,
+        Eq,
+        Gt,
+        Lt,
+    }
+
+    impl CompOp {
+        fn symbol(&self) -> &'static str {
+            match self {
+                Self::Ge => ">=",
+                Self::Le => "<=",
+                Self::Eq => "==",
+                Self::Gt => ">",
+                Self::Lt => "<",
+            }
+        }
+
+        fn apply(&self, lhs: f64, rhs: f64) -> bool {
+            match self {
+                Self::Ge => lhs >= rhs,
+                Self::Le => lhs <= rhs,
+                Self::Eq => (lhs - rhs).abs() < f64::EPSILON,
+                Self::Gt => lhs > rhs,
+                Self::Lt => lhs < rhs,
+            }
+        }
+    }
+
+    /// The result of evaluating an [`Expr`]: whether it passed, and a
+    /// human-readable trail naming whichever sub-condition(s) failed.
+    pub(super) struct Eval {
+        pub(super) passed: bool,
+        pub(super) detail: String,
+    }
+
+    #[derive(Debug, Clone)]
+    pub(super) enum Expr {
+        Compare(Value, CompOp, Value),
+        And(Box<Expr>, Box<Expr>),
+        Or(Box<Expr>, Box<Expr>),
+    }
+
+    impl Expr {
+        pub(super) fn evaluate(&self, metrics: &HashMap<String, f64>, baseline: &HashMap<String, f64>) -> Eval {
+            match self {
+                Expr::Compare(lhs, op, rhs) => {
+                    let (Some(lv), Some(rv)) = (lhs.resolve(metrics, baseline), rhs.resolve(metrics, baseline)) else {
+                        return Eval {
+                            passed: false,
+                            detail: format!(
+                                "{} {} {}: one or more metrics not found",
+                                lhs,
+                                op.symbol(),
+                                rhs
+                            ),
+                        };
+                    };
+
+                    Eval {
+                        passed: op.apply(lv, rv),
+                        detail: format!(
+                            "{} {} {} (actual: {:.4} {} {:.4})",
+                            lhs,
+                            op.symbol(),
+                            rhs,
+                            lv,
+                            op.symbol(),
+                            rv
+                        ),
+                    }
+                }
+                Expr::And(l, r) => {
+                    let (le, re) = (l.evaluate(metrics, baseline), r.evaluate(metrics, baseline));
+                    let passed = le.passed && re.passed;
+                    let detail = if passed {
+                        "all sub-conditions passed".to_string()
+                    } else {
+                        [&le, &re]
+                            .into_iter()
+                            .filter(|e| !e.passed)
+                            .map(|e| e.detail.clone())
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    };
+                    Eval { passed, detail }
+                }
+                Expr::Or(l, r) => {
+                    let (le, re) = (l.evaluate(metrics, baseline), r.evaluate(metrics, baseline));
+                    let passed = le.passed || re.passed;
+                    let detail = if passed {
+                        "at least one sub-condition passed".to_string()
+                    } else {
+                        format!("neither condition passed ({}; {})", le.detail, re.detail)
+                    };
+                    Eval { passed, detail }
+                }
+            }
+        }
+    }
+
+    struct Parser {
+        tokens: Vec<Token>,
+        pos: usize,
+    }
+
+    impl Parser {
+        fn peek(&self) -> Option<&Token> {
+            self.tokens.get(self.pos)
+        }
+
+        fn advance(&mut self) -> Option<Token> {
+            let token = self.tokens.get(self.pos).cloned();
+            self.pos += 1;
+            token
+        }
+
+        fn parse_expr(&mut self) -> Result<Expr> {
+            let mut left = self.parse_and()?;
+            while matches!(self.peek(), Some(Token::Or)) {
+                self.advance();
+                let right = self.parse_and()?;
+                left = Expr::Or(Box::new(left), Box::new(right));
+            }
+            Ok(left)
+        }
+
+        fn parse_and(&mut self) -> Result<Expr> {
+            let mut left = self.parse_comparison()?;
+            while matches!(self.peek(), Some(Token::And)) {
+                self.advance();
+                let right = self.parse_comparison()?;
+                left = Expr::And(Box::new(left), Box::new(right));
+            }
+            Ok(left)
+        }
+
+        fn parse_comparison(&mut self) -> Result<Expr> {
+            let lhs = self.parse_sum()?;
+            let op = match self.advance() {
+                Some(Token::Ge) => CompOp::Ge,
+                Some(Token::Le) => CompOp::Le,
+                Some(Token::Eq) => CompOp::Eq,
+                Some(Token::Gt) => CompOp::Gt,
+                Some(Token::Lt) => CompOp::Lt,
+                other => return Err(anyhow::anyhow!("Expected a comparison operator, found {:?}", other)),
+            };
+            let rhs = self.parse_sum()?;
+            Ok(Expr::Compare(lhs, op, rhs))
+        }
+
+        fn parse_sum(&mut self) -> Result<Value> {
+            let mut left = self.parse_term()?;
+            loop {
+                match self.peek() {
+                    Some(Token::Plus) => {
+                        self.advance();
+                        let right = self.parse_term()?;
+                        left = Value::Add(Box::new(left), Box::new(right));
+                    }
+                    Some(Token::Minus) => {
+                        self.advance();
+                        let right = self.parse_term()?;
+                        left = Value::Sub(Box::new(left), Box::new(right));
+                    }
+                    _ => break,
+                }
+            }
+            Ok(left)
+        }
+
+        fn parse_term(&mut self) -> Result<Value> {
+            match self.advance() {
+                Some(Token::Number(n)) => Ok(Value::Number(n)),
+                Some(Token::Ident(name)) => match name.strip_prefix("baseline.") {
+                    Some(metric) => Ok(Value::Baseline(metric.to_string())),
+                    None => Ok(Value::Metric(name)),
+                },
+                other => Err(anyhow::anyhow!("Expected a number or metric name, found {:?}", other)),
+            }
+        }
+    }
+
+    /// Parse a predicate expression into an evaluable [`Expr`].
+    pub(super) fn parse(input: &str) -> Result<Expr> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_expr()?;
+
+        if parser.pos != parser.tokens.len() {
+            return Err(anyhow::anyhow!(
+                "Unexpected trailing tokens in expression '{}'",
+                input
+            ));
+        }
+
+        Ok(expr)
+    }
+}
+
 /// Pipeline execution result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PipelineResult {
@@ -134,6 +572,9 @@ pub struct StageResult {
     pub error: Option<String>,
     /// Stage artifacts
     pub artifacts: Vec<PathBuf>,
+    /// Number of attempts made, including the first (1 if it succeeded or
+    /// failed without retrying)
+    pub attempts: usize,
 }
 
 impl StageResult {
@@ -146,6 +587,7 @@ impl StageResult {
             metrics: HashMap::new(),
             error: None,
             artifacts: Vec::new(),
+            attempts: 1,
         }
     }
 
@@ -179,6 +621,124 @@ impl StageResult {
         self.artifacts.push(path);
         self
     }
+
+    /// Set attempt count
+    pub fn with_attempts(mut self, attempts: usize) -> Self {
+        self.attempts = attempts;
+        self
+    }
+}
+
+/// Per-stage retry and timeout policy, applied uniformly to every stage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageRetryPolicy {
+    /// Number of retries after an initial failure (0 = no retries)
+    pub max_retries: usize,
+    /// Delay in seconds before the first retry; doubles each subsequent attempt
+    pub base_delay_secs: u64,
+    /// Upper bound on the backoff delay, in seconds
+    pub max_delay_secs: u64,
+    /// Per-attempt timeout in seconds; 0 means no timeout
+    pub timeout_secs: u64,
+}
+
+impl Default for StageRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay_secs: 2,
+            max_delay_secs: 30,
+            timeout_secs: 0,
+        }
+    }
+}
+
+impl StageRetryPolicy {
+    /// Create a new policy allowing up to `max_retries` retries
+    pub fn new(max_retries: usize) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// Set the base retry delay
+    pub fn base_delay_secs(mut self, secs: u64) -> Self {
+        self.base_delay_secs = secs;
+        self
+    }
+
+    /// Set the maximum retry delay
+    pub fn max_delay_secs(mut self, secs: u64) -> Self {
+        self.max_delay_secs = secs;
+        self
+    }
+
+    /// Set the per-attempt timeout (0 disables it)
+    pub fn timeout_secs(mut self, secs: u64) -> Self {
+        self.timeout_secs = secs;
+        self
+    }
+
+    /// Exponential backoff delay before the given attempt number (1-based)
+    fn delay_for_attempt(&self, attempt: usize) -> std::time::Duration {
+        let exponent = attempt.saturating_sub(1) as u32;
+        let scaled = self.base_delay_secs.saturating_mul(2u64.saturating_pow(exponent));
+        std::time::Duration::from_secs(scaled.min(self.max_delay_secs))
+    }
+
+    /// The per-attempt timeout, if one is configured
+    fn timeout(&self) -> Option<std::time::Duration> {
+        if self.timeout_secs == 0 {
+            None
+        } else {
+            Some(std::time::Duration::from_secs(self.timeout_secs))
+        }
+    }
+}
+
+/// Run `attempt_fn` repeatedly according to `policy`, applying the
+/// per-attempt timeout (if any) and an exponential backoff delay between
+/// attempts. Always resolves to a `StageResult` — a failure that survives
+/// `policy.max_retries` attempts is folded into the result rather than
+/// propagated, so callers never need to handle an `Err` here.
+async fn retry_stage<F, Fut>(policy: &StageRetryPolicy, stage: PipelineStage, mut attempt_fn: F) -> StageResult
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<StageResult>>,
+{
+    let mut attempt = 0usize;
+    loop {
+        attempt += 1;
+        let outcome = match policy.timeout() {
+            Some(timeout) => match tokio::time::timeout(timeout, attempt_fn()).await {
+                Ok(result) => result,
+                Err(_) => Err(anyhow::anyhow!("stage {:?} timed out after {:?}", stage, timeout)),
+            },
+            None => attempt_fn().await,
+        };
+
+        match outcome {
+            Ok(mut result) => {
+                result.attempts = attempt;
+                return result;
+            }
+            Err(e) => {
+                if attempt > policy.max_retries {
+                    error!("Stage {:?} failed after {} attempt(s): {}", stage, attempt, e);
+                    return StageResult::new(stage)
+                        .with_error(e.to_string())
+                        .with_attempts(attempt);
+                }
+                let delay = policy.delay_for_attempt(attempt);
+                warn!(
+                    "Stage {:?} attempt {} failed: {}; retrying in {:?}",
+                    stage, attempt, e, delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
 }
 
 /// Notification configuration
@@ -221,6 +781,10 @@ pub struct PipelineConfig {
     pub val_data: PathBuf,
     /// Quality gates
     pub quality_gates: Vec<QualityGate>,
+    /// Expression-based quality gates, for conditions a single min/max
+    /// bound can't express (compound conditions, baseline comparisons)
+    #[serde(default)]
+    pub expression_gates: Vec<ExpressionGate>,
     /// Deployment target directory
     pub deploy_dir: PathBuf,
     /// Artifact storage directory
@@ -233,6 +797,15 @@ pub struct PipelineConfig {
     pub auto_deploy: bool,
     /// Enable rollback on failure
     pub enable_rollback: bool,
+    /// Retry and timeout policy applied to every stage
+    #[serde(default)]
+    pub stage_retry_policy: StageRetryPolicy,
+    /// Stage dependency graph. Defaults to the linear DataPrep -> Training
+    /// -> Validation -> Deployment chain; override to change the dependency
+    /// edges between stages. Nodes are keyed by `PipelineStage`, so each of
+    /// the four built-in stages runs at most once per run.
+    #[serde(default = "PipelineStage::linear_graph")]
+    pub stage_graph: Vec<StageNode>,
 }
 
 impl PipelineConfig {
@@ -244,12 +817,15 @@ impl PipelineConfig {
             train_data: PathBuf::from("data/train.json"),
             val_data: PathBuf::from("data/val.json"),
             quality_gates: Vec::new(),
+            expression_gates: Vec::new(),
             deploy_dir: PathBuf::from("models/production"),
             artifact_dir: PathBuf::from("artifacts"),
             notifications: NotificationConfig::default(),
             max_trials: 100,
             auto_deploy: true,
             enable_rollback: true,
+            stage_retry_policy: StageRetryPolicy::default(),
+            stage_graph: PipelineStage::linear_graph(),
         }
     }
 
@@ -259,6 +835,24 @@ impl PipelineConfig {
         self
     }
 
+    /// Add expression-based quality gate
+    pub fn add_expression_gate(mut self, gate: ExpressionGate) -> Self {
+        self.expression_gates.push(gate);
+        self
+    }
+
+    /// Set the stage retry/timeout policy
+    pub fn with_stage_retry_policy(mut self, policy: StageRetryPolicy) -> Self {
+        self.stage_retry_policy = policy;
+        self
+    }
+
+    /// Override the stage dependency graph (default: the linear chain)
+    pub fn with_stage_graph(mut self, graph: Vec<StageNode>) -> Self {
+        self.stage_graph = graph;
+        self
+    }
+
     /// Load from YAML file
     pub fn from_yaml(path: impl AsRef<Path>) -> Result<Self> {
         let content = std::fs::read_to_string(path.as_ref())
@@ -293,6 +887,13 @@ pub struct PipelineState {
     pub failed_runs: usize,
     /// Currently deployed model
     pub deployed_model: Option<PathBuf>,
+    /// When the most recent run finished, regardless of outcome
+    pub last_run: Option<DateTime<Utc>>,
+    /// When `PipelineScheduler` will next trigger a run, if scheduling is active
+    pub next_run: Option<DateTime<Utc>>,
+    /// Validation metrics from the last successful run, used as the
+    /// `baseline.<metric>` values in expression-based quality gates
+    pub last_metrics: Option<HashMap<String, f64>>,
 }
 
 impl Default for PipelineState {
@@ -306,6 +907,9 @@ impl Default for PipelineState {
             successful_runs: 0,
             failed_runs: 0,
             deployed_model: None,
+            last_run: None,
+            next_run: None,
+            last_metrics: None,
         }
     }
 }
@@ -324,17 +928,26 @@ impl PipelineState {
     }
 
     /// Mark run as completed
-    pub fn complete_run(&mut self, success: bool, deployed_model: Option<PathBuf>) {
+    pub fn complete_run(
+        &mut self,
+        success: bool,
+        deployed_model: Option<PathBuf>,
+        validation_metrics: Option<HashMap<String, f64>>,
+    ) {
         if success {
             self.successful_runs += 1;
             self.last_success = Some(Utc::now());
             if let Some(model) = deployed_model {
                 self.deployed_model = Some(model);
             }
+            if let Some(metrics) = validation_metrics {
+                self.last_metrics = Some(metrics);
+            }
         } else {
             self.failed_runs += 1;
             self.last_failure = Some(Utc::now());
         }
+        self.last_run = Some(Utc::now());
         self.current_stage = None;
         self.current_run_id = None;
     }
@@ -357,6 +970,106 @@ impl PipelineState {
 }
 
 /// Automated optimization pipeline
+/// Prometheus metrics for an `OptimizationPipeline`
+#[derive(Clone)]
+pub struct PipelineMetrics {
+    /// Total pipeline runs, by result
+    pub runs_success: IntCounter,
+    pub runs_failure: IntCounter,
+    /// Per-stage execution duration, in seconds
+    pub stage_duration: HistogramVec,
+    /// Currently-running stage, encoded as its `PipelineStage::all_stages()`
+    /// index (0=DataPrep, 1=Training, 2=Validation, 3=Deployment); -1 when
+    /// no run is in progress
+    pub current_stage: IntGauge,
+    /// Total rollbacks performed
+    pub rollbacks_total: IntCounter,
+    /// Total quality gate failures (min/max or expression gates)
+    pub quality_gate_failures_total: IntCounter,
+    /// Registry these metrics are registered with
+    pub registry: Registry,
+}
+
+impl PipelineMetrics {
+    fn new() -> Result<Self> {
+        let registry = Registry::new();
+
+        let runs_success = IntCounter::with_opts(Opts::new(
+            "pipeline_runs_success_total",
+            "Total pipeline runs that succeeded",
+        ))?;
+        registry.register(Box::new(runs_success.clone()))?;
+
+        let runs_failure = IntCounter::with_opts(Opts::new(
+            "pipeline_runs_failure_total",
+            "Total pipeline runs that failed",
+        ))?;
+        registry.register(Box::new(runs_failure.clone()))?;
+
+        let stage_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "pipeline_stage_duration_seconds",
+                "Per-stage execution duration in seconds",
+            )
+            .buckets(vec![0.1, 0.5, 1.0, 5.0, 15.0, 30.0, 60.0, 180.0, 600.0]),
+            &["stage"],
+        )?;
+        registry.register(Box::new(stage_duration.clone()))?;
+
+        let current_stage = IntGauge::with_opts(Opts::new(
+            "pipeline_current_stage",
+            "Index of the currently-running stage in PipelineStage::all_stages() order, -1 if idle",
+        ))?;
+        current_stage.set(-1);
+        registry.register(Box::new(current_stage.clone()))?;
+
+        let rollbacks_total = IntCounter::with_opts(Opts::new(
+            "pipeline_rollbacks_total",
+            "Total rollbacks to a previous model",
+        ))?;
+        registry.register(Box::new(rollbacks_total.clone()))?;
+
+        let quality_gate_failures_total = IntCounter::with_opts(Opts::new(
+            "pipeline_quality_gate_failures_total",
+            "Total quality gate failures (min/max or expression gates)",
+        ))?;
+        registry.register(Box::new(quality_gate_failures_total.clone()))?;
+
+        Ok(Self {
+            runs_success,
+            runs_failure,
+            stage_duration,
+            current_stage,
+            rollbacks_total,
+            quality_gate_failures_total,
+            registry,
+        })
+    }
+
+    /// Record the start of `stage`, for the `current_stage` gauge
+    fn record_stage_start(&self, stage: PipelineStage) {
+        let index = PipelineStage::all_stages()
+            .iter()
+            .position(|s| *s == stage)
+            .unwrap_or(0) as i64;
+        self.current_stage.set(index);
+    }
+
+    /// Record that no stage is currently running
+    fn record_idle(&self) {
+        self.current_stage.set(-1);
+    }
+
+    /// Record a completed run
+    fn record_run(&self, success: bool) {
+        if success {
+            self.runs_success.inc();
+        } else {
+            self.runs_failure.inc();
+        }
+    }
+}
+
 pub struct OptimizationPipeline {
     /// Pipeline configuration
     config: PipelineConfig,
@@ -366,6 +1079,11 @@ pub struct OptimizationPipeline {
     state_file: PathBuf,
     /// HTTP client for notifications
     http_client: reqwest::Client,
+    /// Held for the duration of `execute`, so `try_execute` can detect an
+    /// in-progress run and skip instead of overlapping it
+    run_lock: Arc<tokio::sync::Mutex<()>>,
+    /// Prometheus metrics for this pipeline
+    metrics: PipelineMetrics,
 }
 
 impl OptimizationPipeline {
@@ -378,9 +1096,22 @@ impl OptimizationPipeline {
             state: Arc::new(RwLock::new(state)),
             state_file,
             http_client: reqwest::Client::new(),
+            run_lock: Arc::new(tokio::sync::Mutex::new(())),
+            metrics: PipelineMetrics::new()?,
         })
     }
 
+    /// Render current metrics in Prometheus text exposition format
+    pub fn metrics_text(&self) -> Result<String> {
+        let encoder = TextEncoder::new();
+        let metric_families = self.metrics.registry.gather();
+        let mut buffer = Vec::new();
+        encoder
+            .encode(&metric_families, &mut buffer)
+            .context("Failed to encode metrics")?;
+        String::from_utf8(buffer).context("Failed to convert metrics to UTF-8")
+    }
+
     /// Execute complete pipeline
     pub async fn execute(&self) -> Result<PipelineResult> {
         let run_id = format!("run_{}", Utc::now().timestamp());
@@ -402,68 +1133,101 @@ impl OptimizationPipeline {
             deployed_model: None,
         };
 
-        // Execute stages
-        for stage in PipelineStage::all_stages() {
+        // Execute stages in dependency order, running every stage whose
+        // dependencies are already satisfied concurrently as one "layer".
+        // The default `stage_graph` is just the linear DataPrep -> Training
+        // -> Validation -> Deployment chain, so a simple config behaves
+        // exactly like before.
+        let graph = self.config.stage_graph.clone();
+        let mut completed: HashSet<PipelineStage> = HashSet::new();
+        let mut pipeline_failed = false;
+
+        while completed.len() < graph.len() {
+            let layer: Vec<PipelineStage> = graph
+                .iter()
+                .filter(|node| !completed.contains(&node.stage))
+                .filter(|node| node.depends_on.iter().all(|dep| completed.contains(dep)))
+                .map(|node| node.stage)
+                .collect();
+
+            if layer.is_empty() {
+                error!("Stage graph has unresolved dependencies (cycle or missing node); stopping pipeline");
+                pipeline_failed = true;
+                break;
+            }
+
             // Update state
             {
                 let mut state = self.state.write().await;
-                state.update_stage(stage);
+                state.update_stage(layer[0]);
                 state.save(&self.state_file)?;
             }
+            self.metrics.record_stage_start(layer[0]);
+
+            info!("Executing stage layer: {:?}", layer);
 
-            info!("Executing stage: {:?}", stage);
+            let layer_results = futures::future::join_all(
+                layer
+                    .iter()
+                    .map(|&stage| self.execute_stage_with_retry(stage, &run_id)),
+            )
+            .await;
 
-            let stage_result = match self.execute_stage(stage, &run_id).await {
-                Ok(r) => r,
-                Err(e) => {
-                    error!("Stage {:?} failed: {}", stage, e);
-                    let mut r = StageResult::new(stage);
-                    r.error = Some(e.to_string());
-                    r
+            for stage_result in layer_results {
+                let stage = stage_result.stage;
+                let stage_success = stage_result.success;
+
+                // Extract deployed model from deployment stage artifacts
+                if stage == PipelineStage::Deployment && stage_success {
+                    if let Some(artifact) = stage_result.artifacts.first() {
+                        result.deployed_model = Some(artifact.clone());
+                    }
                 }
-            };
 
-            let stage_success = stage_result.success;
+                result.stage_results.insert(stage, stage_result);
+                completed.insert(stage);
 
-            // Extract deployed model from deployment stage artifacts
-            if stage == PipelineStage::Deployment && stage_success {
-                if let Some(artifact) = stage_result.artifacts.first() {
-                    result.deployed_model = Some(artifact.clone());
+                if !stage_success {
+                    error!("Stage {:?} failed, stopping pipeline", stage);
+                    pipeline_failed = true;
                 }
             }
 
-            result.stage_results.insert(stage, stage_result);
-
-            if !stage_success {
-                error!("Stage {:?} failed, stopping pipeline", stage);
+            if pipeline_failed {
                 break;
             }
 
-            // Check quality gates after validation stage
-            if stage == PipelineStage::Validation {
+            // Check quality gates for any validation stage that just ran
+            if layer.contains(&PipelineStage::Validation) {
                 if let Err(e) = self.check_quality_gates(&result).await {
                     error!("Quality gates failed: {}", e);
-                    let mut validation_result =
+                    self.metrics.quality_gate_failures_total.inc();
+                    let validation_result =
                         result.stage_results.get_mut(&PipelineStage::Validation).unwrap();
                     validation_result.success = false;
                     validation_result.error = Some(format!("Quality gates failed: {}", e));
+                    pipeline_failed = true;
                     break;
                 }
             }
         }
 
+        self.metrics.record_idle();
+
         // Determine overall success
-        result.success = result
-            .stage_results
-            .values()
-            .all(|r| r.success);
+        result.success = !pipeline_failed && result.stage_results.values().all(|r| r.success);
+        self.metrics.record_run(result.success);
 
         result.completed_at = Some(Utc::now());
 
         // Update final state
         {
+            let validation_metrics = result
+                .stage_results
+                .get(&PipelineStage::Validation)
+                .map(|r| r.metrics.clone());
             let mut state = self.state.write().await;
-            state.complete_run(result.success, result.deployed_model.clone());
+            state.complete_run(result.success, result.deployed_model.clone(), validation_metrics);
             state.save(&self.state_file)?;
         }
 
@@ -483,6 +1247,40 @@ impl OptimizationPipeline {
         Ok(result)
     }
 
+    /// Execute the pipeline unless a run is already in progress.
+    ///
+    /// Used by [`PipelineScheduler`] so an overlapping trigger is skipped
+    /// rather than queued behind the run in progress. Returns `Ok(None)`
+    /// when a run was skipped for that reason.
+    pub async fn try_execute(&self) -> Result<Option<PipelineResult>> {
+        let _guard = match self.run_lock.try_lock() {
+            Ok(guard) => guard,
+            Err(_) => {
+                warn!("Pipeline run already in progress, skipping this trigger");
+                return Ok(None);
+            }
+        };
+
+        self.execute().await.map(Some)
+    }
+
+    /// Record when the next scheduled run will fire, so `get_state()`
+    /// reflects it for operators. Passing `None` clears it (e.g. on stop).
+    pub async fn set_next_run(&self, next_run: Option<DateTime<Utc>>) -> Result<()> {
+        let mut state = self.state.write().await;
+        state.next_run = next_run;
+        state.save(&self.state_file)
+    }
+
+    /// Execute a stage, retrying on failure (and on timeout, if configured)
+    /// according to `self.config.stage_retry_policy`. Unlike `execute_stage`,
+    /// this never returns `Err` — a failure that exhausts its retries is
+    /// folded into the returned `StageResult` with `error` set.
+    async fn execute_stage_with_retry(&self, stage: PipelineStage, run_id: &str) -> StageResult {
+        let policy = self.config.stage_retry_policy.clone();
+        retry_stage(&policy, stage, || self.execute_stage(stage, run_id)).await
+    }
+
     /// Execute a single pipeline stage
     async fn execute_stage(&self, stage: PipelineStage, run_id: &str) -> Result<StageResult> {
         let start = std::time::Instant::now();
@@ -636,10 +1434,13 @@ impl OptimizationPipeline {
 
                     tokio::fs::create_dir_all(&self.config.deploy_dir).await?;
 
-                    let deploy_path = self.config.deploy_dir.join(format!(
-                        "model_{}.pkl",
-                        Utc::now().timestamp()
-                    ));
+                    // Named after run_id (not a fresh timestamp) so retrying
+                    // this stage for the same run overwrites the same file
+                    // instead of leaving one orphaned artifact per attempt.
+                    let deploy_path = self
+                        .config
+                        .deploy_dir
+                        .join(format!("model_{}.pkl", run_id));
 
                     tokio::fs::copy(&model_path, &deploy_path).await?;
 
@@ -663,6 +1464,10 @@ impl OptimizationPipeline {
         }
 
         result.duration_secs = start.elapsed().as_secs_f64();
+        self.metrics
+            .stage_duration
+            .with_label_values(&[stage.name()])
+            .observe(result.duration_secs);
         Ok(result)
     }
 
@@ -698,6 +1503,28 @@ impl OptimizationPipeline {
             }
         }
 
+        if !self.config.expression_gates.is_empty() {
+            let baseline = self
+                .state
+                .read()
+                .await
+                .last_metrics
+                .clone()
+                .unwrap_or_default();
+
+            for gate in &self.config.expression_gates {
+                if let Err(e) = gate.check(&validation_result.metrics, &baseline) {
+                    if gate.required {
+                        return Err(e);
+                    } else {
+                        warn!("{}", e);
+                    }
+                } else {
+                    info!("Quality gate '{}' passed: {}", gate.name, gate.expression);
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -755,6 +1582,7 @@ impl OptimizationPipeline {
     /// Rollback to previous deployment
     async fn rollback(&self) -> Result<()> {
         info!("Rolling back to previous deployment...");
+        self.metrics.rollbacks_total.inc();
 
         let state = self.state.read().await;
 
@@ -793,3 +1621,178 @@ impl OptimizationPipeline {
         &self.config
     }
 }
+
+/// Drives [`OptimizationPipeline::try_execute`] on a cron schedule.
+///
+/// Each tick computes the next run time from the `cron` expression, sleeps
+/// until then, and triggers a run. A run that's still in progress when the
+/// next tick fires is skipped rather than queued, since `try_execute`
+/// only ever lets one run proceed at a time. `PipelineState::next_run` is
+/// kept up to date throughout so `OptimizationPipeline::get_state` reflects
+/// when the next run will fire.
+pub struct PipelineScheduler {
+    pipeline: Arc<OptimizationPipeline>,
+    schedule: cron::Schedule,
+    task: tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl PipelineScheduler {
+    /// Create a scheduler for `pipeline` that fires on `cron_expr`.
+    ///
+    /// `cron_expr` uses the `cron` crate's 7-field format (seconds first),
+    /// e.g. `"0 0 0 * * * *"` for daily at midnight.
+    pub fn new(pipeline: Arc<OptimizationPipeline>, cron_expr: &str) -> Result<Self> {
+        let schedule = cron::Schedule::from_str(cron_expr)
+            .context("Failed to parse cron expression")?;
+
+        Ok(Self {
+            pipeline,
+            schedule,
+            task: tokio::sync::Mutex::new(None),
+        })
+    }
+
+    /// Start the background scheduling loop. No-op if already started.
+    pub async fn start(&self) -> Result<()> {
+        let mut task = self.task.lock().await;
+        if task.is_some() {
+            return Ok(());
+        }
+
+        let pipeline = Arc::clone(&self.pipeline);
+        let schedule = self.schedule.clone();
+
+        *task = Some(tokio::spawn(async move {
+            loop {
+                let Some(next_run) = schedule.upcoming(Utc).next() else {
+                    warn!("Cron schedule has no upcoming runs, stopping scheduler");
+                    break;
+                };
+
+                if let Err(e) = pipeline.set_next_run(Some(next_run)).await {
+                    error!("Failed to persist next scheduled run time: {}", e);
+                }
+
+                let wait = (next_run - Utc::now())
+                    .to_std()
+                    .unwrap_or(std::time::Duration::from_secs(0));
+                tokio::time::sleep(wait).await;
+
+                info!("Scheduled trigger fired for run at {}", next_run);
+                match pipeline.try_execute().await {
+                    Ok(Some(result)) => info!(
+                        "Scheduled pipeline run {} completed: success={}",
+                        result.run_id, result.success
+                    ),
+                    Ok(None) => info!("Skipped scheduled run: previous run still in progress"),
+                    Err(e) => error!("Scheduled pipeline run failed: {}", e),
+                }
+            }
+        }));
+
+        Ok(())
+    }
+
+    /// Stop the background scheduling loop. No-op if not running.
+    pub async fn stop(&self) {
+        let mut task = self.task.lock().await;
+        if let Some(handle) = task.take() {
+            handle.abort();
+            if let Err(e) = self.pipeline.set_next_run(None).await {
+                error!("Failed to clear next scheduled run time: {}", e);
+            }
+            info!("Pipeline scheduler stopped");
+        }
+    }
+
+    /// Whether the background scheduling loop is currently running.
+    pub async fn is_running(&self) -> bool {
+        self.task.lock().await.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn pipeline_metrics_records_and_exports_expected_series() {
+        let metrics = PipelineMetrics::new().unwrap();
+
+        metrics.record_stage_start(PipelineStage::Training);
+        assert_eq!(metrics.current_stage.get(), 1);
+        metrics.record_idle();
+        assert_eq!(metrics.current_stage.get(), -1);
+
+        metrics.record_run(true);
+        metrics.record_run(false);
+        metrics.rollbacks_total.inc();
+        metrics.quality_gate_failures_total.inc();
+        metrics
+            .stage_duration
+            .with_label_values(&[PipelineStage::Training.name()])
+            .observe(1.5);
+
+        let encoder = TextEncoder::new();
+        let mut buffer = Vec::new();
+        encoder.encode(&metrics.registry.gather(), &mut buffer).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("pipeline_runs_success_total 1"));
+        assert!(output.contains("pipeline_runs_failure_total 1"));
+        assert!(output.contains("pipeline_rollbacks_total 1"));
+        assert!(output.contains("pipeline_quality_gate_failures_total 1"));
+        assert!(output.contains("pipeline_stage_duration_seconds"));
+        assert!(output.contains(r#"stage="training""#));
+    }
+
+    #[test]
+    fn linear_graph_chains_each_stage_to_the_previous_one() {
+        let graph = PipelineStage::linear_graph();
+        assert_eq!(graph.len(), PipelineStage::all_stages().len());
+
+        assert_eq!(graph[0].stage, PipelineStage::DataPrep);
+        assert!(graph[0].depends_on.is_empty());
+
+        for (prev, node) in graph.iter().zip(graph.iter().skip(1)) {
+            assert_eq!(node.depends_on, vec![prev.stage]);
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_stage_succeeds_after_one_failure() {
+        let policy = StageRetryPolicy::new(1).base_delay_secs(0);
+        let calls = AtomicUsize::new(0);
+
+        let result = retry_stage(&policy, PipelineStage::Training, || {
+            let attempt = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                if attempt == 1 {
+                    Err(anyhow::anyhow!("transient failure"))
+                } else {
+                    Ok(StageResult::new(PipelineStage::Training).with_success())
+                }
+            }
+        })
+        .await;
+
+        assert!(result.success);
+        assert_eq!(result.attempts, 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_stage_gives_up_after_max_retries() {
+        let policy = StageRetryPolicy::new(2).base_delay_secs(0);
+
+        let result = retry_stage(&policy, PipelineStage::Training, || async {
+            Err(anyhow::anyhow!("persistent failure"))
+        })
+        .await;
+
+        assert!(!result.success);
+        assert_eq!(result.attempts, 3);
+        assert_eq!(result.error, Some("persistent failure".to_string()));
+    }
+}
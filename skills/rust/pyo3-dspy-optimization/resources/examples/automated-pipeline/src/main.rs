@@ -8,10 +8,10 @@
 
 use anyhow::{Context, Result};
 use automated_pipeline::{
-    OptimizationPipeline, PipelineConfig, PipelineStage, QualityGate,
+    OptimizationPipeline, PipelineConfig, PipelineScheduler, PipelineStage, QualityGate,
 };
 use std::path::PathBuf;
-use tokio_cron_scheduler::{Job, JobScheduler};
+use std::sync::Arc;
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 
@@ -30,6 +30,8 @@ enum Command {
     Init,
     /// Validate configuration
     Validate,
+    /// Print Prometheus metrics
+    Metrics,
 }
 
 impl Command {
@@ -57,6 +59,7 @@ impl Command {
             }
             "init" => Ok(Self::Init),
             "validate" => Ok(Self::Validate),
+            "metrics" => Ok(Self::Metrics),
             _ => Err(anyhow::anyhow!("Unknown command: {}", args[1])),
         }
     }
@@ -151,28 +154,8 @@ impl PipelineRunner {
     async fn schedule(&self, cron_expr: String) -> Result<()> {
         info!("Starting pipeline scheduler with cron: {}", cron_expr);
 
-        let mut scheduler = JobScheduler::new().await?;
-
-        // Clone paths for job closure
-        let config_path = self.config_path.clone();
-        let state_path = self.state_path.clone();
-
-        // Create scheduled job
-        let job = Job::new_async(cron_expr.as_str(), move |_uuid, _lock| {
-            let config_path = config_path.clone();
-            let state_path = state_path.clone();
-
-            Box::pin(async move {
-                info!("Scheduled pipeline run starting...");
-
-                match execute_scheduled_run(&config_path, &state_path).await {
-                    Ok(_) => info!("Scheduled pipeline run completed successfully"),
-                    Err(e) => error!("Scheduled pipeline run failed: {}", e),
-                }
-            })
-        })?;
-
-        scheduler.add(job).await?;
+        let pipeline = Arc::new(self.create_pipeline().await?);
+        let scheduler = PipelineScheduler::new(pipeline, &cron_expr)?;
         scheduler.start().await?;
 
         println!("Pipeline scheduler started with cron: {}", cron_expr);
@@ -182,7 +165,7 @@ impl PipelineRunner {
         tokio::signal::ctrl_c().await?;
 
         info!("Stopping scheduler...");
-        scheduler.shutdown().await?;
+        scheduler.stop().await;
 
         Ok(())
     }
@@ -226,6 +209,10 @@ impl PipelineRunner {
             println!("Last Failure: {}", last_failure);
         }
 
+        if let Some(next_run) = state.next_run {
+            println!("Next Scheduled Run: {}", next_run);
+        }
+
         if let Some(model_path) = &state.deployed_model {
             println!("\nDeployed Model: {}", model_path.display());
         }
@@ -252,6 +239,13 @@ impl PipelineRunner {
         Ok(())
     }
 
+    /// Print Prometheus metrics in text exposition format
+    async fn metrics(&self) -> Result<()> {
+        let pipeline = self.create_pipeline().await?;
+        print!("{}", pipeline.metrics_text()?);
+        Ok(())
+    }
+
     /// Show pipeline history
     async fn history(&self, limit: usize) -> Result<()> {
         println!("\n=== Pipeline History (last {}) ===", limit);
@@ -398,20 +392,6 @@ impl PipelineRunner {
     }
 }
 
-/// Execute a scheduled pipeline run
-async fn execute_scheduled_run(config_path: &PathBuf, state_path: &PathBuf) -> Result<()> {
-    let config = PipelineConfig::from_yaml(config_path)?;
-    let pipeline = OptimizationPipeline::new(config, state_path.clone())?;
-
-    let result = pipeline.execute().await?;
-
-    if !result.success {
-        error!("Scheduled pipeline run failed");
-    }
-
-    Ok(())
-}
-
 /// Print usage information
 fn print_usage() {
     println!("Automated DSPy Pipeline");
@@ -459,6 +439,7 @@ async fn main() -> Result<()> {
         Command::History { limit } => runner.history(limit).await,
         Command::Init => runner.init().await,
         Command::Validate => runner.validate().await,
+        Command::Metrics => runner.metrics().await,
     };
 
     if let Err(e) = result {
@@ -4,11 +4,16 @@
 //! multiple metrics, statistical analysis, and model comparison.
 
 use anyhow::{Context, Result};
+use lru::LruCache;
+use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::BufReader;
-use std::path::Path;
+use std::io::{BufRead, BufReader};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 /// Trait for evaluation metrics
 ///
@@ -87,6 +92,31 @@ impl TestSet {
         })
     }
 
+    /// Load test set from a newline-delimited JSON file, parsing one
+    /// `TestExample` at a time rather than materializing the whole file.
+    /// Suitable for multi-gigabyte datasets that would OOM `from_json`.
+    pub fn from_jsonl<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path.as_ref())
+            .context("Failed to open test set JSONL file")?;
+        let reader = BufReader::new(file);
+        let mut examples = Vec::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.context("Failed to read JSONL line")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let example: TestExample = serde_json::from_str(&line)
+                .with_context(|| format!("Failed to parse JSONL record at line {}", i + 1))?;
+            examples.push(example);
+        }
+
+        Ok(Self {
+            examples,
+            metadata: HashMap::new(),
+        })
+    }
+
     /// Add example to test set
     pub fn add_example(&mut self, example: TestExample) {
         self.examples.push(example);
@@ -140,6 +170,20 @@ impl Default for TestSet {
     }
 }
 
+/// Best-effort human-readable message from a caught panic payload, for
+/// recording into [`EvaluationResult::errors`]. Panics most commonly carry a
+/// `&str` (from `panic!("literal")`) or `String` (from `panic!("{}", ..)`);
+/// anything else (a custom payload type) falls back to a generic message.
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "predictor panicked with a non-string payload".to_string()
+    }
+}
+
 /// Result of evaluating a model on a test set
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvaluationResult {
@@ -155,16 +199,54 @@ pub struct EvaluationResult {
     pub percentile_75: f64,
     pub percentile_95: f64,
     pub individual_scores: Vec<f64>,
+    /// Compact quantile summary backing the percentile fields when
+    /// `individual_scores` isn't retained (see [`Self::from_scores_with_options`]).
+    /// `None` whenever the result was built with raw scores available, since
+    /// the percentile fields above are already exact in that case.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<TDigest>,
     #[serde(default)]
     pub metadata: HashMap<String, serde_json::Value>,
+    /// Examples whose predictor call panicked, as `(example_index, panic_message)`.
+    /// Populated by [`EvaluationHarness::evaluate`], which isolates each
+    /// prediction with `catch_unwind` and scores a panicking example as 0.0
+    /// rather than aborting the whole run. Empty for results built directly
+    /// from scores (e.g. via [`Self::from_scores`]).
+    #[serde(default)]
+    pub errors: Vec<(usize, String)>,
 }
 
 impl EvaluationResult {
-    /// Create evaluation result from individual scores
+    /// Create evaluation result from individual scores, retaining the raw
+    /// `scores` vector and computing exact percentiles from it. Equivalent
+    /// to `from_scores_with_options(.., retain_scores: true)`.
     pub fn from_scores(
         model_name: String,
         metric_name: String,
         scores: Vec<f64>,
+    ) -> Self {
+        Self::from_scores_with_options(model_name, metric_name, scores, true)
+    }
+
+    /// Create evaluation result from individual scores, with `retain_scores`
+    /// controlling the memory/precision trade-off.
+    ///
+    /// `retain_scores: true` keeps `individual_scores` (needed by
+    /// `ComparisonReport`'s Welch's t-test and other bootstrap/statistical
+    /// paths that require raw samples) and computes exact percentiles by
+    /// sorting them. `retain_scores: false` discards the scores after
+    /// folding each one into a [`TDigest`], so `individual_scores` ends up
+    /// empty and the percentile fields are estimates read off the digest
+    /// instead of exact order statistics — the right trade for
+    /// million-example evaluations where keeping every score alive (and
+    /// serializing it into a JSON report) isn't affordable. The digest
+    /// itself is kept on `digest` either way scores aren't retained, so
+    /// callers can still re-query other quantiles later.
+    pub fn from_scores_with_options(
+        model_name: String,
+        metric_name: String,
+        scores: Vec<f64>,
+        retain_scores: bool,
     ) -> Self {
         let num_examples = scores.len();
         let mean = calculate_mean(&scores);
@@ -172,28 +254,50 @@ impl EvaluationResult {
         let min = scores.iter().cloned().fold(f64::INFINITY, f64::min);
         let max = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
 
-        let mut sorted_scores = scores.clone();
-        sorted_scores.sort_by(|a, b| a.partial_cmp(b).unwrap());
-
-        let percentile_25 = calculate_percentile(&sorted_scores, 0.25);
-        let percentile_50 = calculate_percentile(&sorted_scores, 0.50);
-        let percentile_75 = calculate_percentile(&sorted_scores, 0.75);
-        let percentile_95 = calculate_percentile(&sorted_scores, 0.95);
+        if retain_scores {
+            let mut sorted_scores = scores.clone();
+            sorted_scores.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            Self {
+                model_name,
+                metric_name,
+                num_examples,
+                mean,
+                std_dev,
+                min,
+                max,
+                percentile_25: calculate_percentile(&sorted_scores, 0.25),
+                percentile_50: calculate_percentile(&sorted_scores, 0.50),
+                percentile_75: calculate_percentile(&sorted_scores, 0.75),
+                percentile_95: calculate_percentile(&sorted_scores, 0.95),
+                individual_scores: scores,
+                digest: None,
+                metadata: HashMap::new(),
+                errors: Vec::new(),
+            }
+        } else {
+            let mut digest = TDigest::new(100);
+            for &score in &scores {
+                digest.update(score);
+            }
 
-        Self {
-            model_name,
-            metric_name,
-            num_examples,
-            mean,
-            std_dev,
-            min,
-            max,
-            percentile_25,
-            percentile_50,
-            percentile_75,
-            percentile_95,
-            individual_scores: scores,
-            metadata: HashMap::new(),
+            Self {
+                model_name,
+                metric_name,
+                num_examples,
+                mean,
+                std_dev,
+                min,
+                max,
+                percentile_25: digest.quantile(0.25),
+                percentile_50: digest.quantile(0.50),
+                percentile_75: digest.quantile(0.75),
+                percentile_95: digest.quantile(0.95),
+                individual_scores: Vec::new(),
+                digest: Some(digest),
+                metadata: HashMap::new(),
+                errors: Vec::new(),
+            }
         }
     }
 
@@ -212,6 +316,9 @@ impl EvaluationResult {
         println!("  50th: {:.4}", self.percentile_50);
         println!("  75th: {:.4}", self.percentile_75);
         println!("  95th: {:.4}", self.percentile_95);
+        if !self.errors.is_empty() {
+            println!("Failed Examples: {} (scored 0.0, predictor panicked)", self.errors.len());
+        }
     }
 
     /// Save results to JSON file
@@ -222,6 +329,76 @@ impl EvaluationResult {
             .context("Failed to write results JSON")?;
         Ok(())
     }
+
+    /// Compare this result against a `baseline`, example by example, to see
+    /// exactly which examples improved, regressed, or stayed the same —
+    /// useful when the aggregate `mean` barely moves but individual examples
+    /// shifted a lot in opposite directions. Both results must have retained
+    /// `individual_scores` (see [`Self::from_scores_with_options`]) over the
+    /// same ordered test set; mismatched lengths are almost always two
+    /// different test sets, so this errors clearly rather than diffing
+    /// unrelated examples against each other.
+    pub fn diff(&self, baseline: &EvaluationResult) -> Result<ExampleDiff> {
+        if self.individual_scores.len() != baseline.individual_scores.len() {
+            anyhow::bail!(
+                "cannot diff results with mismatched example counts ({} vs {}); \
+                 they must come from the same ordered test set",
+                self.individual_scores.len(),
+                baseline.individual_scores.len(),
+            );
+        }
+
+        let mut improved = Vec::new();
+        let mut regressed = Vec::new();
+        let mut unchanged = Vec::new();
+        let mut regressions = Vec::new();
+
+        for (index, (&new_score, &baseline_score)) in self
+            .individual_scores
+            .iter()
+            .zip(baseline.individual_scores.iter())
+            .enumerate()
+        {
+            if new_score > baseline_score {
+                improved.push(index);
+            } else if new_score < baseline_score {
+                regressed.push(index);
+                regressions.push((index, baseline_score - new_score));
+            } else {
+                unchanged.push(index);
+            }
+        }
+
+        regressions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+        Ok(ExampleDiff {
+            improved,
+            regressed,
+            unchanged,
+            regressions,
+        })
+    }
+}
+
+/// Per-example comparison produced by [`EvaluationResult::diff`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExampleDiff {
+    /// Indices where the new result scored higher than the baseline.
+    pub improved: Vec<usize>,
+    /// Indices where the new result scored lower than the baseline.
+    pub regressed: Vec<usize>,
+    /// Indices where the score didn't change.
+    pub unchanged: Vec<usize>,
+    /// Regressed examples as `(index, baseline_score - new_score)`, sorted
+    /// by magnitude of regression, largest first.
+    pub regressions: Vec<(usize, f64)>,
+}
+
+impl ExampleDiff {
+    /// The `n` largest regressions, sorted largest first.
+    pub fn top_regressions(&self, n: usize) -> &[(usize, f64)] {
+        &self.regressions[..n.min(self.regressions.len())]
+    }
 }
 
 /// Comparison report for multiple models
@@ -385,6 +562,113 @@ impl ComparisonReport {
     }
 }
 
+/// Factory for constructing a fresh metric instance. Takes a factory rather
+/// than a `Box<dyn Metric>` directly so the same registry can hand out
+/// independent instances to multiple harnesses.
+type MetricFactory = Box<dyn Fn() -> Box<dyn Metric> + Send + Sync>;
+
+/// Named registry of metric factories, so a harness can be configured by
+/// metric name (e.g. from a config file or CLI flag) instead of wiring up
+/// `Box<dyn Metric>` values by hand.
+pub struct MetricRegistry {
+    factories: HashMap<String, MetricFactory>,
+}
+
+impl MetricRegistry {
+    /// Empty registry with no metrics registered.
+    pub fn new() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Registry pre-populated with this crate's metrics that need no
+    /// external configuration (so not [`SemanticSimilarity`], which needs an
+    /// embedding function).
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register("accuracy", || Box::new(Accuracy));
+        registry.register("exact_match", || Box::new(ExactMatch::new(true)));
+        registry.register("normalized_exact_match", || {
+            Box::new(NormalizedExactMatch::new(TextNormalizer::squad()))
+        });
+        registry.register("f1_score", || Box::new(F1Score));
+        registry.register("bleu", || Box::new(BLEU::new(4)));
+        registry.register("unigram_overlap", || Box::new(UnigramOverlap::new(4)));
+        registry.register("rouge", || Box::new(ROUGE::new("rouge-l")));
+        registry.register("chrf", || Box::new(ChrF::default()));
+        registry.register("meteor", || Box::new(Meteor::new()));
+        registry
+    }
+
+    /// Register a metric factory under `name`. Overwrites any existing
+    /// factory registered under the same name.
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        F: Fn() -> Box<dyn Metric> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.into(), Box::new(factory));
+    }
+
+    /// Build a fresh metric instance by name.
+    pub fn build(&self, name: &str) -> Result<Box<dyn Metric>> {
+        self.factories
+            .get(name)
+            .map(|factory| factory())
+            .with_context(|| format!("Unknown metric: {name}"))
+    }
+}
+
+impl Default for MetricRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs several metrics against the same predictions in a single pass over a
+/// test set, rather than re-running the (potentially expensive) predictor
+/// once per metric. Construct via [`EvaluationHarness::with_metrics`].
+pub struct MultiMetricHarness {
+    metrics: Vec<Box<dyn Metric>>,
+}
+
+impl MultiMetricHarness {
+    /// Evaluate model on test set, computing every configured metric from
+    /// each prediction.
+    pub async fn evaluate<F>(
+        &self,
+        test_set: &TestSet,
+        model_name: &str,
+        mut predictor: F,
+    ) -> Result<HashMap<String, EvaluationResult>>
+    where
+        F: FnMut(&TestExample) -> String,
+    {
+        let mut scores: HashMap<String, Vec<f64>> = self
+            .metrics
+            .iter()
+            .map(|metric| (metric.name().to_string(), Vec::new()))
+            .collect();
+
+        for example in &test_set.examples {
+            let predicted = predictor(example);
+
+            for metric in &self.metrics {
+                let score = metric.compute(&predicted, &example.expected_output);
+                scores.get_mut(metric.name()).unwrap().push(score);
+            }
+        }
+
+        Ok(scores
+            .into_iter()
+            .map(|(name, scores)| {
+                let result = EvaluationResult::from_scores(model_name.to_string(), name.clone(), scores);
+                (name, result)
+            })
+            .collect())
+    }
+}
+
 /// Main evaluation harness
 pub struct EvaluationHarness {
     metric: Box<dyn Metric>,
@@ -396,7 +680,24 @@ impl EvaluationHarness {
         Self { metric }
     }
 
-    /// Evaluate model on test set
+    /// Build a [`MultiMetricHarness`] that evaluates every metric in `names`
+    /// (resolved via `registry`) against the same predictions in a single
+    /// pass over the test set.
+    pub fn with_metrics(registry: &MetricRegistry, names: &[&str]) -> Result<MultiMetricHarness> {
+        let metrics = names
+            .iter()
+            .map(|name| registry.build(name))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(MultiMetricHarness { metrics })
+    }
+
+    /// Evaluate model on test set.
+    ///
+    /// Each prediction is isolated with `catch_unwind`: if `predictor` panics
+    /// on one example, that example is scored 0.0 and recorded in
+    /// [`EvaluationResult::errors`] instead of aborting the whole run, so a
+    /// handful of pathological inputs don't throw away every other score.
     pub async fn evaluate<F>(
         &self,
         test_set: &TestSet,
@@ -407,18 +708,103 @@ impl EvaluationHarness {
         F: FnMut(&TestExample) -> String,
     {
         let mut scores = Vec::new();
+        let mut errors = Vec::new();
 
-        for example in &test_set.examples {
-            let predicted = predictor(example);
-            let score = self.metric.compute(&predicted, &example.expected_output);
-            scores.push(score);
+        for (index, example) in test_set.examples.iter().enumerate() {
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| predictor(example))) {
+                Ok(predicted) => {
+                    let score = self.metric.compute(&predicted, &example.expected_output);
+                    scores.push(score);
+                }
+                Err(panic) => {
+                    errors.push((index, panic_message(&*panic)));
+                    scores.push(0.0);
+                }
+            }
         }
 
-        Ok(EvaluationResult::from_scores(
+        let mut result = EvaluationResult::from_scores(
             model_name.to_string(),
             self.metric.name().to_string(),
             scores,
-        ))
+        );
+        result.errors = errors;
+        Ok(result)
+    }
+
+    /// Evaluate model on test set, grouped by a metadata field so per-stratum
+    /// performance is visible alongside the overall aggregate — e.g. tag
+    /// examples with a `"difficulty"` metadata value and pass `"difficulty"`
+    /// as `strata_key` to see whether a model is failing specifically on hard
+    /// examples. Examples whose metadata is missing `strata_key` (or whose
+    /// value isn't a string) fall into an `"unknown"` bucket. The aggregate
+    /// across every example, regardless of stratum, is included under the
+    /// `"overall"` key. Predictor panics are isolated per-example exactly as
+    /// in [`Self::evaluate`].
+    pub async fn evaluate_stratified<F>(
+        &self,
+        test_set: &TestSet,
+        model_name: &str,
+        strata_key: &str,
+        mut predictor: F,
+    ) -> Result<HashMap<String, EvaluationResult>>
+    where
+        F: FnMut(&TestExample) -> String,
+    {
+        let mut scores_by_stratum: HashMap<String, Vec<f64>> = HashMap::new();
+        let mut errors_by_stratum: HashMap<String, Vec<(usize, String)>> = HashMap::new();
+        let mut overall_scores = Vec::new();
+        let mut overall_errors = Vec::new();
+
+        for (index, example) in test_set.examples.iter().enumerate() {
+            let stratum = example
+                .metadata
+                .get(strata_key)
+                .and_then(|value| value.as_str())
+                .map(|value| value.to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| predictor(example))) {
+                Ok(predicted) => {
+                    let score = self.metric.compute(&predicted, &example.expected_output);
+                    scores_by_stratum.entry(stratum).or_default().push(score);
+                    overall_scores.push(score);
+                }
+                Err(panic) => {
+                    let message = panic_message(&*panic);
+                    scores_by_stratum.entry(stratum.clone()).or_default().push(0.0);
+                    errors_by_stratum
+                        .entry(stratum)
+                        .or_default()
+                        .push((index, message.clone()));
+                    overall_scores.push(0.0);
+                    overall_errors.push((index, message));
+                }
+            }
+        }
+
+        let mut results: HashMap<String, EvaluationResult> = scores_by_stratum
+            .into_iter()
+            .map(|(stratum, scores)| {
+                let mut result = EvaluationResult::from_scores(
+                    model_name.to_string(),
+                    self.metric.name().to_string(),
+                    scores,
+                );
+                result.errors = errors_by_stratum.remove(&stratum).unwrap_or_default();
+                (stratum, result)
+            })
+            .collect();
+
+        let mut overall = EvaluationResult::from_scores(
+            model_name.to_string(),
+            self.metric.name().to_string(),
+            overall_scores,
+        );
+        overall.errors = overall_errors;
+        results.insert("overall".to_string(), overall);
+
+        Ok(results)
     }
 
     /// Evaluate model on test set with batch processing
@@ -449,6 +835,173 @@ impl EvaluationHarness {
             scores,
         ))
     }
+
+    /// Evaluate a model from an iterator of examples without materializing
+    /// the whole test set in memory, for streamed/JSONL-sized datasets.
+    ///
+    /// `mean`/`std_dev` are computed incrementally via Welford's algorithm
+    /// and are numerically equivalent to the batch `evaluate` path.
+    /// Percentiles are estimates read off a [`TDigest`] folded one score at
+    /// a time, and `individual_scores` is always empty since scores are
+    /// never retained — callers that need exact percentiles or raw samples
+    /// (e.g. for `ComparisonReport`'s Welch's t-test) should use `evaluate`
+    /// or `evaluate_batch` instead.
+    pub fn evaluate_streaming<I, F>(
+        &self,
+        examples: I,
+        model_name: &str,
+        mut predictor: F,
+    ) -> Result<EvaluationResult>
+    where
+        I: IntoIterator<Item = TestExample>,
+        F: FnMut(&TestExample) -> String,
+    {
+        let mut stats = RunningStats::new();
+        let mut digest = TDigest::new(100);
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+
+        for example in examples {
+            let predicted = predictor(&example);
+            let score = self.metric.compute(&predicted, &example.expected_output);
+            stats.update(score);
+            digest.update(score);
+            min = min.min(score);
+            max = max.max(score);
+        }
+
+        let num_examples = stats.count;
+        if num_examples == 0 {
+            min = 0.0;
+            max = 0.0;
+        }
+
+        Ok(EvaluationResult {
+            model_name: model_name.to_string(),
+            metric_name: self.metric.name().to_string(),
+            num_examples,
+            mean: stats.mean,
+            std_dev: stats.std_dev(),
+            min,
+            max,
+            percentile_25: digest.quantile(0.25),
+            percentile_50: digest.quantile(0.50),
+            percentile_75: digest.quantile(0.75),
+            percentile_95: digest.quantile(0.95),
+            individual_scores: Vec::new(),
+            digest: Some(digest),
+            metadata: HashMap::new(),
+            errors: Vec::new(),
+        })
+    }
+}
+
+/// Incremental mean/variance accumulator using Welford's online algorithm,
+/// so large or streamed datasets don't need every score held in memory.
+#[derive(Debug, Default)]
+struct RunningStats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        let delta2 = value - self.mean;
+        self.m2 += delta * delta2;
+    }
+
+    /// Sample standard deviation (matches `calculate_std_dev`'s n-1 divisor).
+    fn std_dev(&self) -> f64 {
+        if self.count <= 1 {
+            0.0
+        } else {
+            (self.m2 / (self.count - 1) as f64).sqrt()
+        }
+    }
+}
+
+/// A simplified t-digest: a bounded set of weighted centroids that
+/// approximates a distribution's shape well enough to answer quantile
+/// queries without retaining every value that's been fed in. Memory is
+/// O(`max_centroids`) regardless of how many values are seen, at the cost
+/// of some quantile accuracy relative to sorting the full data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TDigest {
+    centroids: Vec<Centroid>,
+    max_centroids: usize,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl TDigest {
+    pub fn new(max_centroids: usize) -> Self {
+        Self {
+            centroids: Vec::new(),
+            max_centroids: max_centroids.max(1),
+        }
+    }
+
+    /// Fold a single value into the digest, merging the closest pair of
+    /// centroids whenever the digest grows past `max_centroids`.
+    pub fn update(&mut self, value: f64) {
+        let insert_at = self
+            .centroids
+            .partition_point(|c| c.mean < value);
+        self.centroids.insert(insert_at, Centroid { mean: value, weight: 1.0 });
+        self.compress();
+    }
+
+    fn compress(&mut self) {
+        while self.centroids.len() > self.max_centroids {
+            let (merge_idx, _) = self
+                .centroids
+                .windows(2)
+                .enumerate()
+                .map(|(i, pair)| (i, pair[1].mean - pair[0].mean))
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                .expect("at least two centroids while len() > max_centroids >= 1");
+
+            let a = self.centroids[merge_idx];
+            let b = self.centroids[merge_idx + 1];
+            let merged_weight = a.weight + b.weight;
+            let merged_mean = (a.mean * a.weight + b.mean * b.weight) / merged_weight;
+            self.centroids[merge_idx] = Centroid { mean: merged_mean, weight: merged_weight };
+            self.centroids.remove(merge_idx + 1);
+        }
+    }
+
+    /// Estimate the value at quantile `q` (0.0-1.0). Returns `0.0` if no
+    /// values have been seen yet.
+    pub fn quantile(&self, q: f64) -> f64 {
+        let Some(last) = self.centroids.last() else {
+            return 0.0;
+        };
+
+        let total_weight: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = q * total_weight;
+
+        let mut cumulative = 0.0;
+        for centroid in &self.centroids {
+            cumulative += centroid.weight;
+            if cumulative >= target {
+                return centroid.mean;
+            }
+        }
+
+        last.mean
+    }
 }
 
 // Built-in Metrics
@@ -500,6 +1053,111 @@ impl Metric for ExactMatch {
     }
 }
 
+/// Text normalizer for SQuAD-style answer comparison.
+///
+/// Lowercases, strips punctuation, collapses whitespace, and optionally
+/// removes English articles ("a", "an", "the") so that answers differing
+/// only by surface formatting are treated as equivalent.
+#[derive(Debug, Clone)]
+pub struct TextNormalizer {
+    pub lowercase: bool,
+    pub strip_punctuation: bool,
+    pub collapse_whitespace: bool,
+    pub remove_articles: bool,
+}
+
+impl TextNormalizer {
+    /// SQuAD-style normalizer: lowercase, strip punctuation, remove articles,
+    /// collapse whitespace.
+    pub fn squad() -> Self {
+        Self {
+            lowercase: true,
+            strip_punctuation: true,
+            collapse_whitespace: true,
+            remove_articles: true,
+        }
+    }
+
+    /// Apply the configured normalization steps to `text`.
+    pub fn normalize(&self, text: &str) -> String {
+        let mut s = text.trim().to_string();
+
+        if self.lowercase {
+            s = s.to_lowercase();
+        }
+
+        if self.strip_punctuation {
+            s = s.chars().filter(|c| !c.is_ascii_punctuation()).collect();
+        }
+
+        if !self.collapse_whitespace && !self.remove_articles {
+            return s;
+        }
+
+        let mut tokens: Vec<&str> = s.split_whitespace().collect();
+
+        if self.remove_articles {
+            tokens.retain(|t| !matches!(*t, "a" | "an" | "the"));
+        }
+
+        tokens.join(" ")
+    }
+}
+
+impl Default for TextNormalizer {
+    fn default() -> Self {
+        Self::squad()
+    }
+}
+
+/// Exact match metric that normalizes both sides before comparing, and
+/// optionally gives partial credit proportional to token overlap instead
+/// of an all-or-nothing score.
+pub struct NormalizedExactMatch {
+    normalizer: TextNormalizer,
+    partial_credit: bool,
+}
+
+impl NormalizedExactMatch {
+    /// Strict normalized exact match (score is 0.0 or 1.0).
+    pub fn new(normalizer: TextNormalizer) -> Self {
+        Self {
+            normalizer,
+            partial_credit: false,
+        }
+    }
+
+    /// Normalized match with partial credit: score is the token-level F1
+    /// between the normalized strings when they're not identical.
+    pub fn with_partial_credit(normalizer: TextNormalizer) -> Self {
+        Self {
+            normalizer,
+            partial_credit: true,
+        }
+    }
+}
+
+impl Metric for NormalizedExactMatch {
+    fn name(&self) -> &str {
+        "normalized_exact_match"
+    }
+
+    fn compute(&self, predicted: &str, expected: &str) -> f64 {
+        let pred = self.normalizer.normalize(predicted);
+        let exp = self.normalizer.normalize(expected);
+
+        if pred == exp {
+            return 1.0;
+        }
+
+        if !self.partial_credit {
+            return 0.0;
+        }
+
+        F1Score.compute(&pred, &exp)
+    }
+}
+
 /// F1 score metric for token-based comparison
 pub struct F1Score;
 
@@ -539,12 +1197,19 @@ impl Metric for F1Score {
     }
 }
 
-/// BLEU score metric for n-gram overlap
-pub struct BLEU {
+/// Single-order n-gram overlap: the fraction of the predicted text's
+/// `n`-grams that also appear anywhere in the expected text.
+///
+/// This is *not* BLEU — it ignores precision clipping, multiple n-gram
+/// orders, and the brevity penalty, so it diverges from standard BLEU
+/// scores. It predates [`BLEU`] under that name; kept around (and renamed,
+/// rather than removed) so existing callers of the old single-order metric
+/// don't break.
+pub struct UnigramOverlap {
     n: usize,
 }
 
-impl BLEU {
+impl UnigramOverlap {
     pub fn new(n: usize) -> Self {
         Self { n }
     }
@@ -581,9 +1246,9 @@ impl BLEU {
     }
 }
 
-impl Metric for BLEU {
+impl Metric for UnigramOverlap {
     fn name(&self) -> &str {
-        "bleu"
+        "unigram_overlap"
     }
 
     fn compute(&self, predicted: &str, expected: &str) -> f64 {
@@ -591,20 +1256,160 @@ impl Metric for BLEU {
     }
 }
 
-/// ROUGE score metric for recall-based evaluation
-pub struct ROUGE {
-    variant: String,
+/// Standard BLEU score: the geometric mean of clipped n-gram precisions for
+/// orders `1..=n`, scaled by the brevity penalty.
+///
+/// [`Metric::compute`] scores one sentence pair at a time by averaging
+/// per-sentence precisions. For a whole evaluation run, prefer the
+/// [`AggregateMetric`] impl below, which aggregates clipped n-gram counts
+/// and lengths across every pair *before* taking the geometric mean — the
+/// standard "corpus BLEU" definition, which is not the same as averaging
+/// per-sentence scores.
+pub struct BLEU {
+    n: usize,
 }
 
-impl ROUGE {
-    pub fn new(variant: &str) -> Self {
-        Self {
-            variant: variant.to_string(),
+impl BLEU {
+    /// `n` is the maximum n-gram order scored; precisions for orders `1..=n`
+    /// are combined via geometric mean.
+    pub fn new(n: usize) -> Self {
+        Self { n: n.max(1) }
+    }
+
+    /// Counts of each distinct n-gram of `order` in `tokens`.
+    fn ngram_counts<'a>(tokens: &'a [&str], order: usize) -> HashMap<&'a [&'a str], usize> {
+        let mut counts = HashMap::new();
+        if tokens.len() < order {
+            return counts;
+        }
+        for i in 0..=(tokens.len() - order) {
+            *counts.entry(&tokens[i..i + order]).or_insert(0) += 1;
         }
+        counts
     }
 
-    fn calculate_rouge_l(&self, predicted: &str, expected: &str) -> f64 {
-        let pred_tokens: Vec<&str> = predicted.split_whitespace().collect();
+    /// Clipped n-gram matches between `candidate` and `reference` at `order`,
+    /// plus the total number of candidate n-grams at that order (the
+    /// precision denominator).
+    fn clipped_matches(candidate: &[&str], reference: &[&str], order: usize) -> (usize, usize) {
+        let candidate_counts = Self::ngram_counts(candidate, order);
+        let reference_counts = Self::ngram_counts(reference, order);
+
+        let total: usize = candidate_counts.values().sum();
+        let matches: usize = candidate_counts
+            .iter()
+            .map(|(gram, &count)| count.min(*reference_counts.get(gram).unwrap_or(&0)))
+            .sum();
+
+        (matches, total)
+    }
+
+    /// `1.0` if the candidate is at least as long as the reference
+    /// (undoing any advantage a too-short, high-precision candidate would
+    /// otherwise get), else the standard exponential penalty.
+    fn brevity_penalty(candidate_len: usize, reference_len: usize) -> f64 {
+        if candidate_len == 0 {
+            0.0
+        } else if candidate_len > reference_len {
+            1.0
+        } else {
+            (1.0 - reference_len as f64 / candidate_len as f64).exp()
+        }
+    }
+
+    fn sentence_bleu(&self, predicted: &str, expected: &str) -> f64 {
+        let candidate: Vec<&str> = predicted.split_whitespace().collect();
+        let reference: Vec<&str> = expected.split_whitespace().collect();
+
+        if candidate.is_empty() || reference.is_empty() {
+            return 0.0;
+        }
+
+        let mut log_precision_sum = 0.0;
+        for order in 1..=self.n {
+            let (matches, total) = Self::clipped_matches(&candidate, &reference, order);
+            if matches == 0 || total == 0 {
+                // A zero precision at any order zeroes the geometric mean.
+                return 0.0;
+            }
+            log_precision_sum += (matches as f64 / total as f64).ln();
+        }
+
+        let geometric_mean = (log_precision_sum / self.n as f64).exp();
+        Self::brevity_penalty(candidate.len(), reference.len()) * geometric_mean
+    }
+}
+
+impl Metric for BLEU {
+    fn name(&self) -> &str {
+        "bleu"
+    }
+
+    fn compute(&self, predicted: &str, expected: &str) -> f64 {
+        self.sentence_bleu(predicted, expected)
+    }
+}
+
+/// Corpus-level BLEU: aggregates clipped n-gram counts and lengths across
+/// every `(predicted, expected)` pair before computing a single geometric
+/// mean, rather than averaging per-sentence [`Metric::compute`] scores. This
+/// is the definition used when papers report a single BLEU score for a
+/// whole test set.
+impl AggregateMetric<(String, String)> for BLEU {
+    type Output = f64;
+
+    fn compute_aggregate(&self, samples: &[(String, String)]) -> f64 {
+        let mut matches_by_order = vec![0usize; self.n];
+        let mut totals_by_order = vec![0usize; self.n];
+        let mut candidate_len_total = 0usize;
+        let mut reference_len_total = 0usize;
+
+        for (predicted, expected) in samples {
+            let candidate: Vec<&str> = predicted.split_whitespace().collect();
+            let reference: Vec<&str> = expected.split_whitespace().collect();
+            candidate_len_total += candidate.len();
+            reference_len_total += reference.len();
+
+            for order in 1..=self.n {
+                let (matches, total) = Self::clipped_matches(&candidate, &reference, order);
+                matches_by_order[order - 1] += matches;
+                totals_by_order[order - 1] += total;
+            }
+        }
+
+        if candidate_len_total == 0 {
+            return 0.0;
+        }
+
+        let mut log_precision_sum = 0.0;
+        for order in 1..=self.n {
+            let matches = matches_by_order[order - 1];
+            let total = totals_by_order[order - 1];
+            if matches == 0 || total == 0 {
+                return 0.0;
+            }
+            log_precision_sum += (matches as f64 / total as f64).ln();
+        }
+
+        let geometric_mean = (log_precision_sum / self.n as f64).exp();
+        Self::brevity_penalty(candidate_len_total, reference_len_total) * geometric_mean
+    }
+}
+
+/// ROUGE score metric for recall-based evaluation
+pub struct ROUGE {
+    variant: String,
+}
+
+impl ROUGE {
+    pub fn new(variant: &str) -> Self {
+        Self {
+            variant: variant.to_string(),
+        }
+    }
+
+    fn calculate_rouge_l(&self, predicted: &str, expected: &str) -> f64 {
+        let pred_tokens: Vec<&str> = predicted.split_whitespace().collect();
         let exp_tokens: Vec<&str> = expected.split_whitespace().collect();
 
         if pred_tokens.is_empty() || exp_tokens.is_empty() {
@@ -638,6 +1443,692 @@ impl Metric for ROUGE {
     }
 }
 
+/// chrF: character n-gram F-score (Popović, 2015).
+///
+/// BLEU and ROUGE tokenize on whitespace, so morphological variants (e.g.
+/// "walk"/"walking") count as completely different tokens. Matching on
+/// character n-grams instead gives chrF partial credit for shared roots,
+/// which matters a lot for morphologically rich languages and for scoring
+/// near-miss inflections. Pure Rust, no Python dependency.
+pub struct ChrF {
+    max_order: usize,
+    beta: f64,
+}
+
+impl ChrF {
+    /// `max_order` is the maximum character n-gram order (orders `1..=max_order`
+    /// are averaged); `beta` weights recall relative to precision in the final
+    /// F-score (`beta > 1.0` favors recall, matching the standard chrF2 setup).
+    pub fn new(max_order: usize, beta: f64) -> Self {
+        Self {
+            max_order: max_order.max(1),
+            beta,
+        }
+    }
+
+    fn char_ngram_counts(chars: &[char], order: usize) -> HashMap<&[char], usize> {
+        let mut counts = HashMap::new();
+        if chars.len() < order {
+            return counts;
+        }
+        for i in 0..=(chars.len() - order) {
+            *counts.entry(&chars[i..i + order]).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Average precision and average recall across every order `1..=max_order`
+    /// that either string is long enough to form an n-gram for.
+    fn average_precision_recall(&self, candidate: &[char], reference: &[char]) -> (f64, f64) {
+        let mut precision_sum = 0.0;
+        let mut recall_sum = 0.0;
+        let mut valid_orders = 0usize;
+
+        for order in 1..=self.max_order {
+            if candidate.len() < order && reference.len() < order {
+                continue;
+            }
+
+            let candidate_counts = Self::char_ngram_counts(candidate, order);
+            let reference_counts = Self::char_ngram_counts(reference, order);
+
+            let candidate_total: usize = candidate_counts.values().sum();
+            let reference_total: usize = reference_counts.values().sum();
+            let matches: usize = candidate_counts
+                .iter()
+                .map(|(gram, &count)| count.min(*reference_counts.get(gram).unwrap_or(&0)))
+                .sum();
+
+            precision_sum += if candidate_total == 0 { 0.0 } else { matches as f64 / candidate_total as f64 };
+            recall_sum += if reference_total == 0 { 0.0 } else { matches as f64 / reference_total as f64 };
+            valid_orders += 1;
+        }
+
+        if valid_orders == 0 {
+            (0.0, 0.0)
+        } else {
+            (precision_sum / valid_orders as f64, recall_sum / valid_orders as f64)
+        }
+    }
+
+    fn f_beta(&self, precision: f64, recall: f64) -> f64 {
+        if precision + recall == 0.0 {
+            return 0.0;
+        }
+        let beta_sq = self.beta * self.beta;
+        (1.0 + beta_sq) * precision * recall / (beta_sq * precision + recall)
+    }
+}
+
+impl Default for ChrF {
+    /// Matches the commonly reported "chrF2" setup: character 6-grams, beta = 2.
+    fn default() -> Self {
+        Self::new(6, 2.0)
+    }
+}
+
+impl Metric for ChrF {
+    fn name(&self) -> &str {
+        "chrf"
+    }
+
+    fn compute(&self, predicted: &str, expected: &str) -> f64 {
+        // chrF is defined over whitespace-stripped character streams.
+        let candidate: Vec<char> = predicted.chars().filter(|c| !c.is_whitespace()).collect();
+        let reference: Vec<char> = expected.chars().filter(|c| !c.is_whitespace()).collect();
+
+        if reference.is_empty() {
+            return if candidate.is_empty() { 1.0 } else { 0.0 };
+        }
+        if candidate.is_empty() {
+            return 0.0;
+        }
+
+        let (precision, recall) = self.average_precision_recall(&candidate, &reference);
+        self.f_beta(precision, recall)
+    }
+}
+
+/// Naive suffix-stripping stemmer used as METEOR's offline fallback for
+/// stem matching. Not a full Porter stemmer — just enough common English
+/// inflectional suffixes to catch the common case (`"walking"` ~
+/// `"walk"`, `"cats"` ~ `"cat"`) without a dictionary or Python dependency.
+fn naive_stem(word: &str) -> &str {
+    for suffix in ["edly", "ing", "ies", "ed", "es", "ly", "s"] {
+        if word.len() > suffix.len() + 2 && word.ends_with(suffix) {
+            return &word[..word.len() - suffix.len()];
+        }
+    }
+    word
+}
+
+/// Looks up whether `word_a` and `word_b` share a WordNet synset via NLTK,
+/// through a Python call. Returns `false` (not `panic!`) for every failure
+/// mode — Python not embeddable in this process, `nltk` not installed, the
+/// `wordnet` corpus not downloaded — so [`Meteor`] can always fall back to
+/// exact+stem matching instead of crashing the evaluation run.
+fn python_wordnet_synonyms(word_a: &str, word_b: &str) -> bool {
+    let outcome = std::panic::catch_unwind(|| {
+        Python::with_gil(|py| -> PyResult<bool> {
+            let wordnet = PyModule::import_bound(py, "nltk.corpus")?.getattr("wordnet")?;
+
+            let lemmas_for = |word: &str| -> PyResult<Vec<String>> {
+                let synsets = wordnet.call_method1("synsets", (word,))?.extract::<Vec<Py<PyAny>>>()?;
+                let mut lemmas = Vec::new();
+                for synset in synsets {
+                    lemmas.extend(synset.bind(py).call_method0("lemma_names")?.extract::<Vec<String>>()?);
+                }
+                Ok(lemmas)
+            };
+
+            let lemmas_a = lemmas_for(word_a)?;
+            let lemmas_b = lemmas_for(word_b)?;
+            Ok(lemmas_a.iter().any(|lemma| lemmas_b.contains(lemma)))
+        })
+    });
+
+    matches!(outcome, Ok(Ok(true)))
+}
+
+/// METEOR: Metric for Evaluation of Translation with Explicit ORdering
+/// (Banerjee & Lavie, 2005).
+///
+/// Aligns hypothesis and reference tokens in three passes — exact match,
+/// then stem match, then (if `use_synonyms` and Python/NLTK are available)
+/// WordNet synonym match — and scores the alignment by the harmonic mean of
+/// precision and recall (weighted 9:1 toward recall), penalized for
+/// fragmentation: an alignment strung together from many short, reordered
+/// chunks scores lower than one with the same match count in long runs.
+///
+/// Unlike BLEU/ROUGE/chrF, this can optionally shell out to Python for
+/// WordNet lookups; [`python_wordnet_synonyms`] degrades to `false` (no
+/// synonym match) rather than failing if Python or NLTK isn't available, so
+/// `Meteor::new()` is always safe to use even without a Python environment.
+pub struct Meteor {
+    alpha: f64,
+    beta: f64,
+    gamma: f64,
+    use_synonyms: bool,
+}
+
+impl Meteor {
+    /// WordNet synonym matching enabled, with the standard METEOR
+    /// parameters (`alpha = 0.9`, `beta = 3.0`, `gamma = 0.5`).
+    pub fn new() -> Self {
+        Self {
+            alpha: 0.9,
+            beta: 3.0,
+            gamma: 0.5,
+            use_synonyms: true,
+        }
+    }
+
+    /// Exact+stem matching only, skipping the Python/NLTK synonym pass
+    /// entirely (rather than attempting and degrading). Useful when the
+    /// caller already knows Python isn't available, or wants deterministic
+    /// scoring independent of what's installed on the machine running the
+    /// evaluation.
+    pub fn without_synonyms() -> Self {
+        Self {
+            use_synonyms: false,
+            ..Self::new()
+        }
+    }
+
+    /// Greedily aligns `hyp` to `ref_` in three passes, returning
+    /// `(hyp_index, ref_index)` pairs sorted by `hyp_index`.
+    fn align(&self, hyp: &[String], ref_: &[String]) -> Vec<(usize, usize)> {
+        let mut matched_hyp = vec![false; hyp.len()];
+        let mut matched_ref = vec![false; ref_.len()];
+        let mut pairs = Vec::new();
+
+        let mut match_pass = |predicate: &dyn Fn(&str, &str) -> bool| {
+            for (hi, h) in hyp.iter().enumerate() {
+                if matched_hyp[hi] {
+                    continue;
+                }
+                for (ri, r) in ref_.iter().enumerate() {
+                    if matched_ref[ri] {
+                        continue;
+                    }
+                    if predicate(h, r) {
+                        matched_hyp[hi] = true;
+                        matched_ref[ri] = true;
+                        pairs.push((hi, ri));
+                        break;
+                    }
+                }
+            }
+        };
+
+        match_pass(&|h, r| h == r);
+        match_pass(&|h, r| naive_stem(h) == naive_stem(r));
+        if self.use_synonyms {
+            match_pass(&|h, r| python_wordnet_synonyms(h, r));
+        }
+
+        pairs.sort_by_key(|&(hi, _)| hi);
+        pairs
+    }
+
+    /// Number of maximal runs of consecutive `(hyp_index, ref_index)` pairs
+    /// that increase together in lockstep — i.e. how many disjoint,
+    /// contiguously-aligned chunks the match is made of. Fewer, longer
+    /// chunks for the same match count means less reordering and scores
+    /// higher.
+    fn count_chunks(pairs: &[(usize, usize)]) -> usize {
+        if pairs.is_empty() {
+            return 0;
+        }
+        let mut chunks = 1;
+        for i in 1..pairs.len() {
+            let (prev_hi, prev_ri) = pairs[i - 1];
+            let (hi, ri) = pairs[i];
+            if hi != prev_hi + 1 || ri != prev_ri + 1 {
+                chunks += 1;
+            }
+        }
+        chunks
+    }
+}
+
+impl Default for Meteor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for Meteor {
+    fn name(&self) -> &str {
+        "meteor"
+    }
+
+    fn compute(&self, predicted: &str, expected: &str) -> f64 {
+        let hyp: Vec<String> = predicted.split_whitespace().map(|w| w.to_lowercase()).collect();
+        let reference: Vec<String> = expected.split_whitespace().map(|w| w.to_lowercase()).collect();
+
+        if hyp.is_empty() || reference.is_empty() {
+            return 0.0;
+        }
+
+        let pairs = self.align(&hyp, &reference);
+        let matches = pairs.len();
+        if matches == 0 {
+            return 0.0;
+        }
+
+        let precision = matches as f64 / hyp.len() as f64;
+        let recall = matches as f64 / reference.len() as f64;
+        let f_mean = precision * recall / (self.alpha * precision + (1.0 - self.alpha) * recall);
+
+        let fragmentation = Self::count_chunks(&pairs) as f64 / matches as f64;
+        let penalty = self.gamma * fragmentation.powf(self.beta);
+
+        (f_mean * (1.0 - penalty)).max(0.0)
+    }
+}
+
+/// Trait for metrics that need the whole set of predictions at once (e.g.
+/// calibration, which buckets confidence across an entire run, or corpus
+/// BLEU, which aggregates n-gram counts across every pair) rather than
+/// scoring one prediction at a time like [`Metric`]. Generic over the
+/// per-example `Sample` type since different aggregate metrics need
+/// different per-example data — defaults to [`ConfidenceSample`] since
+/// calibration was the first use case.
+pub trait AggregateMetric<Sample = ConfidenceSample> {
+    type Output;
+
+    fn compute_aggregate(&self, samples: &[Sample]) -> Self::Output;
+}
+
+/// Per-example confidence and correctness — the input unit [`AggregateMetric`]
+/// implementations like [`CalibrationReport`] operate over.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfidenceSample {
+    pub confidence: f64,
+    pub correct: bool,
+}
+
+/// Extracts a [`ConfidenceSample`] from a model's raw prediction, since
+/// `Metric::compute` only sees predicted/expected strings and has no notion
+/// of confidence.
+pub trait ConfidenceExtractor {
+    fn extract(&self, predicted: &str, expected: &str) -> Option<ConfidenceSample>;
+}
+
+/// Parses `predicted` as `"<answer><separator><confidence>"`, e.g.
+/// `"Paris|0.92"` with the default `|` separator. Everything before the last
+/// separator is compared against `expected` (trimmed) for correctness.
+pub struct SuffixConfidenceExtractor {
+    separator: char,
+}
+
+impl SuffixConfidenceExtractor {
+    pub fn new(separator: char) -> Self {
+        Self { separator }
+    }
+}
+
+impl Default for SuffixConfidenceExtractor {
+    fn default() -> Self {
+        Self::new('|')
+    }
+}
+
+impl ConfidenceExtractor for SuffixConfidenceExtractor {
+    fn extract(&self, predicted: &str, expected: &str) -> Option<ConfidenceSample> {
+        let (answer, confidence) = predicted.rsplit_once(self.separator)?;
+        let confidence: f64 = confidence.trim().parse().ok()?;
+
+        Some(ConfidenceSample {
+            confidence,
+            correct: answer.trim() == expected.trim(),
+        })
+    }
+}
+
+/// Parses `predicted` as a JSON object with a string answer field and a
+/// numeric confidence field (by default `"answer"` and `"confidence"`), e.g.
+/// `{"answer": "Paris", "confidence": 0.92}`.
+pub struct JsonConfidenceExtractor {
+    answer_field: String,
+    confidence_field: String,
+}
+
+impl JsonConfidenceExtractor {
+    pub fn new(answer_field: impl Into<String>, confidence_field: impl Into<String>) -> Self {
+        Self {
+            answer_field: answer_field.into(),
+            confidence_field: confidence_field.into(),
+        }
+    }
+}
+
+impl Default for JsonConfidenceExtractor {
+    fn default() -> Self {
+        Self::new("answer", "confidence")
+    }
+}
+
+impl ConfidenceExtractor for JsonConfidenceExtractor {
+    fn extract(&self, predicted: &str, expected: &str) -> Option<ConfidenceSample> {
+        let value: serde_json::Value = serde_json::from_str(predicted).ok()?;
+        let answer = value.get(&self.answer_field)?.as_str()?;
+        let confidence = value.get(&self.confidence_field)?.as_f64()?;
+
+        Some(ConfidenceSample {
+            confidence,
+            correct: answer.trim() == expected.trim(),
+        })
+    }
+}
+
+/// A single reliability-diagram bucket: observed accuracy vs. average
+/// predicted confidence for predictions whose confidence fell in
+/// `[lower, upper)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationBin {
+    pub lower: f64,
+    pub upper: f64,
+    pub count: usize,
+    pub avg_confidence: f64,
+    pub accuracy: f64,
+}
+
+/// Result of [`CalibrationReport::compute_aggregate`]: overall Expected
+/// Calibration Error plus the per-bin data needed to plot a reliability
+/// diagram (`avg_confidence` on the x-axis, `accuracy` on the y-axis).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationResult {
+    pub ece: f64,
+    pub bins: Vec<CalibrationBin>,
+}
+
+/// Expected Calibration Error and reliability-diagram data for a set of
+/// confidence-tagged predictions.
+///
+/// Buckets predictions into `num_bins` equal-width confidence bins over
+/// `[0, 1]` and compares, per bin, the average predicted confidence against
+/// the observed accuracy. A perfectly calibrated model has `ece == 0.0`;
+/// label smoothing (which pulls raw confidences toward the prior) shows up
+/// as a systematic gap here even when plain accuracy looks fine.
+pub struct CalibrationReport {
+    num_bins: usize,
+}
+
+impl CalibrationReport {
+    pub fn new(num_bins: usize) -> Self {
+        Self {
+            num_bins: num_bins.max(1),
+        }
+    }
+
+    /// Extract confidence samples from `examples`/`predictions` via
+    /// `extractor`, then compute the calibration report. Predictions the
+    /// extractor can't parse are skipped.
+    pub fn evaluate<E: ConfidenceExtractor>(
+        &self,
+        examples: &[TestExample],
+        predictions: &[String],
+        extractor: &E,
+    ) -> CalibrationResult {
+        let samples: Vec<ConfidenceSample> = examples
+            .iter()
+            .zip(predictions)
+            .filter_map(|(example, predicted)| extractor.extract(predicted, &example.expected_output))
+            .collect();
+
+        self.compute_aggregate(&samples)
+    }
+
+    fn bin_index(&self, confidence: f64) -> usize {
+        let clamped = confidence.clamp(0.0, 1.0);
+        let idx = (clamped * self.num_bins as f64) as usize;
+        idx.min(self.num_bins - 1)
+    }
+}
+
+impl AggregateMetric<ConfidenceSample> for CalibrationReport {
+    type Output = CalibrationResult;
+
+    fn compute_aggregate(&self, samples: &[ConfidenceSample]) -> CalibrationResult {
+        struct BinAccumulator {
+            lower: f64,
+            upper: f64,
+            count: usize,
+            confidence_sum: f64,
+            correct_count: usize,
+        }
+
+        let mut bins: Vec<BinAccumulator> = (0..self.num_bins)
+            .map(|i| BinAccumulator {
+                lower: i as f64 / self.num_bins as f64,
+                upper: (i + 1) as f64 / self.num_bins as f64,
+                count: 0,
+                confidence_sum: 0.0,
+                correct_count: 0,
+            })
+            .collect();
+
+        for sample in samples {
+            let bin = &mut bins[self.bin_index(sample.confidence)];
+            bin.count += 1;
+            bin.confidence_sum += sample.confidence;
+            if sample.correct {
+                bin.correct_count += 1;
+            }
+        }
+
+        let total = samples.len() as f64;
+        let mut ece = 0.0;
+
+        let result_bins = bins
+            .into_iter()
+            .map(|bin| {
+                let avg_confidence = if bin.count > 0 {
+                    bin.confidence_sum / bin.count as f64
+                } else {
+                    0.0
+                };
+                let accuracy = if bin.count > 0 {
+                    bin.correct_count as f64 / bin.count as f64
+                } else {
+                    0.0
+                };
+
+                if bin.count > 0 {
+                    ece += (bin.count as f64 / total) * (avg_confidence - accuracy).abs();
+                }
+
+                CalibrationBin {
+                    lower: bin.lower,
+                    upper: bin.upper,
+                    count: bin.count,
+                    avg_confidence,
+                    accuracy,
+                }
+            })
+            .collect();
+
+        CalibrationResult {
+            ece,
+            bins: result_bins,
+        }
+    }
+}
+
+/// On-disk embedding cache keyed by a hash of the text plus the embedding
+/// model name, so the same ground-truth answers aren't re-embedded across
+/// evaluation runs. Shared via `Arc` between [`SemanticSimilarity`] and any
+/// other caller (e.g. agent summarization) that embeds the same text.
+///
+/// Entries are capped at `max_entries`; once full, the least-recently-used
+/// entry is evicted to make room. The cache is persisted to `path` as a
+/// single JSON file, rewritten after every miss.
+pub struct EmbeddingCache {
+    path: PathBuf,
+    entries: Mutex<LruCache<String, Vec<f32>>>,
+}
+
+impl EmbeddingCache {
+    /// Opens (or creates) an embedding cache backed by `path`, capped at
+    /// `max_entries` entries. Existing entries are loaded from `path` if the
+    /// file already exists.
+    pub fn new<P: AsRef<Path>>(path: P, max_entries: usize) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let capacity = NonZeroUsize::new(max_entries).context("max_entries must be non-zero")?;
+        let mut entries = LruCache::new(capacity);
+
+        if path.exists() {
+            let file = File::open(&path).context("Failed to open embedding cache file")?;
+            let reader = BufReader::new(file);
+            let loaded: HashMap<String, Vec<f32>> = serde_json::from_reader(reader)
+                .context("Failed to parse embedding cache file")?;
+            for (key, embedding) in loaded {
+                entries.put(key, embedding);
+            }
+        }
+
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// Returns the cached embedding for `text` under `model_name`, calling
+    /// `compute_fn` and caching the result on a miss.
+    pub fn get_or_compute<F>(
+        &self,
+        text: &str,
+        model_name: &str,
+        compute_fn: F,
+    ) -> Result<Vec<f32>>
+    where
+        F: FnOnce(&str) -> Result<Vec<f32>>,
+    {
+        let key = Self::cache_key(text, model_name);
+
+        {
+            let mut entries = self.entries.lock().unwrap();
+            if let Some(embedding) = entries.get(&key) {
+                return Ok(embedding.clone());
+            }
+        }
+
+        let embedding = compute_fn(text)?;
+
+        {
+            let mut entries = self.entries.lock().unwrap();
+            entries.put(key, embedding.clone());
+        }
+        self.save().context("Failed to persist embedding cache")?;
+
+        Ok(embedding)
+    }
+
+    /// Number of entries currently cached in memory.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Whether the cache currently holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().unwrap().is_empty()
+    }
+
+    /// Removes all cached entries and deletes the on-disk cache file.
+    pub fn clear(&self) -> Result<()> {
+        self.entries.lock().unwrap().clear();
+        if self.path.exists() {
+            std::fs::remove_file(&self.path).context("Failed to remove embedding cache file")?;
+        }
+        Ok(())
+    }
+
+    fn cache_key(text: &str, model_name: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(model_name.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(text.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    fn save(&self) -> Result<()> {
+        let entries = self.entries.lock().unwrap();
+        let snapshot: HashMap<&String, &Vec<f32>> = entries.iter().collect();
+        let file = File::create(&self.path).context("Failed to create embedding cache file")?;
+        serde_json::to_writer(file, &snapshot).context("Failed to write embedding cache file")?;
+        Ok(())
+    }
+}
+
+type EmbedFn = dyn Fn(&str) -> Result<Vec<f32>> + Send + Sync;
+
+/// Cosine-similarity metric between embeddings of the predicted and expected
+/// text, for cases where exact/overlap-based metrics like [`F1Score`] are
+/// too strict for paraphrased answers.
+///
+/// `embed_fn` computes an embedding for a single string (e.g. a call out to
+/// a DSPy embedding model); results are routed through a shared
+/// [`EmbeddingCache`] so repeated ground-truth answers aren't re-embedded on
+/// every evaluation run.
+pub struct SemanticSimilarity {
+    cache: Arc<EmbeddingCache>,
+    model_name: String,
+    embed_fn: Box<EmbedFn>,
+}
+
+impl SemanticSimilarity {
+    pub fn new(
+        cache: Arc<EmbeddingCache>,
+        model_name: impl Into<String>,
+        embed_fn: impl Fn(&str) -> Result<Vec<f32>> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            cache,
+            model_name: model_name.into(),
+            embed_fn: Box::new(embed_fn),
+        }
+    }
+
+    fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        self.cache
+            .get_or_compute(text, &self.model_name, |t| (self.embed_fn)(t))
+    }
+}
+
+impl Metric for SemanticSimilarity {
+    fn name(&self) -> &str {
+        "semantic_similarity"
+    }
+
+    fn compute(&self, predicted: &str, expected: &str) -> f64 {
+        let (Ok(pred_embedding), Ok(exp_embedding)) =
+            (self.embed(predicted), self.embed(expected))
+        else {
+            return 0.0;
+        };
+
+        cosine_similarity(&pred_embedding, &exp_embedding).clamp(0.0, 1.0) as f64
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
 // Statistical helper functions
 
 fn calculate_mean(values: &[f64]) -> f64 {
@@ -745,6 +2236,189 @@ mod tests {
         assert_eq!(metric.compute("", ""), 1.0);
     }
 
+    #[test]
+    fn test_from_jsonl_round_trip() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("eval_test_{}.jsonl", std::process::id()));
+        std::fs::write(
+            &path,
+            "{\"input\":\"q1\",\"expected_output\":\"a1\"}\n{\"input\":\"q2\",\"expected_output\":\"a2\"}\n",
+        )
+        .unwrap();
+
+        let test_set = TestSet::from_jsonl(&path).unwrap();
+        assert_eq!(test_set.len(), 2);
+        assert_eq!(test_set.examples[1].input, "q2");
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_evaluation_result_diff_classifies_examples() {
+        let baseline = EvaluationResult::from_scores(
+            "baseline".to_string(),
+            "accuracy".to_string(),
+            vec![1.0, 1.0, 0.0, 0.5],
+        );
+        let candidate = EvaluationResult::from_scores(
+            "candidate".to_string(),
+            "accuracy".to_string(),
+            vec![1.0, 0.0, 1.0, 0.5],
+        );
+
+        let diff = candidate.diff(&baseline).unwrap();
+
+        assert_eq!(diff.improved, vec![2]);
+        assert_eq!(diff.regressed, vec![1]);
+        assert_eq!(diff.unchanged, vec![0, 3]);
+        assert_eq!(diff.regressions, vec![(1, 1.0)]);
+        assert_eq!(diff.top_regressions(5), &[(1, 1.0)]);
+    }
+
+    #[test]
+    fn test_evaluation_result_diff_sorts_top_regressions_by_magnitude() {
+        let baseline = EvaluationResult::from_scores(
+            "baseline".to_string(),
+            "accuracy".to_string(),
+            vec![1.0, 1.0, 1.0],
+        );
+        let candidate = EvaluationResult::from_scores(
+            "candidate".to_string(),
+            "accuracy".to_string(),
+            vec![0.9, 0.0, 0.5],
+        );
+
+        let diff = candidate.diff(&baseline).unwrap();
+
+        assert_eq!(diff.top_regressions(2), &[(1, 1.0), (2, 0.5)]);
+    }
+
+    #[test]
+    fn test_evaluation_result_diff_errors_on_mismatched_example_counts() {
+        let baseline = EvaluationResult::from_scores(
+            "baseline".to_string(),
+            "accuracy".to_string(),
+            vec![1.0, 1.0],
+        );
+        let candidate = EvaluationResult::from_scores(
+            "candidate".to_string(),
+            "accuracy".to_string(),
+            vec![1.0],
+        );
+
+        assert!(candidate.diff(&baseline).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_streaming_matches_batch() {
+        let mut test_set = TestSet::new();
+        for i in 0..10 {
+            test_set.add_example(TestExample {
+                input: format!("in{i}"),
+                expected_output: if i % 2 == 0 { "match".to_string() } else { "in".to_string() },
+                metadata: HashMap::new(),
+            });
+        }
+
+        let harness = EvaluationHarness::new(Box::new(Accuracy));
+        let batch = harness
+            .evaluate(&test_set, "m", |ex| {
+                if ex.expected_output == "match" {
+                    "match".to_string()
+                } else {
+                    "nope".to_string()
+                }
+            })
+            .await
+            .unwrap();
+
+        let streaming = harness
+            .evaluate_streaming(test_set.examples.clone(), "m", |ex| {
+                if ex.expected_output == "match" {
+                    "match".to_string()
+                } else {
+                    "nope".to_string()
+                }
+            })
+            .unwrap();
+
+        assert!((batch.mean - streaming.mean).abs() < 1e-12);
+        assert!((batch.std_dev - streaming.std_dev).abs() < 1e-12);
+        assert_eq!(streaming.individual_scores.len(), 0);
+        assert!(streaming.digest.is_some());
+        // TDigest percentiles are estimates, but with only two distinct
+        // scores (0.0/1.0) in this test set the digest should recover them
+        // exactly regardless of which side of the median it lands on.
+        assert!(streaming.percentile_50 == 0.0 || streaming.percentile_50 == 1.0);
+    }
+
+    #[test]
+    fn test_from_scores_with_options_retain_false_drops_scores_but_keeps_digest() {
+        let scores: Vec<f64> = (0..100).map(|i| i as f64).collect();
+        let result = EvaluationResult::from_scores_with_options(
+            "m".to_string(),
+            "metric".to_string(),
+            scores,
+            false,
+        );
+
+        assert_eq!(result.individual_scores.len(), 0);
+        assert!(result.digest.is_some());
+        assert_eq!(result.num_examples, 100);
+        // Estimated median should land close to the true median (49.5).
+        assert!((result.percentile_50 - 49.5).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_from_scores_with_options_retain_true_matches_from_scores() {
+        let scores = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let retained = EvaluationResult::from_scores_with_options(
+            "m".to_string(),
+            "metric".to_string(),
+            scores.clone(),
+            true,
+        );
+        let default = EvaluationResult::from_scores("m".to_string(), "metric".to_string(), scores);
+
+        assert_eq!(retained.individual_scores, default.individual_scores);
+        assert_eq!(retained.percentile_50, default.percentile_50);
+        assert!(retained.digest.is_none());
+    }
+
+    #[test]
+    fn test_tdigest_quantile_on_uniform_data_is_reasonably_accurate() {
+        let mut digest = TDigest::new(50);
+        for i in 0..=100 {
+            digest.update(i as f64);
+        }
+
+        assert!((digest.quantile(0.5) - 50.0).abs() < 5.0);
+        assert!((digest.quantile(0.95) - 95.0).abs() < 5.0);
+        assert!(digest.quantile(0.0) < 5.0);
+    }
+
+    #[test]
+    fn test_tdigest_empty_quantile_is_zero() {
+        let digest = TDigest::new(50);
+        assert_eq!(digest.quantile(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_normalized_exact_match() {
+        let metric = NormalizedExactMatch::new(TextNormalizer::squad());
+        assert_eq!(metric.compute("The Cat.", "cat"), 1.0);
+        assert_eq!(metric.compute("a Dog", "dog"), 1.0);
+        assert_eq!(metric.compute("completely different", "cat"), 0.0);
+    }
+
+    #[test]
+    fn test_normalized_exact_match_partial_credit() {
+        let metric = NormalizedExactMatch::with_partial_credit(TextNormalizer::squad());
+        assert_eq!(metric.compute("the quick fox", "quick fox"), 1.0);
+        assert!(metric.compute("quick", "quick fox") > 0.0);
+        assert!(metric.compute("quick", "quick fox") < 1.0);
+    }
+
     #[test]
     fn test_test_set_filtering() {
         let mut test_set = TestSet::new();
@@ -784,4 +2458,480 @@ mod tests {
         assert_eq!(result.mean, 1.0);
         assert_eq!(result.num_examples, 1);
     }
+
+    #[tokio::test]
+    async fn test_evaluation_harness_isolates_predictor_panics() {
+        let mut test_set = TestSet::new();
+        for input in ["ok", "boom", "also ok"] {
+            test_set.add_example(TestExample {
+                input: input.to_string(),
+                expected_output: input.to_string(),
+                metadata: HashMap::new(),
+            });
+        }
+
+        let harness = EvaluationHarness::new(Box::new(Accuracy));
+        let result = harness
+            .evaluate(&test_set, "test_model", |ex| {
+                if ex.input == "boom" {
+                    panic!("predictor blew up on {}", ex.input);
+                }
+                ex.input.clone()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.num_examples, 3);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].0, 1);
+        assert!(result.errors[0].1.contains("boom"));
+        // The two matching examples score 1.0, the panicking one scores 0.0.
+        assert!((result.mean - (2.0 / 3.0)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_stratified_groups_by_metadata_key() {
+        let mut test_set = TestSet::new();
+        test_set.add_example(TestExample {
+            input: "easy-q".to_string(),
+            expected_output: "easy-q".to_string(),
+            metadata: [("difficulty".to_string(), serde_json::json!("easy"))]
+                .into_iter()
+                .collect(),
+        });
+        test_set.add_example(TestExample {
+            input: "hard-q".to_string(),
+            expected_output: "different".to_string(),
+            metadata: [("difficulty".to_string(), serde_json::json!("hard"))]
+                .into_iter()
+                .collect(),
+        });
+        test_set.add_example(TestExample {
+            input: "no-tag-q".to_string(),
+            expected_output: "no-tag-q".to_string(),
+            metadata: HashMap::new(),
+        });
+
+        let harness = EvaluationHarness::new(Box::new(Accuracy));
+        let results = harness
+            .evaluate_stratified(&test_set, "test_model", "difficulty", |ex| ex.input.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(results["easy"].mean, 1.0);
+        assert_eq!(results["easy"].num_examples, 1);
+        assert_eq!(results["hard"].mean, 0.0);
+        assert_eq!(results["unknown"].mean, 1.0);
+        assert_eq!(results["overall"].num_examples, 3);
+        assert!((results["overall"].mean - (2.0 / 3.0)).abs() < 1e-9);
+    }
+
+    fn temp_cache_path() -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "embedding_cache_test_{}_{}.json",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ))
+    }
+
+    #[test]
+    fn test_embedding_cache_computes_once_and_reuses() {
+        let path = temp_cache_path();
+        let cache = EmbeddingCache::new(&path, 10).unwrap();
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+
+        let embed = |text: &str| -> Result<Vec<f32>> {
+            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(vec![text.len() as f32])
+        };
+
+        let first = cache.get_or_compute("hello", "model-a", embed).unwrap();
+        let second = cache.get_or_compute("hello", "model-a", embed).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_embedding_cache_keys_by_model_name() {
+        let path = temp_cache_path();
+        let cache = EmbeddingCache::new(&path, 10).unwrap();
+
+        let a = cache
+            .get_or_compute("hello", "model-a", |_| Ok(vec![1.0]))
+            .unwrap();
+        let b = cache
+            .get_or_compute("hello", "model-b", |_| Ok(vec![2.0]))
+            .unwrap();
+
+        assert_ne!(a, b);
+        assert_eq!(cache.len(), 2);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_embedding_cache_evicts_beyond_capacity() {
+        let path = temp_cache_path();
+        let cache = EmbeddingCache::new(&path, 2).unwrap();
+
+        cache.get_or_compute("a", "m", |_| Ok(vec![1.0])).unwrap();
+        cache.get_or_compute("b", "m", |_| Ok(vec![2.0])).unwrap();
+        cache.get_or_compute("c", "m", |_| Ok(vec![3.0])).unwrap();
+
+        assert_eq!(cache.len(), 2);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_embedding_cache_persists_across_instances() {
+        let path = temp_cache_path();
+        {
+            let cache = EmbeddingCache::new(&path, 10).unwrap();
+            cache
+                .get_or_compute("hello", "model-a", |_| Ok(vec![42.0]))
+                .unwrap();
+        }
+
+        let reopened = EmbeddingCache::new(&path, 10).unwrap();
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let embedding = reopened
+            .get_or_compute("hello", "model-a", |_| {
+                calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Ok(vec![0.0])
+            })
+            .unwrap();
+
+        assert_eq!(embedding, vec![42.0]);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_embedding_cache_clear_removes_entries_and_file() {
+        let path = temp_cache_path();
+        let cache = EmbeddingCache::new(&path, 10).unwrap();
+        cache.get_or_compute("hello", "m", |_| Ok(vec![1.0])).unwrap();
+        assert!(path.exists());
+
+        cache.clear().unwrap();
+
+        assert_eq!(cache.len(), 0);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_semantic_similarity_identical_embeddings_score_one() {
+        let path = temp_cache_path();
+        let cache = Arc::new(EmbeddingCache::new(&path, 10).unwrap());
+        let metric = SemanticSimilarity::new(cache, "model-a", |text| {
+            Ok(if text.contains("cat") {
+                vec![1.0, 0.0]
+            } else {
+                vec![0.0, 1.0]
+            })
+        });
+
+        assert!((metric.compute("a cat", "the cat") - 1.0).abs() < 1e-6);
+        assert!(metric.compute("a cat", "a dog") < 1.0);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_metric_registry_builds_registered_metrics() {
+        let mut registry = MetricRegistry::new();
+        registry.register("accuracy", || Box::new(Accuracy));
+
+        let metric = registry.build("accuracy").unwrap();
+        assert_eq!(metric.name(), "accuracy");
+        assert!(registry.build("missing").is_err());
+    }
+
+    #[test]
+    fn test_metric_registry_with_builtins_covers_core_metrics() {
+        let registry = MetricRegistry::with_builtins();
+        for name in [
+            "accuracy",
+            "exact_match",
+            "normalized_exact_match",
+            "f1_score",
+            "bleu",
+            "unigram_overlap",
+            "rouge",
+            "chrf",
+            "meteor",
+        ] {
+            assert!(registry.build(name).is_ok(), "missing builtin metric: {name}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multi_metric_harness_runs_predictor_once_per_example() {
+        let test_set = TestSet {
+            examples: vec![TestExample {
+                input: "q".to_string(),
+                expected_output: "answer".to_string(),
+                metadata: HashMap::new(),
+            }],
+            metadata: HashMap::new(),
+        };
+
+        let predictor_calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let calls = Arc::clone(&predictor_calls);
+
+        let harness = EvaluationHarness::with_metrics(
+            &MetricRegistry::with_builtins(),
+            &["accuracy", "f1_score"],
+        )
+        .unwrap();
+
+        let results = harness
+            .evaluate(&test_set, "m", move |_| {
+                calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                "answer".to_string()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(predictor_calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results["accuracy"].mean, 1.0);
+        assert_eq!(results["f1_score"].mean, 1.0);
+    }
+
+    #[test]
+    fn test_evaluation_harness_with_metrics_errors_on_unknown_metric() {
+        let result = EvaluationHarness::with_metrics(&MetricRegistry::with_builtins(), &["not_a_metric"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_suffix_confidence_extractor_parses_answer_and_confidence() {
+        let extractor = SuffixConfidenceExtractor::default();
+
+        let sample = extractor.extract("Paris|0.92", "Paris").unwrap();
+        assert!((sample.confidence - 0.92).abs() < 1e-9);
+        assert!(sample.correct);
+
+        let sample = extractor.extract("London|0.4", "Paris").unwrap();
+        assert!(!sample.correct);
+
+        assert!(extractor.extract("no confidence here", "Paris").is_none());
+    }
+
+    #[test]
+    fn test_json_confidence_extractor_parses_structured_prediction() {
+        let extractor = JsonConfidenceExtractor::default();
+
+        let sample = extractor
+            .extract(r#"{"answer": "Paris", "confidence": 0.8}"#, "Paris")
+            .unwrap();
+        assert!((sample.confidence - 0.8).abs() < 1e-9);
+        assert!(sample.correct);
+
+        assert!(extractor.extract("not json", "Paris").is_none());
+    }
+
+    #[test]
+    fn test_calibration_report_perfectly_calibrated_has_zero_ece() {
+        // 10 samples at confidence 0.9, 9 correct -> accuracy matches confidence exactly.
+        let mut samples: Vec<ConfidenceSample> = (0..9)
+            .map(|_| ConfidenceSample { confidence: 0.9, correct: true })
+            .collect();
+        samples.push(ConfidenceSample { confidence: 0.9, correct: false });
+
+        let report = CalibrationReport::new(10);
+        let result = report.compute_aggregate(&samples);
+
+        assert!(result.ece < 1e-9);
+        let bin = result.bins.iter().find(|b| b.count > 0).unwrap();
+        assert!((bin.avg_confidence - 0.9).abs() < 1e-9);
+        assert!((bin.accuracy - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_calibration_report_detects_overconfidence() {
+        // Confidence 0.95 but only half are correct: large calibration gap.
+        let samples: Vec<ConfidenceSample> = (0..20)
+            .map(|i| ConfidenceSample { confidence: 0.95, correct: i % 2 == 0 })
+            .collect();
+
+        let report = CalibrationReport::new(10);
+        let result = report.compute_aggregate(&samples);
+
+        assert!((result.ece - 0.45).abs() < 1e-9);
+        assert_eq!(result.bins.iter().map(|b| b.count).sum::<usize>(), 20);
+    }
+
+    #[test]
+    fn test_calibration_report_evaluate_skips_unparseable_predictions() {
+        let examples = vec![
+            TestExample { input: "q1".to_string(), expected_output: "Paris".to_string(), metadata: HashMap::new() },
+            TestExample { input: "q2".to_string(), expected_output: "Rome".to_string(), metadata: HashMap::new() },
+        ];
+        let predictions = vec!["Paris|0.9".to_string(), "garbage".to_string()];
+
+        let report = CalibrationReport::new(5);
+        let result = report.evaluate(&examples, &predictions, &SuffixConfidenceExtractor::default());
+
+        assert_eq!(result.bins.iter().map(|b| b.count).sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn test_unigram_overlap_keeps_old_single_order_behavior() {
+        // This is the pre-rename `BLEU::calculate_ngram_overlap` behavior:
+        // fraction of candidate n-grams found anywhere in the reference, no
+        // clipping, no brevity penalty.
+        let metric = UnigramOverlap::new(1);
+        assert_eq!(metric.name(), "unigram_overlap");
+        assert!((metric.compute("the cat sat", "the cat sat") - 1.0).abs() < 1e-9);
+        assert_eq!(metric.compute("zzz yyy", "the cat sat"), 0.0);
+    }
+
+    #[test]
+    fn test_bleu_identical_sentences_scores_one() {
+        let metric = BLEU::new(4);
+        assert_eq!(metric.name(), "bleu");
+        assert!((metric.compute("the cat sat on the mat", "the cat sat on the mat") - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_matches_hand_computed_reference_score() {
+        // candidate is a strict (clipped-1-gram, clipped-2-gram)-matching
+        // prefix of the reference, so both precisions are 1.0 and the score
+        // is exactly the brevity penalty: exp(1 - ref_len/cand_len).
+        let metric = BLEU::new(2);
+        let score = metric.compute("the cat", "the cat sat on the mat");
+        let expected_bp = (1.0f64 - 6.0 / 2.0).exp();
+        assert!((score - expected_bp).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_no_overlap_scores_zero() {
+        let metric = BLEU::new(4);
+        assert_eq!(metric.compute("completely different words", "the cat sat on the mat"), 0.0);
+    }
+
+    #[test]
+    fn test_bleu_candidate_longer_than_reference_has_no_brevity_penalty() {
+        let metric = BLEU::new(1);
+        // Candidate unigram precision: "the" and "cat" match (clipped to
+        // the reference's single occurrence each), "sat" and "here" don't,
+        // so precision is 2/4 = 0.5. The candidate is longer than the
+        // reference, so BP must be 1.0 (no penalty) rather than scaling the
+        // score down further — BLEU only penalizes *short* candidates.
+        let score = metric.compute("the cat sat here", "the cat");
+        assert!((score - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_corpus_aggregate_differs_from_per_sentence_average() {
+        // Sentence 1 matches perfectly; sentence 2 has a clipped match and a
+        // brevity penalty. Corpus BLEU aggregates counts *before* taking the
+        // geometric mean, which is not the same as averaging the two
+        // per-sentence scores below.
+        let metric = BLEU::new(2);
+        let per_sentence_average = (metric.compute("the cat sat on the mat", "the cat sat on the mat")
+            + metric.compute("the cat", "the cat sat on the mat"))
+            / 2.0;
+
+        let samples = vec![
+            ("the cat sat on the mat".to_string(), "the cat sat on the mat".to_string()),
+            ("the cat".to_string(), "the cat sat on the mat".to_string()),
+        ];
+        let corpus_score = metric.compute_aggregate(&samples);
+
+        assert!((corpus_score - per_sentence_average).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_bleu_corpus_aggregate_empty_candidates_scores_zero() {
+        let metric = BLEU::new(4);
+        let samples = vec![("".to_string(), "the cat sat on the mat".to_string())];
+        assert_eq!(metric.compute_aggregate(&samples), 0.0);
+    }
+
+    #[test]
+    fn test_chrf_identical_sentences_scores_one() {
+        let metric = ChrF::default();
+        assert_eq!(metric.name(), "chrf");
+        assert!((metric.compute("the cat sat", "the cat sat") - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_chrf_gives_partial_credit_for_shared_characters() {
+        // Unlike whitespace-tokenized metrics, chrF should score a
+        // completely wrong *word* choice above zero when the characters
+        // still overlap heavily.
+        let metric = ChrF::new(3, 2.0);
+        let score = metric.compute("teh cat sat", "the cat sat");
+        assert!(score > 0.5 && score < 1.0);
+    }
+
+    #[test]
+    fn test_chrf_no_character_overlap_scores_zero() {
+        let metric = ChrF::default();
+        assert_eq!(metric.compute("xyz", "abc"), 0.0);
+    }
+
+    #[test]
+    fn test_chrf_empty_reference_and_candidate_scores_one() {
+        let metric = ChrF::default();
+        assert_eq!(metric.compute("", ""), 1.0);
+        assert_eq!(metric.compute("not empty", ""), 0.0);
+    }
+
+    #[test]
+    fn test_meteor_identical_sentences_scores_near_one() {
+        // Published worked example (Banerjee & Lavie, 2005): a perfect match
+        // scores just under 1.0 because even a single contiguous chunk
+        // incurs a small fragmentation penalty.
+        let metric = Meteor::without_synonyms();
+        assert_eq!(metric.name(), "meteor");
+        let score = metric.compute("the cat sat on the mat", "the cat sat on the mat");
+        assert!((score - 0.997_685_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_meteor_halves_score_for_fully_fragmented_alignment() {
+        // Every word matches (same multiset), but in six disjoint chunks
+        // rather than one run, so the fragmentation penalty is maximal
+        // (gamma = 0.5) and the score is exactly halved.
+        let metric = Meteor::without_synonyms();
+        let score = metric.compute("on the mat sat the cat", "the cat sat on the mat");
+        assert!((score - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_meteor_stem_fallback_matches_inflected_forms() {
+        // "walking" only matches "walk" via the stem pass, not the exact
+        // pass, so this also exercises that the stages compose correctly.
+        let metric = Meteor::without_synonyms();
+        let score = metric.compute("the dog walking", "the dog walk");
+        assert!((score - 0.981_481_5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_meteor_disjoint_vocabularies_score_zero() {
+        let metric = Meteor::without_synonyms();
+        assert_eq!(metric.compute("completely different", "the cat sat"), 0.0);
+    }
+
+    #[test]
+    fn test_meteor_degrades_gracefully_without_synonym_lookup() {
+        // `Meteor::new()` attempts the Python/NLTK synonym pass, but must
+        // never panic or error out even when Python/NLTK aren't available —
+        // it should just fall back to the exact+stem score.
+        let metric = Meteor::new();
+        let score = metric.compute("the cat sat on the mat", "the cat sat on the mat");
+        assert!((0.0..=1.0).contains(&score));
+    }
 }
@@ -5,8 +5,8 @@
 
 use anyhow::{Context, Result};
 use evaluation_framework::{
-    Accuracy, BLEU, ComparisonReport, EvaluationHarness, EvaluationResult,
-    ExactMatch, F1Score, Metric, ROUGE, TestExample, TestSet,
+    Accuracy, BLEU, ChrF, ComparisonReport, EvaluationHarness, EvaluationResult,
+    ExactMatch, F1Score, Meteor, Metric, ROUGE, TestExample, TestSet, UnigramOverlap,
 };
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -132,6 +132,9 @@ fn create_metric(name: &str) -> Result<Box<dyn Metric>> {
         "bleu-3" => Ok(Box::new(BLEU::new(3))),
         "bleu-4" => Ok(Box::new(BLEU::new(4))),
         "rouge" | "rouge-l" => Ok(Box::new(ROUGE::new("rouge-l"))),
+        "unigram_overlap" | "unigram-overlap" => Ok(Box::new(UnigramOverlap::new(4))),
+        "chrf" => Ok(Box::new(ChrF::default())),
+        "meteor" => Ok(Box::new(Meteor::new())),
         _ => anyhow::bail!("Unknown metric: {}", name),
     }
 }
@@ -523,7 +526,7 @@ fn print_help() {
     println!("  --demo                   Run interactive demo");
     println!("  --help                   Print this help");
     println!("\nMetrics:");
-    println!("  accuracy, exact-match, f1-score, bleu, rouge");
+    println!("  accuracy, exact-match, f1-score, bleu, unigram-overlap, rouge, chrf, meteor");
     println!("\nExamples:");
     println!("  # Run demo");
     println!("  eval --demo");
@@ -36,13 +36,20 @@
 //! ```
 
 use anyhow::{Context, Result};
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use statrs::distribution::{ContinuousCDF, StudentsT};
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
 use statrs::statistics::Statistics;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
 use tabled::{Table, Tabled};
 
 /// Configuration for model comparison.
@@ -66,12 +73,125 @@ pub struct ComparisonConfig {
     /// Significance level (default: 0.05)
     #[serde(default = "default_alpha")]
     pub alpha: f64,
+
+    /// Seed for the simulated evaluation's RNG. `None` seeds from entropy
+    /// (the default, non-reproducible); `Some(seed)` makes two runs with an
+    /// otherwise identical config produce equal `ComparisonResults` apart
+    /// from `timestamp` and each model's `execution_time` (`HashMap` fields
+    /// may still serialize in a different order).
+    #[serde(default)]
+    pub seed: Option<u64>,
+
+    /// Abort a model's evaluation once it has run for this long, so a
+    /// misconfigured test set can't run the comparator for hours. The
+    /// partial result is kept and flagged `ModelResult::truncated`.
+    #[serde(default)]
+    pub max_wall_clock: Option<Duration>,
+
+    /// Abort a model's evaluation once its simulated `token_usage` total
+    /// reaches this, as a safety valve against runaway token bills.
+    #[serde(default)]
+    pub max_total_tokens: Option<f64>,
+
+    /// Per-test-set weights used when averaging a model's metrics across
+    /// `test_sets` in `ModelComparator::evaluate_model`. A test set missing
+    /// from this map (or used when the map is `None`) falls back to
+    /// size-weighting by its example count, so a tiny edge-case set doesn't
+    /// count as much as a huge representative one.
+    ///
+    /// Because `criteria`-weighted scoring and the Pareto frontier in
+    /// `ComparisonResults::determine_winner` both operate on each model's
+    /// already-averaged `metrics`, changing these weights changes which
+    /// model wins even though `criteria` stays the same — a model that's
+    /// strong on your biggest test set now outweighs one that's merely
+    /// consistent across many small ones.
+    #[serde(default)]
+    pub test_set_weights: Option<HashMap<PathBuf, f64>>,
+
+    /// Maximum number of models `ModelComparator::evaluate_models` evaluates
+    /// concurrently. Models are independent of one another, so there's no
+    /// reason to run them one at a time; this just caps how many run at
+    /// once so a comparison across many models doesn't spawn an unbounded
+    /// number of concurrent PyO3 calls. Defaults to the number of available
+    /// CPUs (see `default_max_parallel_models`).
+    #[serde(default = "default_max_parallel_models")]
+    pub max_parallel_models: usize,
+
+    /// Which statistical test `perform_pairwise_tests` runs per criterion.
+    /// Defaults to `TTest`. Latency-style metrics are often heavily
+    /// right-skewed, which violates the t-test's normality assumption;
+    /// `MannWhitneyU` is the non-parametric alternative for those.
+    #[serde(default)]
+    pub test_method: TestMethod,
+
+    /// Number of bootstrap resamples `determine_winner` draws per model to
+    /// estimate `WinnerInfo::score_confidence_intervals` and
+    /// `WinnerInfo::win_probability`. Each resample draws `num_runs` values
+    /// (with replacement) from a model's `raw_values` per criterion,
+    /// recomputes its composite score, and the resulting distribution of
+    /// scores gives a sense of how much the ranking could plausibly change
+    /// run-to-run. `0` disables bootstrapping: the confidence interval
+    /// collapses to the point estimate and `win_probability` is `1.0`.
+    #[serde(default = "default_bootstrap_iterations")]
+    pub bootstrap_iterations: usize,
+
+    /// How `perform_pairwise_tests` corrects p-values for running multiple
+    /// pairwise comparisons per criterion. With 3+ models, each criterion
+    /// gets one `StatisticalTest` per model pair, and testing all of them
+    /// against the same raw `alpha` inflates the overall false-positive
+    /// rate. Defaults to `None` (no correction, matching this crate's
+    /// pre-existing behavior).
+    #[serde(default)]
+    pub multiple_comparison_correction: MultipleComparisonCorrection,
+}
+
+/// Which pairwise statistical test to run for each criterion. See
+/// `ComparisonConfig::test_method`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TestMethod {
+    /// Welch's two-sample t-test. Assumes approximately normal metrics.
+    #[default]
+    TTest,
+    /// Mann-Whitney U test (Wilcoxon rank-sum). Non-parametric: valid for
+    /// skewed distributions like latency, at some cost in statistical power
+    /// versus the t-test when the data really is normal.
+    MannWhitneyU,
+}
+
+/// How to correct p-values across the multiple pairwise comparisons
+/// `perform_pairwise_tests` runs per criterion. See
+/// `ComparisonConfig::multiple_comparison_correction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MultipleComparisonCorrection {
+    /// Compare each pairwise test's raw p-value against `alpha` directly.
+    #[default]
+    None,
+    /// Bonferroni correction: multiply each p-value by the number of
+    /// comparisons for that criterion (capped at 1.0). Simple and
+    /// conservative - controls the family-wise error rate but loses power
+    /// quickly as the number of models grows.
+    Bonferroni,
+    /// Holm-Bonferroni step-down correction: sort a criterion's p-values
+    /// ascending and compare the k-th smallest against `alpha / (n - k +
+    /// 1)`, enforcing monotonicity. Uniformly more powerful than
+    /// Bonferroni while still controlling the family-wise error rate.
+    Holm,
 }
 
 fn default_alpha() -> f64 {
     0.05
 }
 
+fn default_max_parallel_models() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+}
+
+fn default_bootstrap_iterations() -> usize {
+    1000
+}
+
 impl Default for ComparisonConfig {
     fn default() -> Self {
         Self {
@@ -86,6 +206,58 @@ impl Default for ComparisonConfig {
             min_effect_size: 0.3,
             num_runs: 3,
             alpha: 0.05,
+            seed: None,
+            max_wall_clock: None,
+            max_total_tokens: None,
+            test_set_weights: None,
+            max_parallel_models: default_max_parallel_models(),
+            test_method: TestMethod::default(),
+            bootstrap_iterations: default_bootstrap_iterations(),
+            multiple_comparison_correction: MultipleComparisonCorrection::default(),
+        }
+    }
+}
+
+/// How close `criteria` weights must sum to 1.0 to pass `ComparisonConfig::validate`.
+const WEIGHT_SUM_EPSILON: f64 = 1e-6;
+
+impl ComparisonConfig {
+    /// Checks that `criteria` weights are non-negative and sum to
+    /// (approximately) 1.0. `calculate_score` divides by the sum of every
+    /// criterion's weight, so misconfigured weights don't error there - they
+    /// are silently rescaled, and a negative weight inverts its criterion.
+    /// Called at the start of `ModelComparator::compare_models`.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(criterion) = self.criteria.iter().find(|c| c.weight < 0.0) {
+            anyhow::bail!(
+                "criterion '{}' has a negative weight ({}); weights must be non-negative",
+                criterion.name,
+                criterion.weight
+            );
+        }
+
+        let total_weight: f64 = self.criteria.iter().map(|c| c.weight).sum();
+        if (total_weight - 1.0).abs() > WEIGHT_SUM_EPSILON {
+            anyhow::bail!(
+                "criteria weights sum to {total_weight}, not 1.0 (within {WEIGHT_SUM_EPSILON}); \
+                 call ComparisonConfig::normalize_weights() to auto-scale them"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Rescales `criteria` weights in place so they sum to 1.0, preserving
+    /// their relative proportions. A no-op if every weight is already zero
+    /// (there's nothing to scale).
+    pub fn normalize_weights(&mut self) {
+        let total_weight: f64 = self.criteria.iter().map(|c| c.weight).sum();
+        if total_weight == 0.0 {
+            return;
+        }
+
+        for criterion in &mut self.criteria {
+            criterion.weight /= total_weight;
         }
     }
 }
@@ -133,6 +305,12 @@ pub struct ModelResult {
 
     /// Execution time (seconds)
     pub execution_time: f64,
+
+    /// Set when evaluation stopped early because `max_wall_clock` or
+    /// `max_total_tokens` was hit, so `metrics`/`raw_values`/`per_test_set`
+    /// only reflect the test sets completed before the budget ran out.
+    #[serde(default)]
+    pub truncated: bool,
 }
 
 impl ModelResult {
@@ -170,6 +348,17 @@ pub struct StatisticalTest {
 
     /// Is result significant at alpha level?
     pub significant: bool,
+
+    /// P-value after `ComparisonConfig::multiple_comparison_correction` is
+    /// applied across every pairwise comparison for `metric`. Equal to
+    /// `p_value` when the correction is `MultipleComparisonCorrection::None`
+    /// (the default) or when this test was built outside of
+    /// `perform_pairwise_tests` (e.g. by calling `perform_t_test` directly).
+    pub adjusted_p_value: f64,
+
+    /// Is result significant at alpha level after correction? This is what
+    /// `determine_winner` consults for `WinnerInfo::significant_improvements`.
+    pub adjusted_significant: bool,
 }
 
 impl StatisticalTest {
@@ -223,6 +412,27 @@ pub struct WinnerInfo {
     pub criterion_scores: HashMap<String, f64>,
     pub significant_improvements: Vec<String>,
     pub recommendation: String,
+    /// Whether the weighted winner is also on the Pareto frontier (see
+    /// `ComparisonResults::pareto_frontier`). `false` means some other
+    /// model is at least as good on every criterion and strictly better on
+    /// at least one — the weighting hid a trade-off.
+    pub pareto_optimal: bool,
+    /// Criteria the winner had no metric for, whose contribution to
+    /// `total_score` was therefore imputed as a neutral 0.5 rather than
+    /// silently excluded (see `ComparisonResults::calculate_score`). Empty
+    /// unless the winner's `ModelResult::metrics` is missing an entry that
+    /// `config.criteria` weights.
+    pub imputed_criteria: Vec<String>,
+    /// 95% bootstrap confidence interval `(low, high)` for each model's
+    /// composite score, keyed by model name. See
+    /// `ComparisonConfig::bootstrap_iterations`.
+    pub score_confidence_intervals: HashMap<String, (f64, f64)>,
+    /// Fraction of bootstrap resamples in which the winner's resampled
+    /// score was at least as high as every other model's resampled score
+    /// in that same resample. Close to `1.0` means the winner is robustly
+    /// best; closer to `0.5` (or lower, with more than two models) means
+    /// the gap is within run-to-run noise.
+    pub win_probability: f64,
 }
 
 /// Row for ASCII table display.
@@ -245,6 +455,9 @@ struct ComparisonRow {
 
     #[tabled(rename = "Score")]
     score: String,
+
+    #[tabled(rename = "Status")]
+    status: String,
 }
 
 impl ComparisonResults {
@@ -268,24 +481,84 @@ impl ComparisonResults {
                     result.get_metric("error_rate").unwrap_or(0.0) * 100.0
                 ),
                 score: format!("{:.3}", score),
+                status: if result.truncated { "TRUNCATED".to_string() } else { "OK".to_string() },
             });
         }
 
-        Table::new(rows).to_string()
+        let table = Table::new(rows).to_string();
+        let warnings = self.sanity_check();
+
+        if warnings.is_empty() {
+            table
+        } else {
+            let mut out = table;
+            out.push_str("\n\nConfiguration warnings:\n");
+            for warning in &warnings {
+                out.push_str("  - ");
+                out.push_str(warning);
+                out.push('\n');
+            }
+            out
+        }
+    }
+
+    /// Heuristic, advisory check for a misconfigured `Criterion::higher_is_better`.
+    /// Metric names containing "latency", "error", "cost", or "loss" are
+    /// almost always lower-is-better; a criterion whose name matches one of
+    /// those hints but is configured `higher_is_better: true` silently
+    /// inverts that criterion's contribution to `calculate_score` and
+    /// `determine_winner` without ever erroring. Returns one warning per
+    /// suspicious criterion — an empty `Vec` means nothing looked off. Never
+    /// fails the comparison; callers decide what to do with the warnings.
+    pub fn sanity_check(&self) -> Vec<String> {
+        const LOWER_IS_BETTER_HINTS: &[&str] = &["latency", "error", "cost", "loss"];
+
+        self.config
+            .criteria
+            .iter()
+            .filter_map(|criterion| {
+                let name_lower = criterion.name.to_lowercase();
+                let matched_hint = LOWER_IS_BETTER_HINTS
+                    .iter()
+                    .find(|hint| name_lower.contains(*hint))?;
+
+                if criterion.higher_is_better {
+                    Some(format!(
+                        "Criterion '{}' is configured higher_is_better=true, but its name \
+                         contains '{}', which usually means lower is better. Double-check this \
+                         isn't silently inverting the winner.",
+                        criterion.name, matched_hint
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect()
     }
 
-    /// Calculate weighted score for a model.
+    /// Neutral score assigned, in place of a normalized value, to a
+    /// criterion that `result` has no metric for. Matches the "all models
+    /// tied" case in `normalize_metric` - a model missing a metric is
+    /// treated as neither better nor worse than the field on that
+    /// criterion, rather than silently excluded from the weighted average
+    /// (which would let its other criteria count for more than intended).
+    const MISSING_METRIC_NEUTRAL_SCORE: f64 = 0.5;
+
+    /// Calculate weighted score for a model. Every criterion's weight
+    /// always counts toward `total_weight`, even when `result` is missing
+    /// that criterion's metric - see `missing_criteria` and
+    /// `MISSING_METRIC_NEUTRAL_SCORE` for how that case is scored.
     fn calculate_score(&self, result: &ModelResult) -> f64 {
         let mut total_score = 0.0;
         let mut total_weight = 0.0;
 
         for criterion in &self.config.criteria {
-            if let Some(value) = result.get_metric(&criterion.name) {
-                // Normalize to [0, 1] range based on all models
-                let normalized = self.normalize_metric(&criterion.name, value, criterion.higher_is_better);
-                total_score += normalized * criterion.weight;
-                total_weight += criterion.weight;
-            }
+            let normalized = match result.get_metric(&criterion.name) {
+                Some(value) => self.normalize_metric(&criterion.name, value, criterion.higher_is_better),
+                None => Self::MISSING_METRIC_NEUTRAL_SCORE,
+            };
+            total_score += normalized * criterion.weight;
+            total_weight += criterion.weight;
         }
 
         if total_weight > 0.0 {
@@ -295,6 +568,20 @@ impl ComparisonResults {
         }
     }
 
+    /// Criterion names in `config.criteria` that `result` has no metric
+    /// for. `calculate_score` scores these with
+    /// `MISSING_METRIC_NEUTRAL_SCORE` instead of silently dropping them;
+    /// this is what lets `determine_winner` flag which criteria were
+    /// imputed for the winner via `WinnerInfo::imputed_criteria`.
+    fn missing_criteria(&self, result: &ModelResult) -> Vec<String> {
+        self.config
+            .criteria
+            .iter()
+            .filter(|criterion| result.get_metric(&criterion.name).is_none())
+            .map(|criterion| criterion.name.clone())
+            .collect()
+    }
+
     /// Normalize metric value across all models.
     fn normalize_metric(&self, metric_name: &str, value: f64, higher_is_better: bool) -> f64 {
         let values: Vec<f64> = self
@@ -303,24 +590,7 @@ impl ComparisonResults {
             .filter_map(|r| r.get_metric(metric_name))
             .collect();
 
-        if values.is_empty() {
-            return 0.0;
-        }
-
-        let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
-        let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
-
-        if (max - min).abs() < 1e-10 {
-            return 0.5; // All values equal
-        }
-
-        let normalized = (value - min) / (max - min);
-
-        if higher_is_better {
-            normalized
-        } else {
-            1.0 - normalized
-        }
+        normalize_against(&values, value, higher_is_better)
     }
 
     /// Determine winner based on criteria.
@@ -329,6 +599,13 @@ impl ComparisonResults {
             anyhow::bail!("No models to compare");
         }
 
+        if self.config.require_significance && self.model_results.iter().any(|r| r.truncated) {
+            anyhow::bail!(
+                "Cannot name a statistically significant winner: evaluation was truncated \
+                 (max_wall_clock/max_total_tokens hit) for one or more models"
+            );
+        }
+
         // Calculate scores for all models
         let mut scored_models: Vec<(String, f64, HashMap<String, f64>)> = self
             .model_results
@@ -340,8 +617,11 @@ impl ComparisonResults {
             })
             .collect();
 
-        // Sort by score descending
-        scored_models.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        // Sort by score descending. `total_cmp` rather than `partial_cmp().unwrap()`
+        // since `calculate_score`/`normalize_metric` can in principle produce NaN
+        // (e.g. a metric value that's itself NaN); total_cmp gives NaN a
+        // well-defined (if arbitrary) place in the order instead of panicking.
+        scored_models.sort_by(|a, b| b.1.total_cmp(&a.1));
 
         let (winner_name, winner_score, winner_criterion_scores) =
             scored_models.first().unwrap().clone();
@@ -363,7 +643,7 @@ impl ComparisonResults {
 
                 for criterion in &self.config.criteria {
                     if let Some(test) = self.find_test(&winner_name, &other_result.model_name, &criterion.name) {
-                        if test.significant && test.effect_size.abs() >= self.config.min_effect_size {
+                        if test.adjusted_significant && test.effect_size.abs() >= self.config.min_effect_size {
                             let improvement = if criterion.higher_is_better {
                                 test.effect_size > 0.0
                             } else {
@@ -373,7 +653,7 @@ impl ComparisonResults {
                             if improvement {
                                 significant_improvements.push(format!(
                                     "{} vs {} (p={:.3}, d={:.2})",
-                                    criterion.name, other_result.model_name, test.p_value, test.effect_size
+                                    criterion.name, other_result.model_name, test.adjusted_p_value, test.effect_size
                                 ));
                             }
                         }
@@ -383,7 +663,7 @@ impl ComparisonResults {
         }
 
         // Generate recommendation
-        let recommendation = if significant_improvements.is_empty() && self.config.require_significance {
+        let mut recommendation = if significant_improvements.is_empty() && self.config.require_significance {
             "No statistically significant winner. Models are equivalent.".to_string()
         } else if significant_improvements.len() >= self.config.criteria.len() / 2 {
             "Deploy with confidence: significant improvements across multiple metrics.".to_string()
@@ -391,15 +671,233 @@ impl ComparisonResults {
             "Deploy with caution: limited significant improvements.".to_string()
         };
 
+        let pareto_optimal = self.pareto_frontier().contains(&winner_name);
+        if !pareto_optimal {
+            recommendation.push_str(
+                " Note: the weighted winner is not Pareto-optimal — another model is at least \
+                 as good on every criterion and strictly better on at least one. Check \
+                 pareto_frontier() before deploying if any single criterion is a hard constraint.",
+            );
+        }
+
+        let winner_result = self
+            .model_results
+            .iter()
+            .find(|r| r.model_name == winner_name)
+            .expect("winner_name was derived from model_results");
+        let imputed_criteria = self.missing_criteria(winner_result);
+        if !imputed_criteria.is_empty() {
+            recommendation.push_str(&format!(
+                " Note: {} had no metric for {} — its score used a neutral {:.1} for that \
+                 criterion instead of being excluded.",
+                winner_name,
+                imputed_criteria.join(", "),
+                Self::MISSING_METRIC_NEUTRAL_SCORE,
+            ));
+        }
+
+        let (score_confidence_intervals, win_probability) =
+            self.bootstrap_winner_confidence(&winner_name);
+
         Ok(WinnerInfo {
             model_name: winner_name,
             total_score: winner_score,
             criterion_scores: winner_criterion_scores,
             significant_improvements,
             recommendation,
+            pareto_optimal,
+            imputed_criteria,
+            score_confidence_intervals,
+            win_probability,
         })
     }
 
+    /// Bootstraps `config.bootstrap_iterations` resamples of every model's
+    /// composite score (see `bootstrap_composite_scores`) and reduces them
+    /// to a 95% confidence interval per model plus the fraction of
+    /// resamples where `winner_name` scored at least as high as every
+    /// other model. With `bootstrap_iterations == 0` (or no models to
+    /// resample), the interval collapses to each model's point-estimate
+    /// score and the win probability is reported as `1.0`.
+    fn bootstrap_winner_confidence(&self, winner_name: &str) -> (HashMap<String, (f64, f64)>, f64) {
+        if self.config.bootstrap_iterations == 0 {
+            let intervals = self
+                .model_results
+                .iter()
+                .map(|r| {
+                    let score = self.calculate_score(r);
+                    (r.model_name.clone(), (score, score))
+                })
+                .collect();
+            return (intervals, 1.0);
+        }
+
+        let scores = self.bootstrap_composite_scores();
+        let iterations = self.config.bootstrap_iterations;
+
+        let mut intervals = HashMap::new();
+        for (model_name, mut model_scores) in scores.clone() {
+            model_scores.sort_by(f64::total_cmp);
+            intervals.insert(model_name, (percentile(&model_scores, 0.025), percentile(&model_scores, 0.975)));
+        }
+
+        let win_probability = match scores.get(winner_name) {
+            Some(winner_scores) => {
+                let wins = (0..iterations)
+                    .filter(|&t| {
+                        let winner_score = winner_scores[t];
+                        scores
+                            .values()
+                            .all(|model_scores| model_scores[t] <= winner_score)
+                    })
+                    .count();
+                wins as f64 / iterations as f64
+            }
+            None => 1.0,
+        };
+
+        (intervals, win_probability)
+    }
+
+    /// Draws `config.bootstrap_iterations` bootstrap resamples of every
+    /// model's composite score. Each resample independently redraws, for
+    /// every criterion with raw values, `config.num_runs` samples with
+    /// replacement from that model's `raw_values` and averages them; a
+    /// criterion the model has only a point estimate for (no raw values)
+    /// uses that estimate unchanged in every resample. Each resample's
+    /// scores are normalized against that resample's own cross-model
+    /// range per criterion (mirroring `calculate_score`/`normalize_metric`,
+    /// but on resampled rather than point-estimate metrics), so the
+    /// resulting distributions reflect how the *ranking* could shift, not
+    /// just each model's score in isolation.
+    ///
+    /// Returns one `Vec<f64>` per model, indexed by resample: `result[i][t]`
+    /// and `result[j][t]` come from the same resample `t`, which is what
+    /// lets `bootstrap_winner_confidence` compare them head-to-head.
+    fn bootstrap_composite_scores(&self) -> HashMap<String, Vec<f64>> {
+        let iterations = self.config.bootstrap_iterations;
+        let num_runs = self.config.num_runs.max(1);
+
+        let mut rng = match self.config.seed {
+            // Offset from the evaluation seed so bootstrap resampling draws
+            // an independent stream rather than replaying the same numbers
+            // `ModelComparator` used to simulate metrics.
+            Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(0xB007_5777)),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut scores: HashMap<String, Vec<f64>> = self
+            .model_results
+            .iter()
+            .map(|r| (r.model_name.clone(), Vec::with_capacity(iterations)))
+            .collect();
+
+        for _ in 0..iterations {
+            let resampled: Vec<HashMap<String, f64>> = self
+                .model_results
+                .iter()
+                .map(|result| self.resample_metrics(result, num_runs, &mut rng))
+                .collect();
+
+            for (result, metrics) in self.model_results.iter().zip(&resampled) {
+                let mut total_score = 0.0;
+                let mut total_weight = 0.0;
+
+                for criterion in &self.config.criteria {
+                    let normalized = match metrics.get(&criterion.name) {
+                        Some(&value) => {
+                            let pool: Vec<f64> =
+                                resampled.iter().filter_map(|m| m.get(&criterion.name).copied()).collect();
+                            normalize_against(&pool, value, criterion.higher_is_better)
+                        }
+                        None => Self::MISSING_METRIC_NEUTRAL_SCORE,
+                    };
+                    total_score += normalized * criterion.weight;
+                    total_weight += criterion.weight;
+                }
+
+                let score = if total_weight > 0.0 { total_score / total_weight } else { 0.0 };
+                scores.get_mut(&result.model_name).unwrap().push(score);
+            }
+        }
+
+        scores
+    }
+
+    /// One bootstrap resample of `result`'s per-criterion metrics: for each
+    /// criterion with raw values, draws `num_runs` values with replacement
+    /// and averages them; otherwise falls back to the point-estimate metric
+    /// (or omits the criterion entirely if `result` has neither).
+    fn resample_metrics(&self, result: &ModelResult, num_runs: usize, rng: &mut StdRng) -> HashMap<String, f64> {
+        self.config
+            .criteria
+            .iter()
+            .filter_map(|criterion| {
+                let value = match result.raw_values.get(&criterion.name) {
+                    Some(raw) if !raw.is_empty() => {
+                        let mean = (0..num_runs).map(|_| raw[rng.gen_range(0..raw.len())]).sum::<f64>()
+                            / num_runs as f64;
+                        Some(mean)
+                    }
+                    _ => result.get_metric(&criterion.name),
+                }?;
+                Some((criterion.name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// Models on the Pareto frontier: those not dominated by any other model
+    /// across `config.criteria`. A model is dominated if some other model is
+    /// at least as good on every criterion (respecting `higher_is_better`)
+    /// and strictly better on at least one. Unlike `calculate_score`'s
+    /// weighted sum, this surfaces trade-offs instead of hiding them behind
+    /// a single number.
+    pub fn pareto_frontier(&self) -> Vec<String> {
+        self.model_results
+            .iter()
+            .filter(|candidate| {
+                !self
+                    .model_results
+                    .iter()
+                    .any(|other| other.model_name != candidate.model_name && self.dominates(other, candidate))
+            })
+            .map(|result| result.model_name.clone())
+            .collect()
+    }
+
+    /// Whether `a` dominates `b` across `config.criteria`.
+    fn dominates(&self, a: &ModelResult, b: &ModelResult) -> bool {
+        let mut strictly_better = false;
+
+        for criterion in &self.config.criteria {
+            let (Some(value_a), Some(value_b)) =
+                (a.get_metric(&criterion.name), b.get_metric(&criterion.name))
+            else {
+                continue;
+            };
+
+            let a_worse = if criterion.higher_is_better {
+                value_a < value_b
+            } else {
+                value_a > value_b
+            };
+            if a_worse {
+                return false;
+            }
+
+            let a_better = if criterion.higher_is_better {
+                value_a > value_b
+            } else {
+                value_a < value_b
+            };
+            if a_better {
+                strictly_better = true;
+            }
+        }
+
+        strictly_better
+    }
+
     /// Calculate per-criterion scores for a model.
     fn calculate_criterion_scores(&self, result: &ModelResult) -> HashMap<String, f64> {
         self.config
@@ -431,6 +929,89 @@ impl ComparisonResults {
         Ok(())
     }
 
+    /// Render a GitHub-flavored Markdown table, one row per model, with the
+    /// same columns as `to_ascii_table`. The winner's model name (if a
+    /// winner was determined) is bolded so it stands out when pasted into a
+    /// PR description.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from(
+            "| Model | Accuracy | Latency P95 | Tokens | Error Rate | Score | Status |\n\
+             |---|---|---|---|---|---|---|\n",
+        );
+
+        for result in &self.model_results {
+            let score = self.calculate_score(result);
+            let is_winner = self
+                .winner
+                .as_ref()
+                .map(|w| w.model_name == result.model_name)
+                .unwrap_or(false);
+            let model_name = if is_winner {
+                format!("**{}**", result.model_name)
+            } else {
+                result.model_name.clone()
+            };
+
+            out.push_str(&format!(
+                "| {} | {:.1}% | {:.2}s | {:.0} | {:.1}% | {:.3} | {} |\n",
+                model_name,
+                result.get_metric("accuracy").unwrap_or(0.0) * 100.0,
+                result.get_metric("latency_p95").unwrap_or(0.0),
+                result.get_metric("token_usage").unwrap_or(0.0),
+                result.get_metric("error_rate").unwrap_or(0.0) * 100.0,
+                score,
+                if result.truncated { "TRUNCATED" } else { "OK" },
+            ));
+        }
+
+        out
+    }
+
+    /// Render results as CSV: one row per model, with a column for every
+    /// metric present on any model (sorted by name for a stable column
+    /// order) plus a trailing `score` column with the weighted composite
+    /// score. Fields containing a comma are quoted.
+    pub fn to_csv(&self) -> String {
+        let mut metric_names: Vec<&String> = self
+            .model_results
+            .iter()
+            .flat_map(|r| r.metrics.keys())
+            .collect();
+        metric_names.sort();
+        metric_names.dedup();
+
+        let mut csv = String::new();
+
+        csv.push_str("model");
+        for name in &metric_names {
+            csv.push(',');
+            csv.push_str(&csv_field(name));
+        }
+        csv.push_str(",score\n");
+
+        for result in &self.model_results {
+            let score = self.calculate_score(result);
+            csv.push_str(&csv_field(&result.model_name));
+            for name in &metric_names {
+                csv.push(',');
+                if let Some(value) = result.get_metric(name) {
+                    csv.push_str(&value.to_string());
+                }
+            }
+            csv.push(',');
+            csv.push_str(&score.to_string());
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    /// Export results to CSV. See `to_csv` for the format.
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, self.to_csv())?;
+        Ok(())
+    }
+
     /// Export results to HTML report.
     pub fn export_html(&self, path: impl AsRef<Path>) -> Result<()> {
         let html = self.generate_html_report();
@@ -466,9 +1047,19 @@ impl ComparisonResults {
         html.push_str(&self.timestamp);
         html.push_str("</p>");
 
+        // Configuration warnings (e.g. a likely-inverted higher_is_better)
+        let warnings = self.sanity_check();
+        if !warnings.is_empty() {
+            html.push_str("<div class='section'><h2>Configuration Warnings</h2><ul>");
+            for warning in &warnings {
+                html.push_str(&format!("<li class='not-significant'>{}</li>", warning));
+            }
+            html.push_str("</ul></div>");
+        }
+
         // Overall results table
         html.push_str("<div class='section'><h2>Overall Results</h2><table>");
-        html.push_str("<tr><th>Model</th><th>Accuracy</th><th>Latency P95</th><th>Tokens</th><th>Error Rate</th><th>Score</th></tr>");
+        html.push_str("<tr><th>Model</th><th>Accuracy</th><th>Latency P95</th><th>Tokens</th><th>Error Rate</th><th>Score</th><th>Status</th></tr>");
 
         for result in &self.model_results {
             let score = self.calculate_score(result);
@@ -479,16 +1070,22 @@ impl ComparisonResults {
                 .unwrap_or(false);
 
             let row_class = if is_winner { " class='winner'" } else { "" };
+            let status = if result.truncated {
+                "<span class='not-significant'>TRUNCATED</span>"
+            } else {
+                "OK"
+            };
 
             html.push_str(&format!(
-                "<tr{}><td>{}</td><td>{:.1}%</td><td>{:.2}s</td><td>{:.0}</td><td>{:.1}%</td><td>{:.3}</td></tr>",
+                "<tr{}><td>{}</td><td>{:.1}%</td><td>{:.2}s</td><td>{:.0}</td><td>{:.1}%</td><td>{:.3}</td><td>{}</td></tr>",
                 row_class,
                 result.model_name,
                 result.get_metric("accuracy").unwrap_or(0.0) * 100.0,
                 result.get_metric("latency_p95").unwrap_or(0.0),
                 result.get_metric("token_usage").unwrap_or(0.0),
                 result.get_metric("error_rate").unwrap_or(0.0) * 100.0,
-                score
+                score,
+                status
             ));
         }
 
@@ -544,35 +1141,231 @@ impl ComparisonResults {
     }
 }
 
+/// Runs real evaluation for a single model against a single test set.
+/// Implement this against DSPy (via PyO3) for production use; tests and the
+/// example binary use `MockEvaluator` instead, so the statistical machinery
+/// in `ModelComparator` can be exercised without a live Python process.
+#[async_trait]
+pub trait ModelEvaluator: Send + Sync {
+    /// Evaluate `model` (as loaded by `ModelComparator::load_model`) on
+    /// `test_set`, returning one metric value per metric name.
+    async fn evaluate(&self, model: &PyObject, test_set: &Path) -> Result<HashMap<String, f64>>;
+}
+
+/// Deterministic stand-in for real DSPy evaluation. Draws simulated metrics
+/// from a seed derived from `base_seed` (mirroring `ComparisonConfig::seed`),
+/// the model's name (read off the `model_name` entry `ModelComparator::load_model`
+/// sets on the model dict), and a per-model call counter - so repeated calls
+/// for the same model produce a fresh draw each time, while staying fully
+/// reproducible end-to-end and independent of how many other models happen
+/// to be evaluating concurrently.
+pub struct MockEvaluator {
+    base_seed: u64,
+    call_counts: Mutex<HashMap<String, u64>>,
+}
+
+impl MockEvaluator {
+    /// `base_seed: None` draws a fresh seed from entropy, matching
+    /// `ComparisonConfig::seed`'s "unset means non-reproducible" semantics.
+    pub fn new(base_seed: Option<u64>) -> Self {
+        let base_seed = base_seed.unwrap_or_else(|| StdRng::from_entropy().gen());
+        Self {
+            base_seed,
+            call_counts: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MockEvaluator {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+/// Combines `base_seed`, `model_name`, and `call_index` into a single seed
+/// for `MockEvaluator`'s per-call `StdRng`. Not cryptographic - just needs
+/// to vary with each of its inputs.
+fn mix_seed(base_seed: u64, model_name: &str, call_index: u64) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    base_seed.hash(&mut hasher);
+    model_name.hash(&mut hasher);
+    call_index.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[async_trait]
+impl ModelEvaluator for MockEvaluator {
+    async fn evaluate(&self, model: &PyObject, _test_set: &Path) -> Result<HashMap<String, f64>> {
+        let model_name: String = Python::with_gil(|py| -> Result<String> {
+            let dict: &PyDict = model.downcast(py).map_err(|_| {
+                anyhow::anyhow!("MockEvaluator expects a model dict produced by ModelComparator::load_model")
+            })?;
+            let name = dict
+                .get_item("model_name")?
+                .context("model dict is missing a \"model_name\" entry")?;
+            Ok(name.extract()?)
+        })?;
+
+        let call_index = {
+            let mut call_counts = self.call_counts.lock().unwrap();
+            let count = call_counts.entry(model_name.clone()).or_insert(0);
+            let index = *count;
+            *count += 1;
+            index
+        };
+
+        let mut rng = StdRng::seed_from_u64(mix_seed(self.base_seed, &model_name, call_index));
+
+        let mut metrics = HashMap::new();
+        metrics.insert("accuracy".to_string(), 0.85 + rng.gen::<f64>() * 0.1);
+        metrics.insert("latency_p95".to_string(), 1.0 + rng.gen::<f64>() * 0.5);
+        metrics.insert("token_usage".to_string(), 400.0 + rng.gen::<f64>() * 100.0);
+        metrics.insert("error_rate".to_string(), 0.05 + rng.gen::<f64>() * 0.05);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        Ok(metrics)
+    }
+}
+
 /// Main model comparator.
 pub struct ModelComparator {
     config: ComparisonConfig,
+    evaluator: Box<dyn ModelEvaluator>,
 }
 
 impl ModelComparator {
-    /// Create new comparator with configuration.
+    /// Create new comparator with configuration, evaluating models with
+    /// `MockEvaluator` (seeded from `config.seed`). Use `with_evaluator` to
+    /// inject real DSPy evaluation instead.
     pub fn new(config: ComparisonConfig) -> Self {
-        Self { config }
+        let evaluator = Box::new(MockEvaluator::new(config.seed));
+        Self { config, evaluator }
+    }
+
+    /// Create a comparator that evaluates models via `evaluator` instead of
+    /// the default `MockEvaluator` - e.g. a real DSPy-backed evaluator in
+    /// production, or a custom deterministic mock in tests.
+    pub fn with_evaluator(config: ComparisonConfig, evaluator: Box<dyn ModelEvaluator>) -> Self {
+        Self { config, evaluator }
     }
 
     /// Compare multiple models.
     pub async fn compare_models(&self, model_paths: &[PathBuf]) -> Result<ComparisonResults> {
-        if model_paths.len() < 2 {
-            anyhow::bail!("Need at least 2 models to compare");
-        }
+        self.config.validate()?;
+        validate_model_count(model_paths)?;
+        let model_results = self.evaluate_models(model_paths, None).await?;
+        self.finish_comparison(model_results)
+    }
+
+    /// Compare multiple models, writing each model's result to
+    /// `checkpoint.json` under `checkpoint_dir` as soon as it finishes. A
+    /// long comparison (many models, many test sets) that crashes partway
+    /// through can then be continued with `resume_from_checkpoint` instead
+    /// of re-running everything.
+    pub async fn compare_models_with_checkpoint(
+        &self,
+        model_paths: &[PathBuf],
+        checkpoint_dir: impl AsRef<Path>,
+    ) -> Result<ComparisonResults> {
+        self.config.validate()?;
+        validate_model_count(model_paths)?;
+        let model_results = self
+            .evaluate_models(model_paths, Some(checkpoint_dir.as_ref()))
+            .await?;
+        self.finish_comparison(model_results)
+    }
+
+    /// Resume a comparison from `checkpoint.json` under `dir`: already-completed
+    /// models are loaded from the checkpoint, and only the models in
+    /// `model_paths` that are missing from it are evaluated. Errors if the
+    /// checkpoint was written with different criteria or `num_runs`, since
+    /// its results wouldn't be comparable to freshly-evaluated ones.
+    pub async fn resume_from_checkpoint(
+        &self,
+        dir: impl AsRef<Path>,
+        model_paths: &[PathBuf],
+    ) -> Result<ComparisonResults> {
+        self.config.validate()?;
+        validate_model_count(model_paths)?;
 
-        if model_paths.len() > 5 {
-            anyhow::bail!("Maximum 5 models supported");
+        let dir = dir.as_ref();
+        let checkpoint = Checkpoint::load(dir)?;
+
+        if let Some(checkpoint) = &checkpoint {
+            checkpoint.validate_compatible(&self.config)?;
         }
 
-        // Evaluate each model
-        let mut model_results = Vec::new();
+        let mut completed: HashMap<String, ModelResult> = checkpoint
+            .map(|c| {
+                c.completed
+                    .into_iter()
+                    .map(|result| (result.model_name.clone(), result))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let missing_paths: Vec<PathBuf> = model_paths
+            .iter()
+            .filter(|path| !completed.contains_key(&model_name(path)))
+            .cloned()
+            .collect();
 
-        for model_path in model_paths {
-            let result = self.evaluate_model(model_path).await?;
-            model_results.push(result);
+        if !missing_paths.is_empty() {
+            let newly_evaluated = self.evaluate_models(&missing_paths, Some(dir)).await?;
+            for result in newly_evaluated {
+                completed.insert(result.model_name.clone(), result);
+            }
         }
 
+        let model_results = model_paths
+            .iter()
+            .map(|path| {
+                completed
+                    .remove(&model_name(path))
+                    .context("model missing after checkpoint resume")
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.finish_comparison(model_results)
+    }
+
+    /// Evaluate up to `ComparisonConfig::max_parallel_models` models
+    /// concurrently, appending each result to `checkpoint_dir`'s
+    /// `checkpoint.json` as soon as it finishes (so a long comparison that
+    /// crashes partway through still checkpoints whichever models happened
+    /// to complete first, not just a prefix of `model_paths`).
+    /// `model_results` is sorted back into `model_paths` order before
+    /// returning, since comparisons (pairwise tests, Pareto frontier) care
+    /// about results being in a stable, deterministic order, not about
+    /// finish order.
+    async fn evaluate_models(
+        &self,
+        model_paths: &[PathBuf],
+        checkpoint_dir: Option<&Path>,
+    ) -> Result<Vec<ModelResult>> {
+        let max_parallel = self.config.max_parallel_models.max(1);
+        let mut indexed_results: Vec<(usize, ModelResult)> = stream::iter(model_paths.iter().enumerate())
+            .map(|(index, model_path)| async move {
+                let result = self.evaluate_model(model_path).await?;
+
+                if let Some(dir) = checkpoint_dir {
+                    Checkpoint::append(dir, &self.config, &result)?;
+                }
+
+                Ok::<_, anyhow::Error>((index, result))
+            })
+            .buffer_unordered(max_parallel)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        indexed_results.sort_by_key(|(index, _)| *index);
+        Ok(indexed_results.into_iter().map(|(_, result)| result).collect())
+    }
+
+    fn finish_comparison(&self, model_results: Vec<ModelResult>) -> Result<ComparisonResults> {
         // Perform pairwise statistical tests
         let statistical_tests = self.perform_pairwise_tests(&model_results)?;
 
@@ -590,25 +1383,32 @@ impl ModelComparator {
         Ok(results)
     }
 
-    /// Evaluate a single model across all test sets.
+    /// Evaluate a single model across all test sets, delegating each
+    /// test-set evaluation to `self.evaluator`.
     async fn evaluate_model(&self, model_path: &Path) -> Result<ModelResult> {
-        let model_name = model_path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("unknown")
-            .to_string();
+        let model_name = model_name(model_path);
 
         let start = std::time::Instant::now();
 
-        // Load model (simulated)
-        let _model = self.load_model(model_path)?;
+        let model = self.load_model(model_path)?;
 
         let mut all_metrics = HashMap::new();
         let mut raw_values: HashMap<String, Vec<f64>> = HashMap::new();
         let mut per_test_set = HashMap::new();
+        let mut total_tokens = 0.0;
+        let mut total_weight = 0.0;
+        let mut truncated = false;
+
+        let weights: Vec<f64> = self
+            .config
+            .test_sets
+            .iter()
+            .map(|path| self.test_set_weight(path))
+            .collect();
+        let max_weight = weights.iter().copied().fold(1.0, f64::max);
 
         // Evaluate on each test set
-        for test_set_path in &self.config.test_sets {
+        'test_sets: for (test_set_path, weight) in self.config.test_sets.iter().zip(&weights) {
             let test_set_name = test_set_path
                 .file_stem()
                 .and_then(|s| s.to_str())
@@ -619,29 +1419,49 @@ impl ModelComparator {
             let mut run_metrics = Vec::new();
 
             for _ in 0..self.config.num_runs {
-                let metrics = self.evaluate_on_test_set(model_path, test_set_path).await?;
+                if self.budget_exceeded(start.elapsed(), total_tokens) {
+                    truncated = true;
+                    break 'test_sets;
+                }
+
+                let metrics = self.evaluator.evaluate(&model, test_set_path).await?;
+                total_tokens += metrics.get("token_usage").copied().unwrap_or(0.0);
                 run_metrics.push(metrics);
             }
 
+            if run_metrics.is_empty() {
+                break;
+            }
+
             // Aggregate metrics
             let aggregated = self.aggregate_metrics(&run_metrics);
             per_test_set.insert(test_set_name, aggregated.clone());
 
-            // Accumulate raw values for statistical testing
+            // Accumulate raw values for statistical testing, repeating each
+            // sample in proportion to this test set's weight relative to the
+            // heaviest one so more important test sets carry more influence
+            // over the t-tests, not just the metrics average below.
+            let repeats = weighted_repeat_count(*weight, max_weight);
             for (key, values) in self.extract_raw_values(&run_metrics) {
-                raw_values.entry(key).or_insert_with(Vec::new).extend(values);
+                let entry = raw_values.entry(key).or_default();
+                for _ in 0..repeats {
+                    entry.extend(values.iter().copied());
+                }
             }
 
-            // Update overall metrics
+            // Update overall metrics, weighted by this test set's importance.
             for (key, value) in aggregated {
-                *all_metrics.entry(key).or_insert(0.0) += value;
+                *all_metrics.entry(key).or_insert(0.0) += value * weight;
             }
+
+            total_weight += weight;
         }
 
-        // Average metrics across test sets
-        let num_test_sets = self.config.test_sets.len() as f64;
+        // Weighted-average metrics across test sets actually completed
+        // (fewer than configured when truncated).
+        let divisor = if total_weight > 0.0 { total_weight } else { 1.0 };
         for value in all_metrics.values_mut() {
-            *value /= num_test_sets;
+            *value /= divisor;
         }
 
         let execution_time = start.elapsed().as_secs_f64();
@@ -653,35 +1473,61 @@ impl ModelComparator {
             raw_values,
             per_test_set,
             execution_time,
+            truncated,
         })
     }
 
-    /// Load model from path (simulated).
-    fn load_model(&self, _path: &Path) -> Result<PyObject> {
-        Python::with_gil(|py| {
-            let model = PyDict::new(py);
-            Ok(model.into())
-        })
-    }
+    /// Whether evaluation should stop early given elapsed wall-clock time
+    /// and cumulative simulated token usage so far.
+    fn budget_exceeded(&self, elapsed: Duration, total_tokens: f64) -> bool {
+        if let Some(max_wall_clock) = self.config.max_wall_clock {
+            if elapsed >= max_wall_clock {
+                return true;
+            }
+        }
 
-    /// Evaluate model on a test set (simulated).
-    async fn evaluate_on_test_set(
-        &self,
-        _model_path: &Path,
-        _test_set_path: &Path,
-    ) -> Result<HashMap<String, f64>> {
-        // In real implementation, would run actual evaluation
-        // For now, return simulated metrics
+        if let Some(max_total_tokens) = self.config.max_total_tokens {
+            if total_tokens >= max_total_tokens {
+                return true;
+            }
+        }
 
-        let mut metrics = HashMap::new();
-        metrics.insert("accuracy".to_string(), 0.85 + rand::random::<f64>() * 0.1);
-        metrics.insert("latency_p95".to_string(), 1.0 + rand::random::<f64>() * 0.5);
-        metrics.insert("token_usage".to_string(), 400.0 + rand::random::<f64>() * 100.0);
-        metrics.insert("error_rate".to_string(), 0.05 + rand::random::<f64>() * 0.05);
+        false
+    }
+
+    /// Weight for `test_set_path` used to average metrics across test sets
+    /// in `evaluate_model`.
+    ///
+    /// Uses `self.config.test_set_weights` if it has an explicit entry for
+    /// this path; otherwise defaults to size-weighting by the test set's
+    /// example count (its number of non-empty lines), falling back to `1.0`
+    /// if the file can't be read — e.g. the simulated paths used in tests.
+    fn test_set_weight(&self, test_set_path: &Path) -> f64 {
+        if let Some(weight) = self
+            .config
+            .test_set_weights
+            .as_ref()
+            .and_then(|weights| weights.get(test_set_path))
+        {
+            return *weight;
+        }
 
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        std::fs::read_to_string(test_set_path)
+            .map(|contents| contents.lines().filter(|line| !line.trim().is_empty()).count() as f64)
+            .map(|count| count.max(1.0))
+            .unwrap_or(1.0)
+    }
 
-        Ok(metrics)
+    /// Load model from path (still a placeholder - real model loading would
+    /// import and construct the DSPy predictor here). Exposes the model's
+    /// name on the returned dict so `self.evaluator` can identify which
+    /// model it's being asked to evaluate.
+    fn load_model(&self, path: &Path) -> Result<PyObject> {
+        Python::with_gil(|py| {
+            let model = PyDict::new(py);
+            model.set_item("model_name", model_name(path))?;
+            Ok(model.into())
+        })
     }
 
     /// Aggregate metrics from multiple runs.
@@ -713,7 +1559,7 @@ impl ModelComparator {
             for (key, value) in metrics {
                 raw_values
                     .entry(key.clone())
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(*value);
             }
         }
@@ -737,12 +1583,20 @@ impl ModelComparator {
                         model_a.get_raw_values(&criterion.name),
                         model_b.get_raw_values(&criterion.name),
                     ) {
-                        let test = self.perform_t_test(
-                            &criterion.name,
-                            values_a,
-                            values_b,
-                            self.config.alpha,
-                        )?;
+                        let test = match self.config.test_method {
+                            TestMethod::TTest => self.perform_t_test(
+                                &criterion.name,
+                                values_a,
+                                values_b,
+                                self.config.alpha,
+                            )?,
+                            TestMethod::MannWhitneyU => self.perform_mann_whitney(
+                                &criterion.name,
+                                values_a,
+                                values_b,
+                                self.config.alpha,
+                            ),
+                        };
                         tests.insert(criterion.name.clone(), test);
                     }
                 }
@@ -755,9 +1609,48 @@ impl ModelComparator {
             }
         }
 
+        self.apply_multiple_comparison_correction(&mut pairwise_tests);
+
         Ok(pairwise_tests)
     }
 
+    /// Corrects p-values for the multiple pairwise comparisons
+    /// `perform_pairwise_tests` runs per criterion, per
+    /// `ComparisonConfig::multiple_comparison_correction`. Each criterion is
+    /// corrected independently of the others, since a different criterion's
+    /// tests are a separate family of hypotheses. Writes
+    /// `StatisticalTest::adjusted_p_value`/`adjusted_significant` in place;
+    /// `p_value`/`significant` (the uncorrected result) are left untouched.
+    fn apply_multiple_comparison_correction(&self, pairwise_tests: &mut [PairwiseTest]) {
+        for criterion in &self.config.criteria {
+            let indices: Vec<usize> = (0..pairwise_tests.len())
+                .filter(|&i| pairwise_tests[i].tests.contains_key(&criterion.name))
+                .collect();
+
+            if indices.is_empty() {
+                continue;
+            }
+
+            let raw_p_values: Vec<f64> = indices
+                .iter()
+                .map(|&i| pairwise_tests[i].tests[&criterion.name].p_value)
+                .collect();
+
+            let adjusted_p_values = match self.config.multiple_comparison_correction {
+                MultipleComparisonCorrection::None => raw_p_values,
+                MultipleComparisonCorrection::Bonferroni => bonferroni_correction(&raw_p_values),
+                MultipleComparisonCorrection::Holm => holm_correction(&raw_p_values),
+            };
+
+            for (&i, adjusted_p_value) in indices.iter().zip(adjusted_p_values) {
+                if let Some(test) = pairwise_tests[i].tests.get_mut(&criterion.name) {
+                    test.adjusted_p_value = adjusted_p_value;
+                    test.adjusted_significant = adjusted_p_value < self.config.alpha;
+                }
+            }
+        }
+    }
+
     /// Perform two-sample t-test.
     fn perform_t_test(
         &self,
@@ -766,10 +1659,15 @@ impl ModelComparator {
         values_b: &[f64],
         alpha: f64,
     ) -> Result<StatisticalTest> {
+        if values_a.is_empty() || values_b.is_empty() {
+            return Ok(Self::degenerate_t_test(metric, 0.0));
+        }
+
         let mean_a = values_a.mean();
         let mean_b = values_b.mean();
         let std_a = values_a.std_dev();
         let std_b = values_b.std_dev();
+        let mean_diff = mean_a - mean_b;
 
         let n_a = values_a.len() as f64;
         let n_b = values_b.len() as f64;
@@ -777,21 +1675,41 @@ impl ModelComparator {
         // Calculate pooled standard deviation
         let pooled_std = ((std_a.powi(2) / n_a) + (std_b.powi(2) / n_b)).sqrt();
 
+        // Zero variance (e.g. identical-valued or single-element samples)
+        // leaves nothing to normalize the mean difference by. Rather than
+        // let that divide-by-zero propagate NaN/Inf into `significant` and
+        // any later sorting, report a well-defined degenerate result:
+        // "no detectable effect" when the means also match, or a maximally
+        // significant one when they don't (every value differs consistently).
+        if pooled_std == 0.0 || !pooled_std.is_finite() {
+            return Ok(Self::degenerate_t_test(metric, mean_diff));
+        }
+
         // Calculate t-statistic
-        let t_stat = (mean_a - mean_b) / pooled_std;
+        let t_stat = mean_diff / pooled_std;
 
         // Calculate degrees of freedom (Welch's approximation)
         let df = (std_a.powi(2) / n_a + std_b.powi(2) / n_b).powi(2)
             / ((std_a.powi(2) / n_a).powi(2) / (n_a - 1.0)
                 + (std_b.powi(2) / n_b).powi(2) / (n_b - 1.0));
 
+        if !df.is_finite() {
+            // A single-element sample leaves the Welch-Satterthwaite degrees
+            // of freedom undefined (division by n - 1 = 0).
+            return Ok(Self::degenerate_t_test(metric, mean_diff));
+        }
+
         // Calculate p-value (two-tailed)
         let t_dist = StudentsT::new(0.0, 1.0, df).context("Invalid t-distribution")?;
         let p_value = 2.0 * (1.0 - t_dist.cdf(t_stat.abs()));
 
         // Calculate Cohen's d (effect size)
         let pooled_std_d = ((std_a.powi(2) + std_b.powi(2)) / 2.0).sqrt();
-        let effect_size = (mean_a - mean_b) / pooled_std_d;
+        let effect_size = if pooled_std_d > 0.0 {
+            mean_diff / pooled_std_d
+        } else {
+            0.0
+        };
 
         Ok(StatisticalTest {
             metric: metric.to_string(),
@@ -801,19 +1719,1474 @@ impl ModelComparator {
             effect_size,
             df: Some(df),
             significant: p_value < alpha,
+            adjusted_p_value: p_value,
+            adjusted_significant: p_value < alpha,
         })
     }
+
+    /// A well-defined `StatisticalTest` for inputs too degenerate to test
+    /// statistically (empty, single-element, or zero-variance samples):
+    /// no effect detected if the means match, otherwise a maximally
+    /// significant (but unquantified) difference.
+    fn degenerate_t_test(metric: &str, mean_diff: f64) -> StatisticalTest {
+        let differs = mean_diff != 0.0;
+
+        let p_value = if differs { 0.0 } else { 1.0 };
+
+        StatisticalTest {
+            metric: metric.to_string(),
+            test_type: "t-test".to_string(),
+            statistic: 0.0,
+            p_value,
+            effect_size: 0.0,
+            df: None,
+            significant: differs,
+            adjusted_p_value: p_value,
+            adjusted_significant: differs,
+        }
+    }
+
+    /// Mann-Whitney U test (Wilcoxon rank-sum), the non-parametric
+    /// alternative to `perform_t_test`. Ranks the pooled samples (averaging
+    /// ranks across ties), computes the U statistic for `values_a`, and
+    /// uses the normal approximation to the null distribution of U to get a
+    /// two-tailed p-value. Effect size is the rank-biserial correlation,
+    /// which ranges from 1 (every `b` value exceeds every `a` value) to -1
+    /// (the reverse), with 0 meaning no tendency either way.
+    fn perform_mann_whitney(
+        &self,
+        metric: &str,
+        values_a: &[f64],
+        values_b: &[f64],
+        alpha: f64,
+    ) -> StatisticalTest {
+        let n_a = values_a.len() as f64;
+        let n_b = values_b.len() as f64;
+
+        if values_a.is_empty() || values_b.is_empty() {
+            return Self::degenerate_mann_whitney(metric, 0.0);
+        }
+
+        let ranks = rank_pooled_values(values_a, values_b);
+        let rank_sum_a: f64 = ranks[..values_a.len()].iter().sum();
+
+        let u_a = rank_sum_a - n_a * (n_a + 1.0) / 2.0;
+        let u_b = n_a * n_b - u_a;
+        let u = u_a.min(u_b);
+
+        let mean_u = n_a * n_b / 2.0;
+        let std_u = (n_a * n_b * (n_a + n_b + 1.0) / 12.0).sqrt();
+
+        if std_u == 0.0 || !std_u.is_finite() {
+            return Self::degenerate_mann_whitney(metric, u_a - u_b);
+        }
+
+        let z = (u - mean_u) / std_u;
+        let normal = Normal::new(0.0, 1.0).expect("standard normal is always valid");
+        let p_value = 2.0 * normal.cdf(-z.abs());
+        let effect_size = 1.0 - (2.0 * u_a) / (n_a * n_b);
+
+        StatisticalTest {
+            metric: metric.to_string(),
+            test_type: "mann-whitney-u".to_string(),
+            statistic: u_a,
+            p_value,
+            effect_size,
+            df: None,
+            significant: p_value < alpha,
+            adjusted_p_value: p_value,
+            adjusted_significant: p_value < alpha,
+        }
+    }
+
+    /// A well-defined `StatisticalTest` for Mann-Whitney inputs too
+    /// degenerate to test (empty samples, or every value tied across both
+    /// groups so the rank-sum spread is zero): no effect detected if the
+    /// rank sums also match, otherwise a maximally significant one.
+    fn degenerate_mann_whitney(metric: &str, rank_sum_diff: f64) -> StatisticalTest {
+        let differs = rank_sum_diff != 0.0;
+
+        let p_value = if differs { 0.0 } else { 1.0 };
+
+        StatisticalTest {
+            metric: metric.to_string(),
+            test_type: "mann-whitney-u".to_string(),
+            statistic: 0.0,
+            p_value,
+            effect_size: 0.0,
+            df: None,
+            significant: differs,
+            adjusted_p_value: p_value,
+            adjusted_significant: differs,
+        }
+    }
+}
+
+/// Bonferroni-corrects a family of p-values: multiplies each by the number
+/// of comparisons, capped at 1.0. Simple and conservative relative to
+/// `holm_correction`, since it doesn't take the other p-values' relative
+/// ordering into account.
+fn bonferroni_correction(p_values: &[f64]) -> Vec<f64> {
+    let n = p_values.len() as f64;
+    p_values.iter().map(|&p| (p * n).min(1.0)).collect()
+}
+
+/// Holm-Bonferroni step-down correction: sorts `p_values` ascending and
+/// compares the k-th smallest (1-indexed) against `alpha / (n - k + 1)`,
+/// which is equivalent to scaling it by `n - k + 1`. Adjusted p-values are
+/// forced to be non-decreasing in rank order (each is the running maximum of
+/// itself and every smaller-ranked adjusted p-value) so that, as required
+/// for the step-down procedure to be well-defined, rejecting a hypothesis at
+/// a given rank never yields a smaller adjusted p-value than one rejected at
+/// an earlier rank. Uniformly more powerful than `bonferroni_correction`.
+fn holm_correction(p_values: &[f64]) -> Vec<f64> {
+    let n = p_values.len();
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&a, &b| p_values[a].total_cmp(&p_values[b]));
+
+    let mut adjusted = vec![0.0; n];
+    let mut running_max = 0.0_f64;
+    for (rank, &original_index) in order.iter().enumerate() {
+        let scale = (n - rank) as f64;
+        running_max = running_max.max((p_values[original_index] * scale).min(1.0));
+        adjusted[original_index] = running_max;
+    }
+
+    adjusted
+}
+
+/// Ranks the pooled values from `values_a` followed by `values_b` (1-based,
+/// ties broken by averaging), as required by the Mann-Whitney U test. The
+/// returned `Vec` has `values_a.len() + values_b.len()` entries in that same
+/// order, so `ranks[..values_a.len()]` gives `values_a`'s ranks.
+fn rank_pooled_values(values_a: &[f64], values_b: &[f64]) -> Vec<f64> {
+    let pooled: Vec<f64> = values_a.iter().chain(values_b.iter()).copied().collect();
+
+    let mut order: Vec<usize> = (0..pooled.len()).collect();
+    order.sort_by(|&i, &j| pooled[i].total_cmp(&pooled[j]));
+
+    let mut ranks = vec![0.0; pooled.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i;
+        while j + 1 < order.len() && pooled[order[j + 1]] == pooled[order[i]] {
+            j += 1;
+        }
+        // Ties from position i..=j share the average of ranks (i+1)..=(j+1).
+        let average_rank = ((i + 1) + (j + 1)) as f64 / 2.0;
+        for &index in &order[i..=j] {
+            ranks[index] = average_rank;
+        }
+        i = j + 1;
+    }
+
+    ranks
+}
+
+/// Maximum number of times a run's raw values are duplicated when
+/// propagating a test set's weight into the raw-value pool used for
+/// statistical tests. Metrics can be averaged with a continuous weight
+/// directly, but biasing a t-test's sample this way only works by
+/// duplicating samples, so the ratio is capped to keep one huge test set
+/// from swamping the effective sample size.
+const MAX_RAW_VALUE_REPEATS: u32 = 5;
+
+/// How many times to repeat a test set's raw values, scaled so the heaviest
+/// test set (`max_weight`) repeats `MAX_RAW_VALUE_REPEATS` times and lighter
+/// ones repeat proportionally less, with a floor of 1 so every completed
+/// test set still contributes to the statistical tests.
+fn weighted_repeat_count(weight: f64, max_weight: f64) -> u32 {
+    ((weight / max_weight) * MAX_RAW_VALUE_REPEATS as f64)
+        .round()
+        .max(1.0) as u32
+}
+
+/// Normalizes `value` to `[0, 1]` against the range of `values` (which
+/// should include `value` itself), flipping the direction when lower is
+/// better. Shared by `ComparisonResults::normalize_metric` and the
+/// per-iteration normalization in `bootstrap_composite_scores`, which both
+/// normalize a value against a differently-sourced pool of comparison
+/// values. Returns `0.0` for an empty pool and `0.5` when every value in
+/// the pool is equal (nothing to distinguish `value` from).
+fn normalize_against(values: &[f64], value: f64, higher_is_better: bool) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    if (max - min).abs() < 1e-10 {
+        return 0.5; // All values equal
+    }
+
+    let normalized = (value - min) / (max - min);
+
+    if higher_is_better {
+        normalized
+    } else {
+        1.0 - normalized
+    }
+}
+
+/// The `p`-th percentile (`0.0..=1.0`) of `sorted`, which must already be
+/// sorted ascending. Uses nearest-rank interpolation, which is adequate for
+/// the bootstrap confidence intervals in `bootstrap_winner_confidence` -
+/// this isn't meant to be a general-purpose statistics utility.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+
+    let index = (p * (sorted.len() - 1) as f64).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+/// Renders `field` as a CSV field, quoting (and escaping embedded quotes)
+/// when it contains a comma, quote, or newline, per RFC 4180.
+fn csv_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn validate_model_count(model_paths: &[PathBuf]) -> Result<()> {
+    if model_paths.len() < 2 {
+        anyhow::bail!("Need at least 2 models to compare");
+    }
+
+    if model_paths.len() > 5 {
+        anyhow::bail!("Maximum 5 models supported");
+    }
+
+    Ok(())
+}
+
+/// The model name `evaluate_model` derives from a path, so checkpoints can
+/// be keyed and looked up the same way.
+fn model_name(model_path: &Path) -> String {
+    model_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+/// On-disk checkpoint of `ModelResult`s completed so far during a long
+/// `compare_models_with_checkpoint` run, so a crash partway through doesn't
+/// lose everything already evaluated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Checkpoint {
+    criteria: Vec<Criterion>,
+    num_runs: usize,
+    completed: Vec<ModelResult>,
+}
+
+impl Checkpoint {
+    fn path(dir: &Path) -> PathBuf {
+        dir.join("checkpoint.json")
+    }
+
+    fn load(dir: &Path) -> Result<Option<Self>> {
+        let path = Self::path(dir);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("reading checkpoint at {}", path.display()))?;
+        let checkpoint: Self = serde_json::from_str(&contents)
+            .with_context(|| format!("parsing checkpoint at {}", path.display()))?;
+
+        Ok(Some(checkpoint))
+    }
+
+    /// Append `result` to the checkpoint under `dir`, creating it (stamped
+    /// with `config`'s criteria and `num_runs`) if it doesn't exist yet.
+    fn append(dir: &Path, config: &ComparisonConfig, result: &ModelResult) -> Result<()> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("creating checkpoint directory {}", dir.display()))?;
+
+        let mut checkpoint = Self::load(dir)?.unwrap_or_else(|| Self {
+            criteria: config.criteria.clone(),
+            num_runs: config.num_runs,
+            completed: Vec::new(),
+        });
+
+        checkpoint.validate_compatible(config)?;
+        checkpoint
+            .completed
+            .retain(|existing| existing.model_name != result.model_name);
+        checkpoint.completed.push(result.clone());
+
+        let json = serde_json::to_string_pretty(&checkpoint)?;
+        std::fs::write(Self::path(dir), json)
+            .with_context(|| format!("writing checkpoint at {}", Self::path(dir).display()))?;
+
+        Ok(())
+    }
+
+    /// Error if this checkpoint was written under different criteria or
+    /// `num_runs` than `config` — its results aren't comparable to results
+    /// from the current run.
+    fn validate_compatible(&self, config: &ComparisonConfig) -> Result<()> {
+        if self.num_runs != config.num_runs {
+            anyhow::bail!(
+                "checkpoint has num_runs={} but current config has num_runs={}",
+                self.num_runs,
+                config.num_runs
+            );
+        }
+
+        if self.criteria.len() != config.criteria.len()
+            || self
+                .criteria
+                .iter()
+                .zip(&config.criteria)
+                .any(|(a, b)| a.name != b.name || a.higher_is_better != b.higher_is_better)
+        {
+            anyhow::bail!("checkpoint was written with different criteria than the current config");
+        }
+
+        Ok(())
+    }
 }
 
-// Re-export chrono for timestamp generation
-use chrono;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded_config(seed: u64) -> ComparisonConfig {
+        ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            num_runs: 2,
+            seed: Some(seed),
+            ..ComparisonConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seeded_comparison_is_reproducible() {
+        let model_paths = vec![PathBuf::from("model_a.json"), PathBuf::from("model_b.json")];
+
+        let comparator_one = ModelComparator::new(seeded_config(42));
+        let mut results_one = comparator_one.compare_models(&model_paths).await.unwrap();
+
+        let comparator_two = ModelComparator::new(seeded_config(42));
+        let mut results_two = comparator_two.compare_models(&model_paths).await.unwrap();
+
+        // Only the timestamp and wall-clock execution time are expected to
+        // differ between runs; everything derived from the RNG must match.
+        results_one.timestamp = String::new();
+        results_two.timestamp = String::new();
+        for result in results_one.model_results.iter_mut().chain(&mut results_two.model_results) {
+            result.execution_time = 0.0;
+        }
+
+        // Compare as `serde_json::Value` rather than raw strings: its maps
+        // compare by content, so HashMap iteration order can't cause a
+        // spurious mismatch.
+        assert_eq!(
+            serde_json::to_value(&results_one).unwrap(),
+            serde_json::to_value(&results_two).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_different_seeds_produce_different_results() {
+        let model_paths = vec![PathBuf::from("model_a.json"), PathBuf::from("model_b.json")];
+
+        let comparator_one = ModelComparator::new(seeded_config(1));
+        let results_one = comparator_one.compare_models(&model_paths).await.unwrap();
+
+        let comparator_two = ModelComparator::new(seeded_config(2));
+        let results_two = comparator_two.compare_models(&model_paths).await.unwrap();
+
+        assert_ne!(
+            results_one.model_results[0].metrics.get("accuracy"),
+            results_two.model_results[0].metrics.get("accuracy")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parallel_evaluation_matches_sequential_results_and_order() {
+        let model_paths = vec![
+            PathBuf::from("model_a.json"),
+            PathBuf::from("model_b.json"),
+            PathBuf::from("model_c.json"),
+            PathBuf::from("model_d.json"),
+        ];
+
+        let sequential_config = ComparisonConfig {
+            max_parallel_models: 1,
+            ..seeded_config(99)
+        };
+        let sequential = ModelComparator::new(sequential_config)
+            .compare_models(&model_paths)
+            .await
+            .unwrap();
+
+        let parallel_config = ComparisonConfig {
+            max_parallel_models: 4,
+            ..seeded_config(99)
+        };
+        let parallel = ModelComparator::new(parallel_config)
+            .compare_models(&model_paths)
+            .await
+            .unwrap();
+
+        let sequential_names: Vec<_> = sequential.model_results.iter().map(|r| r.model_name.clone()).collect();
+        let parallel_names: Vec<_> = parallel.model_results.iter().map(|r| r.model_name.clone()).collect();
+        assert_eq!(
+            sequential_names,
+            vec!["model_a".to_string(), "model_b".to_string(), "model_c".to_string(), "model_d".to_string()],
+            "model_results must stay in model_paths order regardless of how concurrently models were evaluated"
+        );
+        assert_eq!(parallel_names, sequential_names);
+
+        // Same seed, same per-model child RNGs regardless of concurrency,
+        // so every metric (not just ordering) must match exactly.
+        for (seq_result, par_result) in sequential.model_results.iter().zip(&parallel.model_results) {
+            assert_eq!(seq_result.metrics, par_result.metrics);
+            assert_eq!(seq_result.raw_values, par_result.raw_values);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parallel_evaluation_is_faster_than_sequential() {
+        let model_paths = vec![
+            PathBuf::from("model_a.json"),
+            PathBuf::from("model_b.json"),
+            PathBuf::from("model_c.json"),
+            PathBuf::from("model_d.json"),
+        ];
+
+        let sequential_config = ComparisonConfig {
+            max_parallel_models: 1,
+            ..seeded_config(7)
+        };
+        let sequential_start = std::time::Instant::now();
+        ModelComparator::new(sequential_config)
+            .compare_models(&model_paths)
+            .await
+            .unwrap();
+        let sequential_elapsed = sequential_start.elapsed();
+
+        let parallel_config = ComparisonConfig {
+            max_parallel_models: model_paths.len(),
+            ..seeded_config(7)
+        };
+        let parallel_start = std::time::Instant::now();
+        ModelComparator::new(parallel_config)
+            .compare_models(&model_paths)
+            .await
+            .unwrap();
+        let parallel_elapsed = parallel_start.elapsed();
+
+        assert!(
+            parallel_elapsed < sequential_elapsed,
+            "evaluating {} independent models concurrently (max_parallel_models={}) took {parallel_elapsed:?}, \
+             which should be faster than evaluating them one at a time ({sequential_elapsed:?})",
+            model_paths.len(),
+            model_paths.len(),
+        );
+    }
+
+    fn model_result(name: &str, metrics: &[(&str, f64)]) -> ModelResult {
+        ModelResult {
+            model_name: name.to_string(),
+            model_path: PathBuf::from(format!("{name}.json")),
+            metrics: metrics.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            raw_values: HashMap::new(),
+            per_test_set: HashMap::new(),
+            execution_time: 0.0,
+            truncated: false,
+        }
+    }
+
+    fn model_result_with_raw(name: &str, raw_values: &[(&str, &[f64])]) -> ModelResult {
+        let raw_values: HashMap<String, Vec<f64>> = raw_values
+            .iter()
+            .map(|(k, values)| (k.to_string(), values.to_vec()))
+            .collect();
+        let metrics = raw_values
+            .iter()
+            .map(|(k, values)| (k.clone(), values.iter().sum::<f64>() / values.len() as f64))
+            .collect();
+
+        ModelResult {
+            model_name: name.to_string(),
+            model_path: PathBuf::from(format!("{name}.json")),
+            metrics,
+            raw_values,
+            per_test_set: HashMap::new(),
+            execution_time: 0.0,
+            truncated: false,
+        }
+    }
+
+    fn pareto_config() -> ComparisonConfig {
+        ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![
+                Criterion::new("accuracy", 1.0, true),
+                Criterion::new("latency_ms", 1.0, false),
+            ],
+            ..ComparisonConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_pareto_frontier_excludes_dominated_model() {
+        let results = ComparisonResults {
+            config: pareto_config(),
+            model_results: vec![
+                model_result("dominant", &[("accuracy", 0.9), ("latency_ms", 100.0)]),
+                model_result("dominated", &[("accuracy", 0.8), ("latency_ms", 150.0)]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        assert_eq!(results.pareto_frontier(), vec!["dominant".to_string()]);
+    }
+
+    #[test]
+    fn test_pareto_frontier_keeps_mutually_non_dominated_models() {
+        let results = ComparisonResults {
+            config: pareto_config(),
+            model_results: vec![
+                model_result("accurate", &[("accuracy", 0.95), ("latency_ms", 500.0)]),
+                model_result("fast", &[("accuracy", 0.80), ("latency_ms", 50.0)]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let mut frontier = results.pareto_frontier();
+        frontier.sort();
+        assert_eq!(frontier, vec!["accurate".to_string(), "fast".to_string()]);
+    }
+
+    #[test]
+    fn test_determine_winner_flags_non_pareto_optimal_winner() {
+        // latency_ms has zero weight, so it never affects calculate_score,
+        // but pareto_frontier still considers it. Both models tie on the
+        // weighted score (accuracy is equal), so the winner is whichever
+        // comes first in model_results — even though "faster" dominates it.
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![
+                Criterion::new("accuracy", 1.0, true),
+                Criterion::new("latency_ms", 0.0, false),
+            ],
+            ..ComparisonConfig::default()
+        };
+        let results = ComparisonResults {
+            config,
+            model_results: vec![
+                model_result("first_but_slower", &[("accuracy", 0.80), ("latency_ms", 900.0)]),
+                model_result("faster", &[("accuracy", 0.80), ("latency_ms", 100.0)]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let winner = results.determine_winner().unwrap();
+        assert_eq!(winner.model_name, "first_but_slower");
+        assert!(!winner.pareto_optimal);
+        assert!(winner.recommendation.contains("not Pareto-optimal"));
+        assert_eq!(results.pareto_frontier(), vec!["faster".to_string()]);
+    }
+
+    #[test]
+    fn test_calculate_score_imputes_neutral_value_for_missing_metric() {
+        // "missing_latency" has no `latency_p95` entry at all; its score
+        // for that criterion should be a neutral 0.5 rather than the
+        // criterion being dropped from the weighted average.
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            require_significance: false,
+            criteria: vec![
+                Criterion::new("accuracy", 0.5, true),
+                Criterion::new("latency_p95", 0.5, false),
+            ],
+            ..ComparisonConfig::default()
+        };
+        let results = ComparisonResults {
+            config,
+            model_results: vec![
+                model_result("missing_latency", &[("accuracy", 1.0)]),
+                model_result("has_latency", &[("accuracy", 0.0), ("latency_p95", 1.0)]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let winner = results.determine_winner().unwrap();
+        assert_eq!(winner.model_name, "missing_latency");
+        assert_eq!(winner.imputed_criteria, vec!["latency_p95".to_string()]);
+        assert!((winner.total_score - 0.75).abs() < 1e-9);
+        assert!(winner.recommendation.contains("latency_p95"));
+
+        // Running it again must give exactly the same imputation - the
+        // behavior is deterministic, not e.g. dependent on HashMap iteration
+        // order.
+        let winner_again = results.determine_winner().unwrap();
+        assert_eq!(winner.imputed_criteria, winner_again.imputed_criteria);
+        assert_eq!(winner.total_score, winner_again.total_score);
+    }
+
+    fn bootstrap_config(seed: u64) -> ComparisonConfig {
+        ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![Criterion::new("accuracy", 1.0, true)],
+            require_significance: false,
+            num_runs: 5,
+            seed: Some(seed),
+            bootstrap_iterations: 500,
+            ..ComparisonConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_win_probability_high_for_clearly_separated_models() {
+        let results = ComparisonResults {
+            config: bootstrap_config(1),
+            model_results: vec![
+                model_result_with_raw("clear_leader", &[("accuracy", &[0.90, 0.91, 0.89, 0.92, 0.90])]),
+                model_result_with_raw("clear_laggard", &[("accuracy", &[0.50, 0.51, 0.49, 0.52, 0.50])]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let winner = results.determine_winner().unwrap();
+        assert_eq!(winner.model_name, "clear_leader");
+        assert!(
+            winner.win_probability > 0.9,
+            "expected a clearly-separated winner to win almost every resample, got {}",
+            winner.win_probability
+        );
+
+        let (low, high) = winner.score_confidence_intervals["clear_leader"];
+        assert!(low <= winner.total_score && winner.total_score <= high);
+    }
+
+    #[test]
+    fn test_bootstrap_win_probability_moves_toward_uncertain_for_overlapping_models() {
+        let separated = ComparisonResults {
+            config: bootstrap_config(2),
+            model_results: vec![
+                model_result_with_raw("clear_leader", &[("accuracy", &[0.90, 0.91, 0.89, 0.92, 0.90])]),
+                model_result_with_raw("clear_laggard", &[("accuracy", &[0.50, 0.51, 0.49, 0.52, 0.50])]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+        let overlapping = ComparisonResults {
+            config: bootstrap_config(2),
+            model_results: vec![
+                model_result_with_raw("model_a", &[("accuracy", &[0.80, 0.82, 0.78, 0.81, 0.83])]),
+                model_result_with_raw("model_b", &[("accuracy", &[0.79, 0.81, 0.80, 0.78, 0.82])]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let separated_probability = separated.determine_winner().unwrap().win_probability;
+        let overlapping_probability = overlapping.determine_winner().unwrap().win_probability;
+
+        assert!(
+            separated_probability > overlapping_probability,
+            "separated models ({separated_probability}) should have a higher win probability \
+             than overlapping ones ({overlapping_probability})"
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_disabled_collapses_to_point_estimate() {
+        let config = ComparisonConfig {
+            bootstrap_iterations: 0,
+            ..bootstrap_config(1)
+        };
+        let results = ComparisonResults {
+            config,
+            model_results: vec![
+                model_result_with_raw("a", &[("accuracy", &[0.9, 0.8])]),
+                model_result_with_raw("b", &[("accuracy", &[0.5, 0.4])]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let winner = results.determine_winner().unwrap();
+        assert_eq!(winner.win_probability, 1.0);
+        let (low, high) = winner.score_confidence_intervals[&winner.model_name];
+        assert_eq!(low, high);
+        assert_eq!(low, winner.total_score);
+    }
+
+    #[test]
+    fn test_sanity_check_flags_latency_mislabeled_as_higher_is_better() {
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![
+                Criterion::new("accuracy", 0.5, true),
+                Criterion::new("latency_p95", 0.5, true), // misconfigured: should be false
+            ],
+            ..ComparisonConfig::default()
+        };
+        let results = ComparisonResults {
+            config,
+            model_results: vec![model_result("m1", &[("accuracy", 0.9), ("latency_p95", 1.0)])],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let warnings = results.sanity_check();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("latency_p95"));
+        assert!(warnings[0].contains("higher_is_better=true"));
+    }
+
+    #[test]
+    fn test_sanity_check_allows_correctly_configured_criteria() {
+        let results = ComparisonResults {
+            config: ComparisonConfig::default(), // latency_p95/error_rate/token_usage all lower_is_better
+            model_results: vec![model_result("m1", &[("accuracy", 0.9)])],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        assert!(results.sanity_check().is_empty());
+    }
+
+    #[test]
+    fn test_sanity_check_ignores_metrics_without_a_suspicious_name() {
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![Criterion::new("accuracy", 1.0, false)], // unusual but not name-flagged
+            ..ComparisonConfig::default()
+        };
+        let results = ComparisonResults {
+            config,
+            model_results: vec![model_result("m1", &[("accuracy", 0.9)])],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        assert!(results.sanity_check().is_empty());
+    }
+
+    #[test]
+    fn test_to_ascii_table_appends_configuration_warnings() {
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![Criterion::new("cost_usd", 1.0, true)], // misconfigured
+            ..ComparisonConfig::default()
+        };
+        let results = ComparisonResults {
+            config,
+            model_results: vec![model_result("m1", &[("cost_usd", 1.0)])],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let table = results.to_ascii_table();
+        assert!(table.contains("Configuration warnings:"));
+        assert!(table.contains("cost_usd"));
+    }
+
+    fn winner_info(model_name: &str) -> WinnerInfo {
+        WinnerInfo {
+            model_name: model_name.to_string(),
+            total_score: 0.9,
+            criterion_scores: HashMap::new(),
+            significant_improvements: Vec::new(),
+            recommendation: String::new(),
+            pareto_optimal: true,
+            imputed_criteria: Vec::new(),
+            score_confidence_intervals: HashMap::new(),
+            win_probability: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_bolds_winner() {
+        let results = ComparisonResults {
+            config: ComparisonConfig::default(),
+            model_results: vec![
+                model_result("model_a", &[("accuracy", 0.9)]),
+                model_result("model_b", &[("accuracy", 0.8)]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: Some(winner_info("model_a")),
+            timestamp: String::new(),
+        };
+
+        let markdown = results.to_markdown();
+        assert!(markdown.contains("| **model_a** |"));
+        assert!(markdown.contains("| model_b |"));
+        assert!(markdown.starts_with("| Model | Accuracy |"));
+    }
+
+    #[test]
+    fn test_export_csv_round_trips_expected_row_count() {
+        let temp_dir = std::env::temp_dir().join(format!("test_export_csv_{}", std::process::id()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let path = temp_dir.join("results.csv");
+
+        let results = ComparisonResults {
+            config: ComparisonConfig::default(),
+            model_results: vec![
+                model_result("model_a", &[("accuracy", 0.9), ("latency_p95", 1.2)]),
+                model_result("model_b", &[("accuracy", 0.8), ("latency_p95", 1.5)]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: Some(winner_info("model_a")),
+            timestamp: String::new(),
+        };
+
+        results.export_csv(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+
+        assert_eq!(lines.len(), 3, "header + one row per model");
+        assert_eq!(lines[0], "model,accuracy,latency_p95,score");
+        assert!(lines[1].starts_with("model_a,"));
+        assert!(lines[2].starts_with("model_b,"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_csv_field_quotes_values_containing_commas() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("has,comma"), "\"has,comma\"");
+        assert_eq!(csv_field("has\"quote"), "\"has\"\"quote\"");
+    }
+
+    #[test]
+    fn test_perform_t_test_identical_samples_is_not_significant_not_nan() {
+        let comparator = ModelComparator::new(seeded_config(1));
+        let values = vec![0.5, 0.5, 0.5, 0.5];
+
+        let test = comparator
+            .perform_t_test("accuracy", &values, &values, 0.05)
+            .unwrap();
+
+        assert!(!test.significant);
+        assert!(!test.statistic.is_nan());
+        assert!(!test.p_value.is_nan());
+        assert!(!test.effect_size.is_nan());
+        assert_eq!(test.statistic, 0.0);
+        assert_eq!(test.effect_size, 0.0);
+    }
+
+    #[test]
+    fn test_perform_t_test_zero_variance_differing_means_is_significant_not_inf() {
+        let comparator = ModelComparator::new(seeded_config(1));
+        let values_a = vec![1.0, 1.0, 1.0];
+        let values_b = vec![0.0, 0.0, 0.0];
+
+        let test = comparator
+            .perform_t_test("accuracy", &values_a, &values_b, 0.05)
+            .unwrap();
+
+        assert!(test.significant);
+        assert!(!test.statistic.is_nan());
+        assert!(!test.p_value.is_nan());
+        assert_eq!(test.p_value, 0.0);
+    }
+
+    #[test]
+    fn test_perform_t_test_single_element_samples_does_not_panic() {
+        let comparator = ModelComparator::new(seeded_config(1));
+        let test = comparator
+            .perform_t_test("accuracy", &[0.9], &[0.4], 0.05)
+            .unwrap();
+
+        assert!(!test.statistic.is_nan());
+        assert!(!test.p_value.is_nan());
+    }
+
+    #[test]
+    fn test_perform_t_test_empty_samples_does_not_panic() {
+        let comparator = ModelComparator::new(seeded_config(1));
+        let test = comparator.perform_t_test("accuracy", &[], &[], 0.05).unwrap();
+
+        assert!(!test.significant);
+        assert_eq!(test.statistic, 0.0);
+    }
+
+    #[test]
+    fn test_perform_mann_whitney_no_ties_matches_hand_computed_values() {
+        // Every value in `a` is below every value in `b`: U for `a` is 0,
+        // the minimum possible, so the effect size is the maximal 1.0.
+        // z = -1.9639..., p = 0.04953... (normal-approximation, hand-computed).
+        let comparator = ModelComparator::new(seeded_config(1));
+        let values_a = vec![1.0, 2.0, 3.0];
+        let values_b = vec![4.0, 5.0, 6.0];
+
+        let test = comparator.perform_mann_whitney("latency_p95", &values_a, &values_b, 0.05);
+
+        assert_eq!(test.test_type, "mann-whitney-u");
+        assert_eq!(test.statistic, 0.0);
+        assert!((test.p_value - 0.049535).abs() < 1e-4);
+        assert_eq!(test.effect_size, 1.0);
+        assert!(test.significant);
+    }
+
+    #[test]
+    fn test_perform_mann_whitney_averages_tied_ranks() {
+        // Pooled+sorted: [1, 2, 2, 2, 3, 4] -> the three tied 2s each get
+        // the average of ranks 2,3,4 (rank 3). U_a = 1, z = -1.5275...,
+        // p = 0.126630... (hand-computed).
+        let comparator = ModelComparator::new(seeded_config(1));
+        let values_a = vec![1.0, 2.0, 2.0];
+        let values_b = vec![2.0, 3.0, 4.0];
+
+        let test = comparator.perform_mann_whitney("latency_p95", &values_a, &values_b, 0.05);
+
+        assert_eq!(test.statistic, 1.0);
+        assert!((test.p_value - 0.126630).abs() < 1e-4);
+        assert!((test.effect_size - 0.777778).abs() < 1e-4);
+        assert!(!test.significant);
+    }
+
+    #[test]
+    fn test_perform_mann_whitney_empty_samples_does_not_panic() {
+        let comparator = ModelComparator::new(seeded_config(1));
+        let test = comparator.perform_mann_whitney("accuracy", &[], &[], 0.05);
+
+        assert!(!test.significant);
+        assert_eq!(test.statistic, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_pairwise_tests_use_configured_test_method() {
+        let config = ComparisonConfig {
+            test_method: TestMethod::MannWhitneyU,
+            require_significance: false,
+            ..seeded_config(1)
+        };
+        let comparator = ModelComparator::new(config);
+
+        let results = comparator
+            .compare_models(&[PathBuf::from("model_a.json"), PathBuf::from("model_b.json")])
+            .await
+            .unwrap();
+
+        let pairwise = &results.statistical_tests[0];
+        for test in pairwise.tests.values() {
+            assert_eq!(test.test_type, "mann-whitney-u");
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_weights_summing_to_one() {
+        let config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 0.6, true), Criterion::new("latency_p95", 0.4, false)],
+            ..ComparisonConfig::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_weights_not_summing_to_one() {
+        let config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 0.6, true), Criterion::new("latency_p95", 0.6, false)],
+            ..ComparisonConfig::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("sum to"));
+    }
+
+    #[test]
+    fn test_validate_rejects_negative_weight() {
+        let config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 1.2, true), Criterion::new("latency_p95", -0.2, false)],
+            ..ComparisonConfig::default()
+        };
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("negative"));
+    }
+
+    #[test]
+    fn test_normalize_weights_scales_to_sum_one() {
+        let mut config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 2.0, true), Criterion::new("latency_p95", 6.0, false)],
+            ..ComparisonConfig::default()
+        };
+        config.normalize_weights();
+
+        let total: f64 = config.criteria.iter().map(|c| c.weight).sum();
+        assert!((total - 1.0).abs() < WEIGHT_SUM_EPSILON);
+        assert!((config.criteria[0].weight - 0.25).abs() < 1e-9);
+        assert!((config.criteria[1].weight - 0.75).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_compare_models_rejects_invalid_weights() {
+        let config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 0.5, true)],
+            ..seeded_config(1)
+        };
+        let comparator = ModelComparator::new(config);
+
+        let err = comparator
+            .compare_models(&[PathBuf::from("model_a.json"), PathBuf::from("model_b.json")])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("sum to"));
+    }
+
+    #[test]
+    fn test_determine_winner_handles_nan_metric_without_panicking() {
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![Criterion::new("accuracy", 1.0, true)],
+            ..ComparisonConfig::default()
+        };
+        let results = ComparisonResults {
+            config,
+            model_results: vec![
+                model_result("normal", &[("accuracy", 0.9)]),
+                model_result("nan", &[("accuracy", f64::NAN)]),
+            ],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        // Must not panic on a NaN score; which model comes out on top is
+        // unspecified (total_cmp's NaN ordering is arbitrary but total).
+        let _ = results.determine_winner().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_written_after_each_model() {
+        let temp_dir = std::env::temp_dir().join(format!("test_checkpoint_write_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        let model_paths = vec![PathBuf::from("model_a.json"), PathBuf::from("model_b.json")];
+        let comparator = ModelComparator::new(seeded_config(1));
+        comparator
+            .compare_models_with_checkpoint(&model_paths, &temp_dir)
+            .await
+            .unwrap();
+
+        let checkpoint = Checkpoint::load(&temp_dir).unwrap().unwrap();
+        let mut names: Vec<_> = checkpoint.completed.iter().map(|r| r.model_name.clone()).collect();
+        names.sort();
+        assert_eq!(names, vec!["model_a".to_string(), "model_b".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_checkpoint_only_evaluates_missing_models() {
+        let temp_dir = std::env::temp_dir().join(format!("test_checkpoint_resume_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        let model_paths = vec![
+            PathBuf::from("model_a.json"),
+            PathBuf::from("model_b.json"),
+            PathBuf::from("model_c.json"),
+        ];
+        let comparator = ModelComparator::new(seeded_config(7));
+
+        // Simulate a crash after the first two models by checkpointing just
+        // those, then resuming with all three.
+        comparator
+            .compare_models_with_checkpoint(&model_paths[..2], &temp_dir)
+            .await
+            .unwrap();
+
+        let results = comparator
+            .resume_from_checkpoint(&temp_dir, &model_paths)
+            .await
+            .unwrap();
+
+        let mut names: Vec<_> = results.model_results.iter().map(|r| r.model_name.clone()).collect();
+        names.sort();
+        assert_eq!(names, vec!["model_a".to_string(), "model_b".to_string(), "model_c".to_string()]);
+        assert!(results.winner.is_some());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_checkpoint_rejects_incompatible_config() {
+        let temp_dir = std::env::temp_dir().join(format!("test_checkpoint_mismatch_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+
+        let model_paths = vec![PathBuf::from("model_a.json"), PathBuf::from("model_b.json")];
+        let comparator = ModelComparator::new(seeded_config(3));
+        comparator
+            .compare_models_with_checkpoint(&model_paths, &temp_dir)
+            .await
+            .unwrap();
+
+        let mismatched_config = ComparisonConfig {
+            num_runs: seeded_config(3).num_runs + 1,
+            ..seeded_config(3)
+        };
+        let mismatched_comparator = ModelComparator::new(mismatched_config);
+
+        let result = mismatched_comparator
+            .resume_from_checkpoint(&temp_dir, &model_paths)
+            .await;
+        assert!(result.is_err());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_max_total_tokens_truncates_evaluation() {
+        let config = ComparisonConfig {
+            max_total_tokens: Some(0.0),
+            require_significance: false,
+            ..seeded_config(1)
+        };
+        let comparator = ModelComparator::new(config);
+
+        let results = comparator
+            .compare_models(&[PathBuf::from("model_a.json"), PathBuf::from("model_b.json")])
+            .await
+            .unwrap();
+
+        let result = &results.model_results[0];
+        assert!(result.truncated);
+        assert!(result.metrics.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_max_wall_clock_truncates_evaluation() {
+        let config = ComparisonConfig {
+            max_wall_clock: Some(Duration::from_secs(0)),
+            require_significance: false,
+            ..seeded_config(1)
+        };
+        let comparator = ModelComparator::new(config);
+
+        let results = comparator
+            .compare_models(&[PathBuf::from("model_a.json"), PathBuf::from("model_b.json")])
+            .await
+            .unwrap();
+
+        assert!(results.model_results[0].truncated);
+    }
+
+    #[test]
+    fn test_determine_winner_bails_when_truncated_and_significance_required() {
+        let config = ComparisonConfig {
+            require_significance: true,
+            ..pareto_config()
+        };
+        let mut truncated_result = model_result("partial", &[("accuracy", 0.9), ("latency_ms", 100.0)]);
+        truncated_result.truncated = true;
+        let results = ComparisonResults {
+            config,
+            model_results: vec![truncated_result],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        let err = results.determine_winner().unwrap_err();
+        assert!(err.to_string().contains("truncated"));
+    }
+
+    #[test]
+    fn test_determine_winner_allows_truncated_when_significance_not_required() {
+        let config = ComparisonConfig {
+            require_significance: false,
+            ..pareto_config()
+        };
+        let mut truncated_result = model_result("partial", &[("accuracy", 0.9), ("latency_ms", 100.0)]);
+        truncated_result.truncated = true;
+        let results = ComparisonResults {
+            config,
+            model_results: vec![truncated_result],
+            statistical_tests: Vec::new(),
+            winner: None,
+            timestamp: String::new(),
+        };
+
+        assert!(results.determine_winner().is_ok());
+    }
+
+    #[test]
+    fn test_test_set_weight_defaults_to_example_count() {
+        let temp_dir = std::env::temp_dir().join(format!("test_weight_by_size_{}", std::process::id()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let path = temp_dir.join("examples.jsonl");
+        std::fs::write(&path, "{}\n{}\n{}\n").unwrap();
+
+        let comparator = ModelComparator::new(ComparisonConfig::default());
+        assert_eq!(comparator.test_set_weight(&path), 3.0);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_test_set_weight_falls_back_to_one_for_unreadable_file() {
+        let comparator = ModelComparator::new(ComparisonConfig::default());
+        assert_eq!(
+            comparator.test_set_weight(&PathBuf::from("data/does-not-exist.jsonl")),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_test_set_weight_prefers_explicit_config_over_example_count() {
+        let mut weights = HashMap::new();
+        weights.insert(PathBuf::from("data/test.jsonl"), 42.0);
+
+        let config = ComparisonConfig {
+            test_set_weights: Some(weights),
+            ..ComparisonConfig::default()
+        };
+        let comparator = ModelComparator::new(config);
+
+        assert_eq!(
+            comparator.test_set_weight(&PathBuf::from("data/test.jsonl")),
+            42.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_test_set_weights_bias_the_averaged_metrics() {
+        let mut weights = HashMap::new();
+        weights.insert(PathBuf::from("data/big.jsonl"), 100.0);
+        weights.insert(PathBuf::from("data/tiny.jsonl"), 1.0);
+
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/big.jsonl"), PathBuf::from("data/tiny.jsonl")],
+            test_set_weights: Some(weights),
+            require_significance: false,
+            ..seeded_config(5)
+        };
+        let comparator = ModelComparator::new(config);
+
+        let results = comparator
+            .compare_models(&[PathBuf::from("model_a.json"), PathBuf::from("model_b.json")])
+            .await
+            .unwrap();
+
+        let result = &results.model_results[0];
+        let weighted_accuracy = result.metrics["accuracy"];
+        let big_accuracy = result.per_test_set["big"]["accuracy"];
+        let tiny_accuracy = result.per_test_set["tiny"]["accuracy"];
+        let unweighted_average = (big_accuracy + tiny_accuracy) / 2.0;
+
+        // The 100:1 weight should pull the overall average much closer to
+        // the heavily-weighted "big" test set's metric than a flat average
+        // across both test sets would.
+        assert!(
+            (weighted_accuracy - big_accuracy).abs() < (weighted_accuracy - unweighted_average).abs()
+        );
+        assert!((weighted_accuracy - big_accuracy).abs() < (weighted_accuracy - tiny_accuracy).abs());
+    }
+
+    /// A `ModelEvaluator` that returns a fixed metric set per model name,
+    /// so tests can assert `ModelComparator` actually calls the injected
+    /// evaluator rather than its default `MockEvaluator`.
+    struct FixedEvaluator {
+        metrics_by_model: HashMap<String, HashMap<String, f64>>,
+    }
+
+    #[async_trait]
+    impl ModelEvaluator for FixedEvaluator {
+        async fn evaluate(&self, model: &PyObject, _test_set: &Path) -> Result<HashMap<String, f64>> {
+            let model_name: String = Python::with_gil(|py| {
+                let dict: &PyDict = model.downcast(py).unwrap();
+                dict.get_item("model_name").unwrap().unwrap().extract().unwrap()
+            });
+            Ok(self.metrics_by_model.get(&model_name).cloned().unwrap_or_default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_evaluator_uses_injected_evaluator() {
+        let mut metrics_by_model = HashMap::new();
+        metrics_by_model.insert("model_a".to_string(), HashMap::from([("accuracy".to_string(), 0.7)]));
+        metrics_by_model.insert("model_b".to_string(), HashMap::from([("accuracy".to_string(), 0.3)]));
+
+        let config = ComparisonConfig {
+            test_sets: vec![PathBuf::from("data/test.jsonl")],
+            criteria: vec![Criterion::new("accuracy", 1.0, true)],
+            require_significance: false,
+            num_runs: 1,
+            ..ComparisonConfig::default()
+        };
+        let comparator = ModelComparator::with_evaluator(config, Box::new(FixedEvaluator { metrics_by_model }));
+
+        let results = comparator
+            .compare_models(&[PathBuf::from("model_a.json"), PathBuf::from("model_b.json")])
+            .await
+            .unwrap();
+
+        assert_eq!(results.model_results[0].metrics["accuracy"], 0.7);
+        assert_eq!(results.model_results[1].metrics["accuracy"], 0.3);
+    }
+
+    #[tokio::test]
+    async fn test_mock_evaluator_reads_model_name_and_varies_per_call() {
+        let evaluator = MockEvaluator::new(Some(1));
+        let model_a = Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            dict.set_item("model_name", "model_a").unwrap();
+            PyObject::from(dict)
+        });
+
+        let first = evaluator.evaluate(&model_a, Path::new("data/test.jsonl")).await.unwrap();
+        let second = evaluator.evaluate(&model_a, Path::new("data/test.jsonl")).await.unwrap();
+
+        assert_ne!(first["accuracy"], second["accuracy"]);
+    }
+
+    fn model_result_with_raw_values(name: &str, raw: Vec<f64>) -> ModelResult {
+        let mean = raw.iter().sum::<f64>() / raw.len() as f64;
+        ModelResult {
+            model_name: name.to_string(),
+            model_path: PathBuf::from(format!("{name}.json")),
+            metrics: HashMap::from([("accuracy".to_string(), mean)]),
+            raw_values: HashMap::from([("accuracy".to_string(), raw)]),
+            per_test_set: HashMap::new(),
+            execution_time: 0.0,
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn test_bonferroni_correction_matches_hand_computed_values() {
+        let adjusted = bonferroni_correction(&[0.01, 0.02, 0.5]);
+
+        assert!((adjusted[0] - 0.03).abs() < 1e-9);
+        assert!((adjusted[1] - 0.06).abs() < 1e-9);
+        // 0.5 * 3 = 1.5, capped at 1.0
+        assert!((adjusted[2] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_holm_correction_matches_hand_computed_values() {
+        // Sorted ascending this is 0.01, 0.02, 0.03, 0.5 with scales
+        // (n - rank) = 4, 3, 2, 1, giving raw products 0.04, 0.06, 0.06, 0.5;
+        // already non-decreasing, so the running max is a no-op here.
+        let adjusted = holm_correction(&[0.5, 0.01, 0.03, 0.02]);
+
+        assert!((adjusted[0] - 0.5).abs() < 1e-9);
+        assert!((adjusted[1] - 0.04).abs() < 1e-9);
+        assert!((adjusted[2] - 0.06).abs() < 1e-9);
+        assert!((adjusted[3] - 0.06).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_holm_correction_enforces_monotonicity() {
+        // Sorted ascending: 0.01 (scale 3 -> 0.03), 0.02 (scale 2 -> 0.04),
+        // 0.03 (scale 1 -> 0.03). Without the running max, the third-ranked
+        // adjusted p-value (0.03) would be *smaller* than the second-ranked
+        // one (0.04), which isn't a valid step-down result.
+        let adjusted = holm_correction(&[0.01, 0.02, 0.03]);
+
+        assert!((adjusted[0] - 0.03).abs() < 1e-9);
+        assert!((adjusted[1] - 0.04).abs() < 1e-9);
+        // Carried forward from rank 1's 0.04, not rank 2's own 0.03.
+        assert!((adjusted[2] - 0.04).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bonferroni_correction_scales_pairwise_p_values_by_comparison_count() {
+        let config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 1.0, true)],
+            multiple_comparison_correction: MultipleComparisonCorrection::Bonferroni,
+            ..ComparisonConfig::default()
+        };
+        let comparator = ModelComparator::new(config);
+
+        let results = vec![
+            model_result_with_raw_values("model_a", vec![0.9, 0.92, 0.88]),
+            model_result_with_raw_values("model_b", vec![0.5, 0.52, 0.48]),
+            model_result_with_raw_values("model_c", vec![0.6, 0.58, 0.62]),
+        ];
+
+        let pairwise_tests = comparator.perform_pairwise_tests(&results).unwrap();
+        // C(3, 2) pairs, so each criterion's family has 3 comparisons.
+        assert_eq!(pairwise_tests.len(), 3);
+
+        for pair in &pairwise_tests {
+            let test = &pair.tests["accuracy"];
+            let expected = (test.p_value * 3.0).min(1.0);
+            assert!((test.adjusted_p_value - expected).abs() < 1e-9);
+            assert_eq!(test.adjusted_significant, test.adjusted_p_value < comparator.config.alpha);
+        }
+    }
+
+    #[test]
+    fn test_holm_correction_is_never_less_significant_than_bonferroni() {
+        let config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 1.0, true)],
+            multiple_comparison_correction: MultipleComparisonCorrection::Holm,
+            ..ComparisonConfig::default()
+        };
+        let comparator = ModelComparator::new(config);
+
+        let results = vec![
+            model_result_with_raw_values("model_a", vec![0.9, 0.92, 0.88]),
+            model_result_with_raw_values("model_b", vec![0.5, 0.52, 0.48]),
+            model_result_with_raw_values("model_c", vec![0.6, 0.58, 0.62]),
+            model_result_with_raw_values("model_d", vec![0.61, 0.59, 0.6]),
+        ];
+
+        let holm_tests = comparator.perform_pairwise_tests(&results).unwrap();
+        let bonferroni_p_values: Vec<f64> = holm_tests
+            .iter()
+            .map(|pair| pair.tests["accuracy"].p_value)
+            .collect();
+        let bonferroni_adjusted = bonferroni_correction(&bonferroni_p_values);
+
+        for (pair, bonferroni_p) in holm_tests.iter().zip(bonferroni_adjusted) {
+            let holm_p = pair.tests["accuracy"].adjusted_p_value;
+            assert!(holm_p <= bonferroni_p + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_no_correction_leaves_adjusted_p_value_equal_to_raw() {
+        let config = ComparisonConfig {
+            criteria: vec![Criterion::new("accuracy", 1.0, true)],
+            multiple_comparison_correction: MultipleComparisonCorrection::None,
+            ..ComparisonConfig::default()
+        };
+        let comparator = ModelComparator::new(config);
+
+        let results = vec![
+            model_result_with_raw_values("model_a", vec![0.9, 0.92, 0.88]),
+            model_result_with_raw_values("model_b", vec![0.5, 0.52, 0.48]),
+        ];
 
-// Placeholder for random number generation (use rand crate in real implementation)
-mod rand {
-    pub fn random<T>() -> T
-    where
-        T: std::default::Default,
-    {
-        T::default()
+        let pairwise_tests = comparator.perform_pairwise_tests(&results).unwrap();
+        let test = &pairwise_tests[0].tests["accuracy"];
+        assert_eq!(test.adjusted_p_value, test.p_value);
+        assert_eq!(test.adjusted_significant, test.significant);
     }
 }
@@ -27,6 +27,9 @@ struct Args {
     /// Number of runs per model
     num_runs: usize,
 
+    /// Seed for the simulated evaluation's RNG (reproducible runs)
+    seed: Option<u64>,
+
     /// Output format (table, json, html)
     output_format: OutputFormat,
 
@@ -42,6 +45,8 @@ enum OutputFormat {
     Table,
     Json,
     Html,
+    Markdown,
+    Csv,
 }
 
 impl Default for Args {
@@ -58,6 +63,7 @@ impl Default for Args {
             require_significance: true,
             min_effect_size: 0.3,
             num_runs: 3,
+            seed: None,
             output_format: OutputFormat::Table,
             output_path: None,
             verbose: false,
@@ -114,6 +120,12 @@ impl Args {
                         args.num_runs = env_args[i].parse()?;
                     }
                 }
+                "--seed" => {
+                    i += 1;
+                    if i < env_args.len() {
+                        args.seed = Some(env_args[i].parse().context("Invalid seed")?);
+                    }
+                }
                 "--format" => {
                     i += 1;
                     if i < env_args.len() {
@@ -121,6 +133,8 @@ impl Args {
                             "table" => OutputFormat::Table,
                             "json" => OutputFormat::Json,
                             "html" => OutputFormat::Html,
+                            "markdown" => OutputFormat::Markdown,
+                            "csv" => OutputFormat::Csv,
                             _ => anyhow::bail!("Invalid format: {}", env_args[i]),
                         };
                     }
@@ -189,13 +203,18 @@ fn parse_criteria(spec: &str) -> Result<Vec<Criterion>> {
         criteria.push(Criterion::new(name, weight, higher_is_better));
     }
 
-    // Validate weights sum to approximately 1.0
+    // Auto-normalize weights that don't sum to ~1.0, same as
+    // ComparisonConfig::normalize_weights, so a slightly-off --criteria
+    // spec doesn't hard-error out of the CLI.
     let total_weight: f64 = criteria.iter().map(|c| c.weight).sum();
-    if (total_weight - 1.0).abs() > 0.01 {
+    if (total_weight - 1.0).abs() > 0.01 && total_weight != 0.0 {
         eprintln!(
-            "Warning: Criterion weights sum to {:.2}, not 1.0. Weights will be normalized.",
+            "Warning: Criterion weights sum to {:.2}, not 1.0. Normalizing.",
             total_weight
         );
+        for criterion in &mut criteria {
+            criterion.weight /= total_weight;
+        }
     }
 
     Ok(criteria)
@@ -233,8 +252,12 @@ OPTIONS:
     --num-runs <N>
             Number of evaluation runs per model (default: 3)
 
+    --seed <N>
+            Seed the simulated evaluation's RNG for reproducible comparisons
+            (default: seeded from entropy, i.e. non-reproducible)
+
     --format <FORMAT>
-            Output format: table, json, html (default: table)
+            Output format: table, json, html, markdown, csv (default: table)
 
     --output <PATH>
             Output path (stdout if not specified)
@@ -297,6 +320,11 @@ async fn main() -> Result<()> {
         min_effect_size: args.min_effect_size,
         num_runs: args.num_runs,
         alpha: 0.05,
+        seed: args.seed,
+        max_wall_clock: None,
+        max_total_tokens: None,
+        test_set_weights: None,
+        ..ComparisonConfig::default()
     };
 
     // Create comparator
@@ -349,6 +377,14 @@ async fn main() -> Result<()> {
                 println!("HTML report saved to: {:?}", args.output_path.unwrap());
             }
         }
+        OutputFormat::Markdown => {
+            let markdown = results.to_markdown();
+            print_or_save(&markdown, args.output_path.as_ref())?;
+        }
+        OutputFormat::Csv => {
+            let csv = results.to_csv();
+            print_or_save(&csv, args.output_path.as_ref())?;
+        }
     }
 
     // Export detailed results if verbose
@@ -8,17 +8,21 @@ use anyhow::{Context, Result, anyhow};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use semver::Version;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 /// Model lifecycle status
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ModelStatus {
     /// Under active development
     Development,
     /// In staging environment for testing
     Staging,
+    /// Receiving a limited percentage of production traffic
+    Canary { traffic_percent: f64 },
     /// Deployed in production
     Production,
     /// No longer in use
@@ -28,7 +32,7 @@ pub enum ModelStatus {
 impl ModelStatus {
     /// Check if status allows promotion to production
     pub fn can_promote_to_production(&self) -> bool {
-        matches!(self, ModelStatus::Staging)
+        matches!(self, ModelStatus::Staging | ModelStatus::Canary { .. })
     }
 
     /// Check if status allows promotion to staging
@@ -36,11 +40,17 @@ impl ModelStatus {
         matches!(self, ModelStatus::Development)
     }
 
+    /// Check if status allows promotion to canary
+    pub fn can_promote_to_canary(&self) -> bool {
+        matches!(self, ModelStatus::Staging)
+    }
+
     /// Get status as string
     pub fn as_str(&self) -> &str {
         match self {
             ModelStatus::Development => "development",
             ModelStatus::Staging => "staging",
+            ModelStatus::Canary { .. } => "canary",
             ModelStatus::Production => "production",
             ModelStatus::Deprecated => "deprecated",
         }
@@ -94,6 +104,11 @@ pub struct ModelMetadata {
 
     /// Model size in bytes
     pub model_size_bytes: Option<u64>,
+
+    /// SHA-256 checksum of the model file, hex-encoded. `None` for
+    /// metadata written before integrity checking was introduced.
+    #[serde(default)]
+    pub sha256: Option<String>,
 }
 
 impl ModelMetadata {
@@ -123,6 +138,7 @@ impl ModelMetadata {
             git_commit: None,
             training_duration_secs: None,
             model_size_bytes: None,
+            sha256: None,
         }
     }
 
@@ -146,6 +162,64 @@ impl ModelMetadata {
     }
 }
 
+/// Compute the SHA-256 checksum of a file, hex-encoded.
+fn sha256_file(path: &Path) -> Result<String> {
+    let mut file = fs::File::open(path)
+        .with_context(|| format!("Failed to open model file for checksum: {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Recursively diff two hyperparameter JSON values, appending human-readable
+/// lines like `temperature: 0.7 → 0.9` to `details`. Keys present in only
+/// one side are reported as added/removed; nested objects are walked with a
+/// dotted `prefix` so deeply-configured optimizers still produce readable
+/// output.
+fn diff_json_values(prefix: &str, a: &serde_json::Value, b: &serde_json::Value, details: &mut Vec<String>) {
+    use serde_json::Value;
+
+    match (a, b) {
+        (Value::Object(map_a), Value::Object(map_b)) => {
+            let mut keys: Vec<&String> = map_a.keys().chain(map_b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+
+                match (map_a.get(key), map_b.get(key)) {
+                    (Some(va), Some(vb)) => diff_json_values(&path, va, vb, details),
+                    (Some(va), None) => {
+                        details.push(format!("{path}: {va} → (removed)"));
+                    }
+                    (None, Some(vb)) => {
+                        details.push(format!("{path}: (added) → {vb}"));
+                    }
+                    (None, None) => unreachable!("key came from one of the two maps"),
+                }
+            }
+        }
+        _ if a != b => {
+            details.push(format!("{prefix}: {a} → {b}"));
+        }
+        _ => {}
+    }
+}
+
 /// A specific version of a model
 #[derive(Debug, Clone)]
 pub struct ModelVersion {
@@ -175,6 +249,14 @@ pub struct StatusChange {
     pub changed_by: String,
 }
 
+/// On-disk representation of a version's current status and full audit
+/// trail, persisted separately from `ModelMetadata` in `status.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusRecord {
+    pub status: ModelStatus,
+    pub history: Vec<StatusChange>,
+}
+
 impl ModelVersion {
     /// Create a new model version
     pub fn new(
@@ -224,11 +306,38 @@ impl ModelVersion {
         self.path.join("model.pkl")
     }
 
+    /// Get the path to the status/history file
+    pub fn status_path(&self) -> PathBuf {
+        self.path.join("status.json")
+    }
+
     /// Save version metadata to disk
     pub fn save_metadata(&self) -> Result<()> {
         self.metadata.save(&self.metadata_path())
     }
 
+    /// Persist the current status and full status history to `status.json`,
+    /// so promotions/rollbacks survive a registry restart and we can answer
+    /// "who promoted this version, and when" from disk alone.
+    pub fn save_status(&self) -> Result<()> {
+        let record = StatusRecord {
+            status: self.status.clone(),
+            history: self.status_history.clone(),
+        };
+        let json = serde_json::to_string_pretty(&record)
+            .context("Failed to serialize status record")?;
+        fs::write(self.status_path(), json)
+            .context("Failed to write status.json")?;
+        Ok(())
+    }
+
+    /// Load a persisted status/history record from `status.json`, if present.
+    pub fn load_status(status_path: &Path) -> Result<StatusRecord> {
+        let json = fs::read_to_string(status_path)?;
+        let record = serde_json::from_str(&json)?;
+        Ok(record)
+    }
+
     /// Get age of version in days
     pub fn age_days(&self) -> Option<i64> {
         let created: DateTime<Utc> = self.metadata.created_at.parse().ok()?;
@@ -256,6 +365,16 @@ pub enum ComparisonWinner {
     Tie,
 }
 
+/// What to do when `promote_to_production` is asked to promote a version
+/// whose semver is lower than the version currently in production.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DowngradePolicy {
+    /// Allow the promotion, but print a warning.
+    Warn,
+    /// Refuse the promotion outright.
+    Block,
+}
+
 /// Central registry for managing model versions
 #[derive(Debug)]
 pub struct ModelRegistry {
@@ -276,6 +395,12 @@ pub struct RegistryMetadata {
     pub updated_at: String,
     pub total_models: usize,
     pub total_versions: usize,
+
+    /// Named channels (e.g. "stable", "latest") per model, mapping the
+    /// alias to a semver string. `#[serde(default)]` so `registry.json`
+    /// files written before aliases existed still load.
+    #[serde(default)]
+    pub aliases: HashMap<String, HashMap<String, String>>,
 }
 
 impl RegistryMetadata {
@@ -286,6 +411,7 @@ impl RegistryMetadata {
             updated_at: now,
             total_models: 0,
             total_versions: 0,
+            aliases: HashMap::new(),
         }
     }
 
@@ -308,20 +434,42 @@ impl ModelRegistry {
             RegistryMetadata::new()
         };
 
-        Ok(Self {
+        let mut registry = Self {
             base_dir,
             models: HashMap::new(),
             metadata,
-        })
+        };
+        registry.load_from_disk()?;
+
+        Ok(registry)
     }
 
-    /// Register a new model version
+    /// Register a new model version. Unless `force` is set, the new
+    /// version must be greater than every version already registered for
+    /// `model_id`, so a stray older semver can't silently become the
+    /// latest entry in the registry.
     pub fn register_model(
         &mut self,
         model_id: &str,
         version: Version,
-        metadata: ModelMetadata,
+        mut metadata: ModelMetadata,
+        force: bool,
     ) -> Result<PathBuf> {
+        if !force {
+            if let Some(existing) = self.models.get(model_id) {
+                if let Some(max_existing) = existing.iter().map(|v| &v.version).max() {
+                    if &version <= max_existing {
+                        return Err(anyhow!(
+                            "Refusing to register {} v{}: not greater than existing v{} (pass force=true to override)",
+                            model_id,
+                            version,
+                            max_existing
+                        ));
+                    }
+                }
+            }
+        }
+
         // Create version directory
         let model_dir = self.base_dir
             .join(model_id)
@@ -330,6 +478,14 @@ impl ModelRegistry {
         fs::create_dir_all(&model_dir)
             .context("Failed to create model version directory")?;
 
+        // Compute an integrity checksum if the model file is already in
+        // place; older call sites that register metadata before copying
+        // the model file in simply leave `sha256` unset.
+        let model_path = model_dir.join("model.pkl");
+        if model_path.exists() {
+            metadata.sha256 = Some(sha256_file(&model_path)?);
+        }
+
         // Create model version
         let version_entry = ModelVersion::new(
             version.clone(),
@@ -339,6 +495,7 @@ impl ModelRegistry {
 
         // Save metadata
         version_entry.save_metadata()?;
+        version_entry.save_status()?;
 
         // Add to registry
         self.models
@@ -379,11 +536,119 @@ impl ModelRegistry {
 
         version_entry.change_status(ModelStatus::Staging, reason, promoted_by);
         version_entry.save_metadata()?;
+        version_entry.save_status()?;
 
         println!("✓ Promoted {} v{} to staging", model_id, version);
         Ok(())
     }
 
+    /// Route a small percentage of traffic to a staged version before a
+    /// full production promotion. `traffic_percent` must be in `(0, 100]`.
+    pub fn promote_to_canary(
+        &mut self,
+        model_id: &str,
+        version: &Version,
+        traffic_percent: f64,
+        reason: String,
+        promoted_by: String,
+    ) -> Result<()> {
+        if !(traffic_percent > 0.0 && traffic_percent <= 100.0) {
+            return Err(anyhow!(
+                "traffic_percent must be in (0, 100], got {traffic_percent}"
+            ));
+        }
+
+        let versions = self.models.get_mut(model_id)
+            .context("Model not found")?;
+
+        let version_entry = versions.iter_mut()
+            .find(|v| &v.version == version)
+            .context("Version not found")?;
+
+        if !version_entry.status.can_promote_to_canary() {
+            return Err(anyhow!(
+                "Cannot promote from {:?} to canary. Must be in staging first.",
+                version_entry.status
+            ));
+        }
+
+        version_entry.change_status(ModelStatus::Canary { traffic_percent }, reason, promoted_by);
+        version_entry.save_metadata()?;
+        version_entry.save_status()?;
+
+        println!(
+            "✓ Promoted {} v{} to canary ({traffic_percent:.1}% traffic)",
+            model_id, version
+        );
+        Ok(())
+    }
+
+    /// Pick which version should serve a request given a `roll` in
+    /// `[0, 100)`: the canary version if `roll < traffic_percent`,
+    /// otherwise the current production version.
+    pub fn get_serving_version(&self, model_id: &str, roll: f64) -> Option<&ModelVersion> {
+        let versions = self.models.get(model_id)?;
+
+        let canary = versions.iter().find_map(|v| match v.status {
+            ModelStatus::Canary { traffic_percent } if roll < traffic_percent => Some(v),
+            _ => None,
+        });
+
+        canary.or_else(|| versions.iter().find(|v| v.status == ModelStatus::Production))
+    }
+
+    /// Reject a canary that didn't pan out, moving it straight to
+    /// deprecated without ever reaching full production.
+    pub fn reject_canary(
+        &mut self,
+        model_id: &str,
+        version: &Version,
+        reason: String,
+        rejected_by: String,
+    ) -> Result<()> {
+        let versions = self.models.get_mut(model_id)
+            .context("Model not found")?;
+
+        let version_entry = versions.iter_mut()
+            .find(|v| &v.version == version)
+            .context("Version not found")?;
+
+        if !matches!(version_entry.status, ModelStatus::Canary { .. }) {
+            return Err(anyhow!(
+                "Cannot reject {:?}: not a canary",
+                version_entry.status
+            ));
+        }
+
+        version_entry.change_status(ModelStatus::Deprecated, reason, rejected_by);
+        version_entry.save_metadata()?;
+        version_entry.save_status()?;
+
+        println!("✓ Rejected canary {} v{}", model_id, version);
+        Ok(())
+    }
+
+    /// Recompute the SHA-256 of a version's model file and compare it
+    /// against the checksum recorded at registration time.
+    ///
+    /// Versions registered before integrity checksums existed have no
+    /// stored hash; these are treated as unverifiable and return `true`
+    /// so legacy models aren't blocked retroactively.
+    pub fn verify_integrity(&self, model_id: &str, version: &Version) -> Result<bool> {
+        let versions = self.models.get(model_id).context("Model not found")?;
+        let version_entry = versions
+            .iter()
+            .find(|v| &v.version == version)
+            .context("Version not found")?;
+
+        let Some(expected) = &version_entry.metadata.sha256 else {
+            return Ok(true);
+        };
+
+        let actual = sha256_file(&version_entry.model_path())?;
+        Ok(&actual == expected)
+    }
+
     /// Promote a model version to production
     pub fn promote_to_production(
         &mut self,
@@ -391,7 +656,10 @@ impl ModelRegistry {
         version: &Version,
         reason: String,
         promoted_by: String,
+        on_downgrade: DowngradePolicy,
     ) -> Result<()> {
+        let integrity_ok = self.verify_integrity(model_id, version)?;
+
         let versions = self.models.get_mut(model_id)
             .context("Model not found")?;
 
@@ -407,6 +675,36 @@ impl ModelRegistry {
             ));
         }
 
+        if !integrity_ok {
+            return Err(anyhow!(
+                "Refusing to promote {} v{} to production: model file checksum does not match the recorded sha256",
+                model_id,
+                version
+            ));
+        }
+
+        if let Some(current_production) = versions.iter().find(|v| v.status == ModelStatus::Production) {
+            if version < &current_production.version {
+                match on_downgrade {
+                    DowngradePolicy::Warn => {
+                        println!(
+                            "⚠ Promoting {} v{} to production is a downgrade from current v{}",
+                            model_id, version, current_production.version
+                        );
+                    }
+                    DowngradePolicy::Block => {
+                        return Err(anyhow!(
+                            "Refusing to promote {} v{} to production: v{} is lower than the current production v{}",
+                            model_id,
+                            version,
+                            version,
+                            current_production.version
+                        ));
+                    }
+                }
+            }
+        }
+
         // Demote current production version
         let mut demoted = Vec::new();
         for v in versions.iter_mut() {
@@ -417,6 +715,7 @@ impl ModelRegistry {
                     promoted_by.clone(),
                 );
                 v.save_metadata()?;
+                v.save_status()?;
                 demoted.push(v.version.clone());
             }
         }
@@ -428,12 +727,17 @@ impl ModelRegistry {
 
         version_entry.change_status(ModelStatus::Production, reason, promoted_by);
         version_entry.save_metadata()?;
+        version_entry.save_status()?;
 
         if !demoted.is_empty() {
             println!("✓ Deprecated production versions: {:?}", demoted);
         }
         println!("✓ Promoted {} v{} to production", model_id, version);
 
+        // Keep the "stable" channel pointed at whatever is in production,
+        // so clients resolving it never need to know about the bump.
+        self.set_alias(model_id, "stable", version)?;
+
         Ok(())
     }
 
@@ -466,6 +770,7 @@ impl ModelRegistry {
                     rolled_back_by.clone(),
                 );
                 v.save_metadata()?;
+                v.save_status()?;
             }
         }
 
@@ -480,11 +785,64 @@ impl ModelRegistry {
             rolled_back_by,
         );
         target_entry.save_metadata()?;
+        target_entry.save_status()?;
 
         println!("✓ Rolled back {} to v{}", model_id, target_version);
         Ok(())
     }
 
+    /// Delete the on-disk directories of deprecated versions beyond the
+    /// most recent `keep_last` (by creation time), to reclaim storage used
+    /// by stale pickled models. Staging, production, canary, and
+    /// development versions are never pruned; a production deployment is
+    /// always in one of those non-deprecated states, so it is never at
+    /// risk here. Returns the versions that were pruned.
+    pub fn prune_deprecated(&mut self, model_id: &str, keep_last: usize) -> Result<Vec<Version>> {
+        let versions = self.models.get_mut(model_id)
+            .context("Model not found")?;
+
+        let mut deprecated: Vec<usize> = versions
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.status == ModelStatus::Deprecated)
+            .map(|(i, _)| i)
+            .collect();
+
+        // Newest first, so the first `keep_last` are the ones kept.
+        deprecated.sort_by(|&a, &b| {
+            let created_a: DateTime<Utc> = versions[a].metadata.created_at.parse()
+                .unwrap_or(DateTime::<Utc>::MIN_UTC);
+            let created_b: DateTime<Utc> = versions[b].metadata.created_at.parse()
+                .unwrap_or(DateTime::<Utc>::MIN_UTC);
+            created_b.cmp(&created_a)
+        });
+
+        let to_prune: Vec<usize> = deprecated.into_iter().skip(keep_last).collect();
+
+        let mut pruned = Vec::new();
+        for &i in &to_prune {
+            let path = &versions[i].path;
+            if path.exists() {
+                fs::remove_dir_all(path)
+                    .with_context(|| format!("Failed to remove pruned version directory {:?}", path))?;
+            }
+            pruned.push(versions[i].version.clone());
+        }
+
+        let mut idx = 0;
+        versions.retain(|_| {
+            let keep = !to_prune.contains(&idx);
+            idx += 1;
+            keep
+        });
+
+        self.metadata.total_versions = self.metadata.total_versions.saturating_sub(pruned.len());
+        self.metadata.touch();
+        self.save_metadata()?;
+
+        Ok(pruned)
+    }
+
     /// Get the production model version
     pub fn get_production_model(&self, model_id: &str) -> Option<&ModelVersion> {
         self.models.get(model_id)?
@@ -533,6 +891,54 @@ impl ModelRegistry {
         }
     }
 
+    /// Point a named channel (e.g. "stable", "latest") at a specific
+    /// version, so clients can load a model without tracking exact semver
+    /// bumps. Errors if the version doesn't exist.
+    pub fn set_alias(&mut self, model_id: &str, alias: &str, version: &Version) -> Result<()> {
+        let versions = self.models.get(model_id).context("Model not found")?;
+        if !versions.iter().any(|v| &v.version == version) {
+            return Err(anyhow!(
+                "Cannot alias {}/{} to nonexistent version {}",
+                model_id,
+                alias,
+                version
+            ));
+        }
+
+        self.metadata
+            .aliases
+            .entry(model_id.to_string())
+            .or_default()
+            .insert(alias.to_string(), version.to_string());
+        self.metadata.touch();
+        self.save_metadata()?;
+
+        Ok(())
+    }
+
+    /// Resolve a named channel to the model version it currently points at.
+    pub fn resolve_alias(&self, model_id: &str, alias: &str) -> Option<&ModelVersion> {
+        let version_str = self.metadata.aliases.get(model_id)?.get(alias)?;
+        let version = Version::parse(version_str).ok()?;
+        self.models.get(model_id)?.iter().find(|v| v.version == version)
+    }
+
+    /// List all aliases configured for a model, as `(alias, version)` pairs.
+    pub fn list_aliases(&self, model_id: &str) -> Vec<(String, Version)> {
+        let Some(aliases) = self.metadata.aliases.get(model_id) else {
+            return Vec::new();
+        };
+
+        let mut result: Vec<(String, Version)> = aliases
+            .iter()
+            .filter_map(|(alias, version)| {
+                Version::parse(version).ok().map(|v| (alias.clone(), v))
+            })
+            .collect();
+        result.sort_by(|a, b| a.0.cmp(&b.0));
+        result
+    }
+
     /// Compare two versions
     pub fn compare_versions(
         &self,
@@ -596,6 +1002,14 @@ impl ModelRegistry {
             ));
         }
 
+        // Hyperparameter diff (recurses into nested objects)
+        diff_json_values(
+            "",
+            &ver_a.metadata.hyperparameters,
+            &ver_b.metadata.hyperparameters,
+            &mut details,
+        );
+
         // Determine winner
         let winner = if score_diff > 0.01 {
             ComparisonWinner::VersionB
@@ -623,6 +1037,7 @@ impl ModelRegistry {
             total_versions: 0,
             development: 0,
             staging: 0,
+            canary: 0,
             production: 0,
             deprecated: 0,
             avg_versions_per_model: 0.0,
@@ -634,6 +1049,7 @@ impl ModelRegistry {
                 match version.status {
                     ModelStatus::Development => stats.development += 1,
                     ModelStatus::Staging => stats.staging += 1,
+                    ModelStatus::Canary { .. } => stats.canary += 1,
                     ModelStatus::Production => stats.production += 1,
                     ModelStatus::Deprecated => stats.deprecated += 1,
                 }
@@ -656,7 +1072,13 @@ impl ModelRegistry {
         Ok(())
     }
 
-    /// Load all model versions from disk
+    /// Scan `base_dir` for `<model_id>/<version>/metadata.json` entries and
+    /// populate the in-memory `models` map, so a freshly-constructed
+    /// registry rediscovers everything a previous process registered.
+    ///
+    /// Directories that don't parse as a model/version pair (bad semver,
+    /// unreadable metadata) are skipped with a warning rather than
+    /// aborting the whole scan.
     pub fn load_from_disk(&mut self) -> Result<()> {
         let entries = fs::read_dir(&self.base_dir)?;
 
@@ -668,45 +1090,94 @@ impl ModelRegistry {
                 continue;
             }
 
-            let model_id = model_path.file_name()
-                .and_then(|n| n.to_str())
-                .context("Invalid model directory name")?;
-
-            if model_id == "registry.json" {
+            let Some(model_id) = model_path.file_name().and_then(|n| n.to_str()) else {
+                eprintln!("Warning: skipping non-UTF8 model directory: {}", model_path.display());
                 continue;
-            }
+            };
 
-            // Load versions
-            let version_entries = fs::read_dir(&model_path)?;
+            let version_entries = match fs::read_dir(&model_path) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    eprintln!("Warning: skipping unreadable model directory {}: {e}", model_path.display());
+                    continue;
+                }
+            };
 
             for version_entry in version_entries {
-                let version_entry = version_entry?;
+                let version_entry = match version_entry {
+                    Ok(e) => e,
+                    Err(e) => {
+                        eprintln!("Warning: skipping unreadable entry in {}: {e}", model_path.display());
+                        continue;
+                    }
+                };
                 let version_path = version_entry.path();
 
                 if !version_path.is_dir() {
                     continue;
                 }
 
-                let version_str = version_path.file_name()
-                    .and_then(|n| n.to_str())
-                    .context("Invalid version directory name")?;
-
-                let version = Version::parse(version_str)
-                    .context("Invalid semver version")?;
+                let Some(version_str) = version_path.file_name().and_then(|n| n.to_str()) else {
+                    eprintln!("Warning: skipping non-UTF8 version directory: {}", version_path.display());
+                    continue;
+                };
+
+                let version = match Version::parse(version_str) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        eprintln!(
+                            "Warning: skipping {} with invalid semver directory '{}': {e}",
+                            model_id, version_str
+                        );
+                        continue;
+                    }
+                };
 
                 let metadata_path = version_path.join("metadata.json");
                 if !metadata_path.exists() {
+                    eprintln!(
+                        "Warning: skipping {} v{} with no metadata.json",
+                        model_id, version
+                    );
                     continue;
                 }
 
-                let metadata = ModelMetadata::load(&metadata_path)?;
-
-                let model_version = ModelVersion::new(
+                let metadata = match ModelMetadata::load(&metadata_path) {
+                    Ok(m) => m,
+                    Err(e) => {
+                        eprintln!(
+                            "Warning: skipping {} v{} with unreadable metadata: {e}",
+                            model_id, version
+                        );
+                        continue;
+                    }
+                };
+
+                let mut model_version = ModelVersion::new(
                     version,
                     version_path,
                     metadata,
                 );
 
+                // Restore the persisted status and audit trail if present;
+                // versions registered before status.json existed keep the
+                // "development" default from `ModelVersion::new`.
+                let status_path = model_version.status_path();
+                if status_path.exists() {
+                    match ModelVersion::load_status(&status_path) {
+                        Ok(record) => {
+                            model_version.status = record.status;
+                            model_version.status_history = record.history;
+                        }
+                        Err(e) => {
+                            eprintln!(
+                                "Warning: skipping corrupt status.json for {} v{}: {e}",
+                                model_id, model_version.version
+                            );
+                        }
+                    }
+                }
+
                 self.models
                     .entry(model_id.to_string())
                     .or_insert_with(Vec::new)
@@ -714,6 +1185,9 @@ impl ModelRegistry {
             }
         }
 
+        self.metadata.total_models = self.models.len();
+        self.metadata.total_versions = self.models.values().map(|v| v.len()).sum();
+
         Ok(())
     }
 }
@@ -725,6 +1199,7 @@ pub struct RegistryStatistics {
     pub total_versions: usize,
     pub development: usize,
     pub staging: usize,
+    pub canary: usize,
     pub production: usize,
     pub deprecated: usize,
     pub avg_versions_per_model: f64,
@@ -755,4 +1230,425 @@ mod tests {
         let v2 = Version::parse("2.0.0").unwrap();
         assert!(v2 > v1);
     }
+
+    #[test]
+    fn test_integrity_checksum_blocks_corrupt_promotion() {
+        let temp_dir = std::env::temp_dir().join(format!("test_integrity_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        let version = Version::parse("1.0.0").unwrap();
+        let model_dir = temp_dir.join("qa-model").join(version.to_string());
+        fs::create_dir_all(&model_dir).unwrap();
+        fs::write(model_dir.join("model.pkl"), b"original weights").unwrap();
+
+        let metadata = ModelMetadata::new(
+            "qa-model".to_string(),
+            version.to_string(),
+            "bootstrap".to_string(),
+            "gpt-4".to_string(),
+            100,
+            0.9,
+        );
+        registry.register_model("qa-model", version.clone(), metadata, false).unwrap();
+
+        assert!(registry.verify_integrity("qa-model", &version).unwrap());
+
+        registry
+            .promote_to_staging("qa-model", &version, "ready".to_string(), "tester".to_string())
+            .unwrap();
+
+        // Corrupt the model file after staging but before promotion.
+        fs::write(model_dir.join("model.pkl"), b"corrupted bytes").unwrap();
+        assert!(!registry.verify_integrity("qa-model", &version).unwrap());
+
+        let result = registry.promote_to_production(
+            "qa-model",
+            &version,
+            "go live".to_string(),
+            "tester".to_string(),
+            DowngradePolicy::Block,
+        );
+        assert!(result.is_err());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_new_rediscovers_versions_after_restart() {
+        let temp_dir = std::env::temp_dir().join(format!("test_rediscover_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        {
+            let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+            for version in ["1.0.0", "1.1.0"] {
+                let metadata = ModelMetadata::new(
+                    "qa-model".to_string(),
+                    version.to_string(),
+                    "bootstrap".to_string(),
+                    "gpt-4".to_string(),
+                    50,
+                    0.8,
+                );
+                registry
+                    .register_model("qa-model", Version::parse(version).unwrap(), metadata, false)
+                    .unwrap();
+            }
+        }
+
+        let fresh = ModelRegistry::new(temp_dir.clone()).unwrap();
+        let versions = fresh.list_versions("qa-model").expect("qa-model should be rediscovered");
+        assert_eq!(versions.len(), 2);
+        assert_eq!(fresh.statistics().total_versions, 2);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_status_history_survives_restart() {
+        let temp_dir = std::env::temp_dir().join(format!("test_status_history_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let version = Version::parse("1.0.0").unwrap();
+
+        {
+            let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+            let metadata = ModelMetadata::new(
+                "qa-model".to_string(),
+                version.to_string(),
+                "bootstrap".to_string(),
+                "gpt-4".to_string(),
+                10,
+                0.95,
+            );
+            registry.register_model("qa-model", version.clone(), metadata, false).unwrap();
+            registry
+                .promote_to_staging("qa-model", &version, "looks good".to_string(), "alice".to_string())
+                .unwrap();
+            registry
+                .promote_to_production("qa-model", &version, "ship it".to_string(), "alice".to_string(), DowngradePolicy::Block)
+                .unwrap();
+        }
+
+        let fresh = ModelRegistry::new(temp_dir.clone()).unwrap();
+        let production = fresh.get_production_model("qa-model").expect("status should survive restart");
+        assert_eq!(production.status, ModelStatus::Production);
+        assert_eq!(production.status_history.len(), 3);
+        assert_eq!(production.status_history.last().unwrap().changed_by, "alice");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_canary_promotion_and_serving() {
+        let temp_dir = std::env::temp_dir().join(format!("test_canary_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        let stable = Version::parse("1.0.0").unwrap();
+        let candidate = Version::parse("1.1.0").unwrap();
+        for v in [&stable, &candidate] {
+            let metadata = ModelMetadata::new(
+                "qa-model".to_string(),
+                v.to_string(),
+                "bootstrap".to_string(),
+                "gpt-4".to_string(),
+                10,
+                0.9,
+            );
+            registry.register_model("qa-model", v.clone(), metadata, false).unwrap();
+            registry
+                .promote_to_staging("qa-model", v, "ready".to_string(), "alice".to_string())
+                .unwrap();
+        }
+        registry
+            .promote_to_production("qa-model", &stable, "initial launch".to_string(), "alice".to_string(), DowngradePolicy::Block)
+            .unwrap();
+
+        registry
+            .promote_to_canary("qa-model", &candidate, 20.0, "try it out".to_string(), "alice".to_string())
+            .unwrap();
+
+        assert_eq!(
+            registry.get_serving_version("qa-model", 10.0).unwrap().version,
+            candidate
+        );
+        assert_eq!(
+            registry.get_serving_version("qa-model", 50.0).unwrap().version,
+            stable
+        );
+
+        registry
+            .promote_to_production("qa-model", &candidate, "graduate".to_string(), "alice".to_string(), DowngradePolicy::Block)
+            .unwrap();
+        assert_eq!(registry.get_production_model("qa-model").unwrap().version, candidate);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_promote_to_canary_rejects_bad_percent() {
+        let temp_dir = std::env::temp_dir().join(format!("test_canary_bad_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        let version = Version::parse("1.0.0").unwrap();
+        let metadata = ModelMetadata::new(
+            "qa-model".to_string(),
+            version.to_string(),
+            "bootstrap".to_string(),
+            "gpt-4".to_string(),
+            10,
+            0.9,
+        );
+        registry.register_model("qa-model", version.clone(), metadata, false).unwrap();
+        registry
+            .promote_to_staging("qa-model", &version, "ready".to_string(), "alice".to_string())
+            .unwrap();
+
+        assert!(registry
+            .promote_to_canary("qa-model", &version, 0.0, "x".to_string(), "alice".to_string())
+            .is_err());
+        assert!(registry
+            .promote_to_canary("qa-model", &version, 150.0, "x".to_string(), "alice".to_string())
+            .is_err());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_compare_versions_diffs_hyperparameters() {
+        let temp_dir = std::env::temp_dir().join(format!("test_hparam_diff_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        let v1 = Version::parse("1.0.0").unwrap();
+        let mut meta_a = ModelMetadata::new(
+            "qa-model".to_string(),
+            v1.to_string(),
+            "bootstrap".to_string(),
+            "gpt-4".to_string(),
+            100,
+            0.9,
+        );
+        meta_a.hyperparameters = serde_json::json!({
+            "temperature": 0.7,
+            "retries": 3,
+            "lm": { "top_p": 0.9 },
+        });
+        registry.register_model("qa-model", v1.clone(), meta_a, false).unwrap();
+
+        let v2 = Version::parse("1.1.0").unwrap();
+        let mut meta_b = ModelMetadata::new(
+            "qa-model".to_string(),
+            v2.to_string(),
+            "bootstrap".to_string(),
+            "gpt-4".to_string(),
+            100,
+            0.92,
+        );
+        meta_b.hyperparameters = serde_json::json!({
+            "temperature": 0.9,
+            "lm": { "top_p": 0.9, "max_tokens": 256 },
+        });
+        registry.register_model("qa-model", v2.clone(), meta_b, false).unwrap();
+
+        let comparison = registry.compare_versions("qa-model", &v1, &v2).unwrap();
+
+        assert!(comparison.details.iter().any(|d| d == "temperature: 0.7 → 0.9"));
+        assert!(comparison.details.iter().any(|d| d.starts_with("retries:") && d.ends_with("(removed)")));
+        assert!(comparison.details.iter().any(|d| d.starts_with("lm.max_tokens:") && d.ends_with("256")));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_prune_deprecated_keeps_newest_and_non_deprecated() {
+        let temp_dir = std::env::temp_dir().join(format!("test_prune_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        // Register four versions and promote 1.0.0, 1.1.0 and 1.2.0 to
+        // production in turn, which deprecates 1.0.0 and then 1.1.0.
+        for v in ["1.0.0", "1.1.0", "1.2.0", "1.3.0"] {
+            let metadata = ModelMetadata::new(
+                "qa-model".to_string(),
+                v.to_string(),
+                "bootstrap".to_string(),
+                "gpt-4".to_string(),
+                10,
+                0.9,
+            );
+            registry
+                .register_model("qa-model", Version::parse(v).unwrap(), metadata, false)
+                .unwrap();
+        }
+
+        for v in ["1.0.0", "1.1.0", "1.2.0"] {
+            let version = Version::parse(v).unwrap();
+            registry
+                .promote_to_staging("qa-model", &version, "ready".to_string(), "alice".to_string())
+                .unwrap();
+            registry
+                .promote_to_production("qa-model", &version, "go live".to_string(), "alice".to_string(), DowngradePolicy::Block)
+                .unwrap();
+        }
+        // 1.3.0 stays in development and must never be pruned.
+
+        let pruned = registry.prune_deprecated("qa-model", 1).unwrap();
+
+        // Only 1.0.0 and 1.1.0 are ever deprecated (1.2.0 is production,
+        // 1.3.0 is still in development). Keeping the newest 1 of those
+        // two deprecated versions (1.1.0) leaves 1.0.0 to be pruned.
+        assert_eq!(pruned, vec![Version::parse("1.0.0").unwrap()]);
+
+        let remaining = registry.list_versions("qa-model").unwrap();
+        assert_eq!(remaining.len(), 3);
+        assert!(remaining.iter().any(|v| v.version == Version::parse("1.1.0").unwrap()));
+        assert!(remaining.iter().any(|v| v.version == Version::parse("1.2.0").unwrap()));
+        assert!(remaining.iter().any(|v| v.version == Version::parse("1.3.0").unwrap()));
+
+        assert!(!temp_dir.join("qa-model").join("1.0.0").exists());
+        assert!(temp_dir.join("qa-model").join("1.1.0").exists());
+        assert!(temp_dir.join("qa-model").join("1.2.0").exists());
+        assert!(temp_dir.join("qa-model").join("1.3.0").exists());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_aliases_resolve_and_auto_update_stable_on_promotion() {
+        let temp_dir = std::env::temp_dir().join(format!("test_aliases_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        let v1 = Version::parse("1.0.0").unwrap();
+        let v2 = Version::parse("2.0.0").unwrap();
+        for v in [&v1, &v2] {
+            let metadata = ModelMetadata::new(
+                "qa-model".to_string(),
+                v.to_string(),
+                "bootstrap".to_string(),
+                "gpt-4".to_string(),
+                10,
+                0.9,
+            );
+            registry.register_model("qa-model", v.clone(), metadata, false).unwrap();
+        }
+
+        registry.set_alias("qa-model", "latest", &v2).unwrap();
+        assert_eq!(registry.resolve_alias("qa-model", "latest").unwrap().version, v2);
+
+        assert!(registry
+            .set_alias("qa-model", "broken", &Version::parse("9.9.9").unwrap())
+            .is_err());
+
+        registry
+            .promote_to_staging("qa-model", &v1, "ready".to_string(), "alice".to_string())
+            .unwrap();
+        registry
+            .promote_to_production("qa-model", &v1, "go live".to_string(), "alice".to_string(), DowngradePolicy::Block)
+            .unwrap();
+
+        assert_eq!(registry.resolve_alias("qa-model", "stable").unwrap().version, v1);
+
+        let aliases = registry.list_aliases("qa-model");
+        assert_eq!(aliases, vec![
+            ("latest".to_string(), v2.clone()),
+            ("stable".to_string(), v1.clone()),
+        ]);
+
+        // Aliases survive a restart, since they live in registry.json.
+        drop(registry);
+        let reloaded = ModelRegistry::new(temp_dir.clone()).unwrap();
+        assert_eq!(reloaded.resolve_alias("qa-model", "stable").unwrap().version, v1);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_register_model_rejects_lower_semver_unless_forced() {
+        let temp_dir = std::env::temp_dir().join(format!("test_semver_register_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        let make_metadata = |v: &str| {
+            ModelMetadata::new(
+                "qa-model".to_string(),
+                v.to_string(),
+                "bootstrap".to_string(),
+                "gpt-4".to_string(),
+                10,
+                0.9,
+            )
+        };
+
+        registry
+            .register_model("qa-model", Version::parse("1.5.0").unwrap(), make_metadata("1.5.0"), false)
+            .unwrap();
+
+        // Equal and minor-lower versions are rejected; a higher one is accepted.
+        assert!(registry
+            .register_model("qa-model", Version::parse("1.5.0").unwrap(), make_metadata("1.5.0"), false)
+            .is_err());
+        assert!(registry
+            .register_model("qa-model", Version::parse("1.4.9").unwrap(), make_metadata("1.4.9"), false)
+            .is_err());
+        assert!(registry
+            .register_model("qa-model", Version::parse("1.6.0").unwrap(), make_metadata("1.6.0"), false)
+            .is_ok());
+
+        // A major-version regression is still rejected without force...
+        assert!(registry
+            .register_model("qa-model", Version::parse("0.9.0").unwrap(), make_metadata("0.9.0"), false)
+            .is_err());
+        // ...but forced through when explicitly requested.
+        assert!(registry
+            .register_model("qa-model", Version::parse("0.9.0").unwrap(), make_metadata("0.9.0"), true)
+            .is_ok());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_promote_to_production_downgrade_policy() {
+        let temp_dir = std::env::temp_dir().join(format!("test_semver_promote_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&temp_dir);
+        let mut registry = ModelRegistry::new(temp_dir.clone()).unwrap();
+
+        let v1 = Version::parse("2.0.0").unwrap();
+        let v2 = Version::parse("1.0.0").unwrap();
+        for v in [&v1, &v2] {
+            let metadata = ModelMetadata::new(
+                "qa-model".to_string(),
+                v.to_string(),
+                "bootstrap".to_string(),
+                "gpt-4".to_string(),
+                10,
+                0.9,
+            );
+            registry.register_model("qa-model", v.clone(), metadata, true).unwrap();
+            registry
+                .promote_to_staging("qa-model", v, "ready".to_string(), "alice".to_string())
+                .unwrap();
+        }
+
+        registry
+            .promote_to_production("qa-model", &v1, "go live".to_string(), "alice".to_string(), DowngradePolicy::Block)
+            .unwrap();
+
+        // v2 (1.0.0) is older than the current production v1 (2.0.0):
+        // blocked by default...
+        assert!(registry
+            .promote_to_production("qa-model", &v2, "rollforward by mistake".to_string(), "alice".to_string(), DowngradePolicy::Block)
+            .is_err());
+        assert_eq!(registry.get_production_model("qa-model").unwrap().version, v1);
+
+        // ...but allowed through when the caller explicitly opts into Warn.
+        registry
+            .promote_to_production("qa-model", &v2, "intentional downgrade".to_string(), "alice".to_string(), DowngradePolicy::Warn)
+            .unwrap();
+        assert_eq!(registry.get_production_model("qa-model").unwrap().version, v2);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
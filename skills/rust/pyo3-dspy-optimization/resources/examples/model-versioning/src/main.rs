@@ -9,7 +9,7 @@
 
 use anyhow::Result;
 use model_versioning::{
-    ComparisonWinner, ModelMetadata, ModelRegistry, ModelStatus,
+    ComparisonWinner, DowngradePolicy, ModelMetadata, ModelRegistry, ModelStatus,
 };
 use semver::Version;
 use std::path::PathBuf;
@@ -87,10 +87,11 @@ fn create_model_versions(registry: &mut ModelRegistry) -> Result<()> {
         git_commit: Some("abc123".to_string()),
         training_duration_secs: Some(3600),
         model_size_bytes: Some(1024 * 1024 * 10), // 10 MB
+        sha256: None,
     };
 
     let v1 = Version::parse("1.0.0")?;
-    let v1_path = registry.register_model("qa-model", v1.clone(), v1_metadata)?;
+    let v1_path = registry.register_model("qa-model", v1.clone(), v1_metadata, false)?;
     println!("✓ Registered qa-model v1.0.0");
     println!("  Path: {:?}", v1_path);
     println!("  Validation score: 0.82");
@@ -117,10 +118,11 @@ fn create_model_versions(registry: &mut ModelRegistry) -> Result<()> {
         git_commit: Some("def456".to_string()),
         training_duration_secs: Some(7200),
         model_size_bytes: Some(1024 * 1024 * 12), // 12 MB
+        sha256: None,
     };
 
     let v1_1 = Version::parse("1.1.0")?;
-    let v1_1_path = registry.register_model("qa-model", v1_1.clone(), v1_1_metadata)?;
+    let v1_1_path = registry.register_model("qa-model", v1_1.clone(), v1_1_metadata, false)?;
     println!("✓ Registered qa-model v1.1.0");
     println!("  Path: {:?}", v1_1_path);
     println!("  Validation score: 0.87 (+0.05)");
@@ -147,10 +149,11 @@ fn create_model_versions(registry: &mut ModelRegistry) -> Result<()> {
         git_commit: Some("ghi789".to_string()),
         training_duration_secs: Some(14400),
         model_size_bytes: Some(1024 * 1024 * 15), // 15 MB
+        sha256: None,
     };
 
     let v2 = Version::parse("2.0.0")?;
-    let v2_path = registry.register_model("qa-model", v2.clone(), v2_metadata)?;
+    let v2_path = registry.register_model("qa-model", v2.clone(), v2_metadata, false)?;
     println!("✓ Registered qa-model v2.0.0");
     println!("  Path: {:?}", v2_path);
     println!("  Validation score: 0.93 (+0.06)");
@@ -177,10 +180,11 @@ fn create_model_versions(registry: &mut ModelRegistry) -> Result<()> {
         git_commit: Some("jkl012".to_string()),
         training_duration_secs: Some(2400),
         model_size_bytes: Some(1024 * 1024 * 8), // 8 MB
+        sha256: None,
     };
 
     let summ_v = Version::parse("1.0.0")?;
-    registry.register_model("summarization-model", summ_v, summ_metadata)?;
+    registry.register_model("summarization-model", summ_v, summ_metadata, false)?;
     println!("✓ Registered summarization-model v1.0.0");
     println!("  Validation score: 0.78\n");
 
@@ -211,6 +215,7 @@ fn promotion_workflow(registry: &mut ModelRegistry) -> Result<()> {
         &v1_0_0,
         "Passed staging tests and load testing".to_string(),
         "deployment-team".to_string(),
+        DowngradePolicy::Warn,
     )?;
     println!("  ✓ v1.0.0 now in production\n");
 
@@ -317,6 +322,7 @@ fn rollback_scenario(registry: &mut ModelRegistry) -> Result<()> {
         &v1_1_0,
         "Better performance metrics observed".to_string(),
         "deployment-team".to_string(),
+        DowngradePolicy::Warn,
     )?;
     println!();
 
@@ -371,6 +377,7 @@ fn display_version_info(version: &model_versioning::ModelVersion) {
         ModelStatus::Development => "🔨",
         ModelStatus::Staging => "🧪",
         ModelStatus::Production => "🚀",
+        ModelStatus::Canary { .. } => "🐤",
         ModelStatus::Deprecated => "⛔",
     };
 
@@ -1,14 +1,28 @@
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Duration, Utc};
+use hdrhistogram::Histogram;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use rand::distributions::WeightedIndex;
 use rand::prelude::*;
 use serde::{Deserialize, Serialize};
 use statrs::distribution::{ChiSquared, ContinuousCDF, StudentsT};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 
+/// Cap on the number of raw latency samples retained per variant for
+/// statistical tests (t-test, effect size, confidence interval), which need
+/// actual samples rather than percentiles. Bounded via reservoir sampling so
+/// memory stays flat for long-running experiments.
+const LATENCY_RESERVOIR_CAP: usize = 10_000;
+
+/// 1 microsecond to ~1 hour range at 2 significant digits of precision,
+/// recording latencies in microseconds like `LatencyTracker` in the
+/// performance-monitoring example (whose upper bound is higher).
+fn new_latency_histogram() -> Histogram<u64> {
+    Histogram::new_with_bounds(1, 3_600_000_000, 2).expect("valid histogram bounds")
+}
+
 /// Traffic splitting strategy for routing requests
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -70,6 +84,29 @@ impl ABTestConfig {
 
         Ok(())
     }
+
+    /// Rescales variant weights proportionally so they sum to 1.0, so
+    /// operators can specify relative weights like 70/30/10 instead of
+    /// pre-normalized fractions.
+    ///
+    /// Errors if any weight is negative or the weights sum to zero, since
+    /// neither case can be rescaled into a valid distribution.
+    pub fn normalize_weights(&mut self) -> Result<()> {
+        if self.variants.iter().any(|v| v.weight < 0.0) {
+            return Err(anyhow!("Variant weights must not be negative"));
+        }
+
+        let total_weight: f64 = self.variants.iter().map(|v| v.weight).sum();
+        if total_weight == 0.0 {
+            return Err(anyhow!("Variant weights must not all be zero"));
+        }
+
+        for variant in &mut self.variants {
+            variant.weight /= total_weight;
+        }
+
+        Ok(())
+    }
 }
 
 /// Request metrics collected during prediction
@@ -87,10 +124,19 @@ pub struct RequestMetrics {
 }
 
 /// Aggregated metrics for a variant
+///
+/// Latency percentiles are served from a bounded HdrHistogram rather than a
+/// fully-sorted `Vec`, so lookups are O(1) and memory stays flat no matter
+/// how long the experiment runs. `latencies` is still populated, but as a
+/// capped reservoir sample (see [`LATENCY_RESERVOIR_CAP`]) for statistical
+/// tests that need raw samples rather than percentiles.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VariantMetrics {
     pub variant_name: String,
     pub request_count: usize,
+    #[serde(skip, default = "new_latency_histogram")]
+    latency_histogram: Histogram<u64>,
+    total_latency_ms: f64,
     pub latencies: Vec<f64>,
     pub success_count: usize,
     pub failure_count: usize,
@@ -105,6 +151,8 @@ impl VariantMetrics {
         Self {
             variant_name,
             request_count: 0,
+            latency_histogram: new_latency_histogram(),
+            total_latency_ms: 0.0,
             latencies: Vec::new(),
             success_count: 0,
             failure_count: 0,
@@ -117,7 +165,11 @@ impl VariantMetrics {
 
     pub fn add_request(&mut self, metrics: &RequestMetrics) {
         self.request_count += 1;
-        self.latencies.push(metrics.latency_ms);
+        self.total_latency_ms += metrics.latency_ms;
+
+        let micros = (metrics.latency_ms * 1000.0).max(0.0) as u64;
+        let _ = self.latency_histogram.record(micros);
+        self.reservoir_sample_latency(metrics.latency_ms);
 
         if metrics.success {
             self.success_count += 1;
@@ -137,6 +189,21 @@ impl VariantMetrics {
         self.total_cost += metrics.cost;
     }
 
+    /// Reservoir-samples `value` into `latencies` using Algorithm R, keeping
+    /// at most [`LATENCY_RESERVOIR_CAP`] samples regardless of how many
+    /// requests have been recorded.
+    fn reservoir_sample_latency(&mut self, value: f64) {
+        if self.latencies.len() < LATENCY_RESERVOIR_CAP {
+            self.latencies.push(value);
+            return;
+        }
+
+        let j = rand::thread_rng().gen_range(0..self.request_count);
+        if j < LATENCY_RESERVOIR_CAP {
+            self.latencies[j] = value;
+        }
+    }
+
     pub fn success_rate(&self) -> f64 {
         if self.request_count == 0 {
             return 0.0;
@@ -145,20 +212,19 @@ impl VariantMetrics {
     }
 
     pub fn mean_latency(&self) -> f64 {
-        if self.latencies.is_empty() {
+        if self.request_count == 0 {
             return 0.0;
         }
-        self.latencies.iter().sum::<f64>() / self.latencies.len() as f64
+        self.total_latency_ms / self.request_count as f64
     }
 
+    /// Returns the latency in milliseconds at `percentile` (0.0-1.0), read
+    /// directly from the histogram in O(1).
     pub fn percentile_latency(&self, percentile: f64) -> f64 {
-        if self.latencies.is_empty() {
+        if self.request_count == 0 {
             return 0.0;
         }
-        let mut sorted = self.latencies.clone();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        let idx = (percentile * sorted.len() as f64).ceil() as usize;
-        sorted[idx.min(sorted.len() - 1)]
+        self.latency_histogram.value_at_percentile(percentile * 100.0) as f64 / 1000.0
     }
 
     pub fn mean_quality(&self) -> f64 {
@@ -268,9 +334,21 @@ impl StatisticalAnalyzer {
             .sum::<f64>()
             / (n_treatment - 1.0);
 
+        let mean_diff = mean_treatment - mean_control;
+
         // Welch's t-test (unequal variances)
         let se = ((var_control / n_control) + (var_treatment / n_treatment)).sqrt();
-        let t_statistic = (mean_treatment - mean_control) / se;
+
+        // Zero variance (identical-valued or single-element samples) leaves
+        // nothing to normalize the mean difference by. Rather than let that
+        // divide-by-zero propagate NaN/Inf into `significant`, report a
+        // well-defined degenerate result: no detectable difference when the
+        // means also match, or a maximally significant one when they don't.
+        if se == 0.0 || !se.is_finite() {
+            return Ok(Self::degenerate_t_test(mean_diff, self.confidence_level));
+        }
+
+        let t_statistic = mean_diff / se;
 
         // Degrees of freedom (Welch-Satterthwaite equation)
         let df_numerator = ((var_control / n_control) + (var_treatment / n_treatment)).powi(2);
@@ -278,6 +356,12 @@ impl StatisticalAnalyzer {
             + (var_treatment / n_treatment).powi(2) / (n_treatment - 1.0);
         let df = df_numerator / df_denominator;
 
+        if !df.is_finite() {
+            // A single-element sample leaves the degrees of freedom
+            // undefined (division by n - 1 = 0).
+            return Ok(Self::degenerate_t_test(mean_diff, self.confidence_level));
+        }
+
         let t_dist = StudentsT::new(0.0, 1.0, df)
             .map_err(|e| anyhow!("Failed to create t-distribution: {}", e))?;
 
@@ -295,6 +379,22 @@ impl StatisticalAnalyzer {
         })
     }
 
+    /// A well-defined `StatisticalTest` for inputs too degenerate to test
+    /// statistically (zero-variance or single-element samples): no
+    /// difference detected if the means match, otherwise a maximally
+    /// significant (but unquantified) one.
+    fn degenerate_t_test(mean_diff: f64, confidence_level: f64) -> StatisticalTest {
+        let differs = mean_diff != 0.0;
+
+        StatisticalTest {
+            test_name: "Welch's t-test".to_string(),
+            statistic: 0.0,
+            p_value: if differs { 0.0 } else { 1.0 },
+            significant: differs,
+            confidence_level,
+        }
+    }
+
     /// Perform chi-square test for categorical metrics (e.g., success rate)
     pub fn chi_square_test(
         &self,
@@ -320,15 +420,21 @@ impl StatisticalAnalyzer {
         let expected_treatment_success = (treatment_total as f64) * success_total / total;
         let expected_treatment_failure = (treatment_total as f64) * failure_total / total;
 
-        // Chi-square statistic
-        let chi_square = ((control_success as f64 - expected_control_success).powi(2)
-            / expected_control_success)
-            + ((control_failure as f64 - expected_control_failure).powi(2)
-                / expected_control_failure)
-            + ((treatment_success as f64 - expected_treatment_success).powi(2)
-                / expected_treatment_success)
-            + ((treatment_failure as f64 - expected_treatment_failure).powi(2)
-                / expected_treatment_failure);
+        // Chi-square statistic. An expected frequency of zero only happens
+        // when the corresponding observed count is also zero (e.g. nobody
+        // succeeded in either group), so that cell contributes nothing
+        // rather than a 0/0 NaN.
+        let chi_square_term = |observed: f64, expected: f64| {
+            if expected == 0.0 {
+                0.0
+            } else {
+                (observed - expected).powi(2) / expected
+            }
+        };
+        let chi_square = chi_square_term(control_success as f64, expected_control_success)
+            + chi_square_term(control_failure as f64, expected_control_failure)
+            + chi_square_term(treatment_success as f64, expected_treatment_success)
+            + chi_square_term(treatment_failure as f64, expected_treatment_failure);
 
         let df = 1.0; // (rows - 1) * (cols - 1) = (2 - 1) * (2 - 1)
         let chi_dist = ChiSquared::new(df)
@@ -372,12 +478,24 @@ impl StatisticalAnalyzer {
             .sum::<f64>()
             / (n_treatment - 1.0);
 
+        let mean_diff = mean_treatment - mean_control;
+
         // Pooled standard deviation
         let pooled_var = ((n_control - 1.0) * var_control + (n_treatment - 1.0) * var_treatment)
             / (n_control + n_treatment - 2.0);
         let pooled_sd = pooled_var.sqrt();
 
-        let cohens_d = (mean_treatment - mean_control) / pooled_sd;
+        // No within-group spread to normalize by (e.g. identical-valued or
+        // single-element samples). Cohen's d is undefined here rather than
+        // merely zero, but reporting 0.0 ("negligible") for matching means
+        // and a large-but-finite sentinel for differing ones keeps
+        // `EffectSize::new`'s interpretation well-defined instead of NaN/Inf.
+        if pooled_sd == 0.0 || !pooled_sd.is_finite() {
+            let cohens_d = if mean_diff == 0.0 { 0.0 } else { mean_diff.signum() * 10.0 };
+            return Ok(EffectSize::new(cohens_d));
+        }
+
+        let cohens_d = mean_diff / pooled_sd;
 
         Ok(EffectSize::new(cohens_d))
     }
@@ -490,7 +608,15 @@ pub struct ABTestRunner {
 }
 
 impl ABTestRunner {
-    pub fn new(config: ABTestConfig) -> Result<Self> {
+    /// Creates a runner for `config`. When `auto_normalize` is true, variant
+    /// weights are rescaled to sum to 1.0 (via
+    /// [`ABTestConfig::normalize_weights`]) before the strict validation in
+    /// [`ABTestConfig::validate`] runs, so relative weights like 70/30/10 are
+    /// accepted as-is. Pass `false` to keep the strict sum-to-1.0 requirement.
+    pub fn new(mut config: ABTestConfig, auto_normalize: bool) -> Result<Self> {
+        if auto_normalize {
+            config.normalize_weights()?;
+        }
         config.validate()?;
 
         let mut metrics = HashMap::new();
@@ -758,3 +884,735 @@ impl ABTestRunner {
         }
     }
 }
+
+impl std::fmt::Display for ExperimentReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Experiment Report: {}", self.experiment_name)?;
+        writeln!(
+            f,
+            "Duration: {:.2}h ({} -> {})",
+            self.duration_hours, self.start_time, self.end_time
+        )?;
+        writeln!(f, "Total Requests: {}", self.total_requests)?;
+        writeln!(f)?;
+
+        let mut variant_names: Vec<&String> = self.variants.keys().collect();
+        variant_names.sort();
+
+        for name in variant_names {
+            let summary = &self.variants[name];
+            writeln!(f, "Variant: {}", name)?;
+            writeln!(f, "  Requests: {}", summary.request_count)?;
+            writeln!(f, "  Latency:")?;
+            writeln!(f, "    Mean: {:.2}ms", summary.mean_latency)?;
+            writeln!(f, "    p50: {:.2}ms", summary.latency_p50)?;
+            writeln!(f, "    p95: {:.2}ms", summary.latency_p95)?;
+            writeln!(f, "    p99: {:.2}ms", summary.latency_p99)?;
+            writeln!(f, "  Success Rate: {:.2}%", summary.success_rate * 100.0)?;
+            writeln!(f, "  Mean Quality: {:.2}", summary.mean_quality)?;
+            writeln!(f, "  Mean Feedback: {:.2}", summary.mean_feedback)?;
+            writeln!(f, "  Cost per Request: ${:.4}", summary.cost_per_request)?;
+            writeln!(f)?;
+        }
+
+        let stats = &self.statistical_analysis;
+        writeln!(f, "Statistical Analysis:")?;
+        writeln!(
+            f,
+            "  Latency t-test: statistic={:.4}, p={:.4}, significant={}",
+            stats.latency_t_test.statistic,
+            stats.latency_t_test.p_value,
+            stats.latency_t_test.significant
+        )?;
+        writeln!(
+            f,
+            "  Success rate chi-square: statistic={:.4}, p={:.4}, significant={}",
+            stats.success_rate_chi_square.statistic,
+            stats.success_rate_chi_square.p_value,
+            stats.success_rate_chi_square.significant
+        )?;
+        writeln!(
+            f,
+            "  Effect size: d={:.2} ({})",
+            stats.effect_size.cohens_d, stats.effect_size.interpretation
+        )?;
+        writeln!(
+            f,
+            "  Confidence interval ({:.2}%): [{:.2}, {:.2}]",
+            stats.confidence_interval.confidence_level * 100.0,
+            stats.confidence_interval.lower,
+            stats.confidence_interval.upper
+        )?;
+        writeln!(f)?;
+
+        writeln!(f, "Promotion Decision:")?;
+        writeln!(f, "  Should Promote: {}", self.promotion_decision.should_promote)?;
+        writeln!(
+            f,
+            "  Winner: {}",
+            self.promotion_decision
+                .winner
+                .as_deref()
+                .unwrap_or("none")
+        )?;
+        writeln!(
+            f,
+            "  Confidence: {:.2}%",
+            self.promotion_decision.confidence * 100.0
+        )?;
+        writeln!(f, "  Reasoning: {}", self.promotion_decision.reasoning)?;
+
+        Ok(())
+    }
+}
+
+impl ExperimentReport {
+    /// Renders this report as Markdown, suitable for posting to a dashboard
+    /// or chat channel. Mirrors the section layout of the `Display` impl, but
+    /// with variant metrics as a table.
+    pub fn to_markdown(&self) -> String {
+        use std::fmt::Write;
+
+        let mut md = String::new();
+
+        let _ = writeln!(md, "# Experiment Report: {}", self.experiment_name);
+        let _ = writeln!(
+            md,
+            "\n**Duration:** {:.2}h ({} -> {})  \n**Total Requests:** {}\n",
+            self.duration_hours, self.start_time, self.end_time, self.total_requests
+        );
+
+        let mut variant_names: Vec<&String> = self.variants.keys().collect();
+        variant_names.sort();
+
+        let _ = writeln!(md, "## Variants\n");
+        let _ = writeln!(
+            md,
+            "| Variant | Requests | Mean Latency | p50 | p95 | p99 | Success Rate | Mean Quality | Mean Feedback | Cost/Request |"
+        );
+        let _ = writeln!(
+            md,
+            "|---|---|---|---|---|---|---|---|---|---|"
+        );
+        for name in variant_names {
+            let summary = &self.variants[name];
+            let _ = writeln!(
+                md,
+                "| {} | {} | {:.2}ms | {:.2}ms | {:.2}ms | {:.2}ms | {:.2}% | {:.2} | {:.2} | ${:.4} |",
+                name,
+                summary.request_count,
+                summary.mean_latency,
+                summary.latency_p50,
+                summary.latency_p95,
+                summary.latency_p99,
+                summary.success_rate * 100.0,
+                summary.mean_quality,
+                summary.mean_feedback,
+                summary.cost_per_request
+            );
+        }
+
+        let stats = &self.statistical_analysis;
+        let _ = writeln!(md, "\n## Statistical Analysis\n");
+        let _ = writeln!(
+            md,
+            "- **Latency t-test:** statistic={:.4}, p={:.4}, significant={}",
+            stats.latency_t_test.statistic,
+            stats.latency_t_test.p_value,
+            stats.latency_t_test.significant
+        );
+        let _ = writeln!(
+            md,
+            "- **Success rate chi-square:** statistic={:.4}, p={:.4}, significant={}",
+            stats.success_rate_chi_square.statistic,
+            stats.success_rate_chi_square.p_value,
+            stats.success_rate_chi_square.significant
+        );
+        let _ = writeln!(
+            md,
+            "- **Effect size:** d={:.2} ({})",
+            stats.effect_size.cohens_d, stats.effect_size.interpretation
+        );
+        let _ = writeln!(
+            md,
+            "- **Confidence interval ({:.2}%):** [{:.2}, {:.2}]",
+            stats.confidence_interval.confidence_level * 100.0,
+            stats.confidence_interval.lower,
+            stats.confidence_interval.upper
+        );
+
+        let _ = writeln!(md, "\n## Promotion Decision\n");
+        let _ = writeln!(
+            md,
+            "- **Should Promote:** {}",
+            self.promotion_decision.should_promote
+        );
+        let _ = writeln!(
+            md,
+            "- **Winner:** {}",
+            self.promotion_decision
+                .winner
+                .as_deref()
+                .unwrap_or("none")
+        );
+        let _ = writeln!(
+            md,
+            "- **Confidence:** {:.2}%",
+            self.promotion_decision.confidence * 100.0
+        );
+        let _ = writeln!(md, "- **Reasoning:** {}", self.promotion_decision.reasoning);
+
+        md
+    }
+}
+
+/// Archives serialized [`ExperimentReport`]s across repeated runs of the same
+/// experiment, keyed by run date, so [`ExperimentArchive::meta_analysis`] can
+/// check whether a treatment's effect holds up week over week rather than
+/// being a single lucky run.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExperimentArchive {
+    runs: HashMap<String, BTreeMap<DateTime<Utc>, Vec<u8>>>,
+}
+
+/// Result of combining effect sizes across runs of one experiment via
+/// inverse-variance meta-analysis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaAnalysis {
+    pub experiment_name: String,
+    pub run_count: usize,
+    /// Pooled effect assuming every run estimates the same true effect.
+    pub fixed_effect: PooledEffect,
+    /// Pooled effect allowing the true effect to vary run to run
+    /// (DerSimonian-Laird); widens the confidence interval when
+    /// `heterogeneity.i_squared` is high.
+    pub random_effect: PooledEffect,
+    pub heterogeneity: Heterogeneity,
+    /// One point per archived run, oldest first, ready to hand to a
+    /// forest-plot renderer.
+    pub forest_plot: Vec<ForestPlotPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PooledEffect {
+    pub cohens_d: f64,
+    pub standard_error: f64,
+    pub confidence_interval: ConfidenceInterval,
+}
+
+/// Heterogeneity of the effect across runs. `i_squared` near 0% means the
+/// variation between runs is explained by sampling noise alone (the effect
+/// is consistent); a high `i_squared` means the runs disagree more than
+/// chance would predict (the effect may be a fluke, or context-dependent).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Heterogeneity {
+    pub q_statistic: f64,
+    pub degrees_of_freedom: f64,
+    pub i_squared: f64,
+    pub tau_squared: f64,
+}
+
+/// One run's effect size and confidence interval, plus its relative weight
+/// in the fixed-effect pooled estimate, for forest-plot rendering.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForestPlotPoint {
+    pub run_date: DateTime<Utc>,
+    pub cohens_d: f64,
+    pub lower: f64,
+    pub upper: f64,
+    /// Percentage (0-100) of the total fixed-effect weight contributed by
+    /// this run.
+    pub weight_pct: f64,
+}
+
+impl ExperimentArchive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Archives `report`, keyed by its experiment name and `start_time`.
+    /// Re-recording the same experiment with the same `start_time`
+    /// overwrites the prior entry.
+    pub fn record(&mut self, report: &ExperimentReport) -> Result<()> {
+        let serialized =
+            serde_json::to_vec(report).context("Failed to serialize experiment report")?;
+        self.runs
+            .entry(report.experiment_name.clone())
+            .or_default()
+            .insert(report.start_time, serialized);
+        Ok(())
+    }
+
+    /// Deserializes every archived run of `experiment_name`, oldest first.
+    pub fn runs(&self, experiment_name: &str) -> Result<Vec<ExperimentReport>> {
+        let Some(runs) = self.runs.get(experiment_name) else {
+            return Ok(Vec::new());
+        };
+
+        runs.values()
+            .map(|bytes| {
+                serde_json::from_slice(bytes)
+                    .context("Failed to deserialize archived experiment report")
+            })
+            .collect()
+    }
+
+    /// Combines the effect sizes of every archived run of `experiment_name`
+    /// via fixed-effect and random-effects (DerSimonian-Laird)
+    /// inverse-variance meta-analysis, reporting whether the treatment's
+    /// benefit is consistent across runs or a fluke.
+    ///
+    /// Each run's Cohen's d variance is approximated from its
+    /// `total_requests` assuming an even control/treatment split, since
+    /// `ExperimentReport` doesn't retain the raw samples needed for an exact
+    /// variance — consistent with the rest of this crate's sample-based
+    /// statistics being estimates, not exact values.
+    pub fn meta_analysis(&self, experiment_name: &str) -> Result<MetaAnalysis> {
+        let runs = self.runs(experiment_name)?;
+        if runs.len() < 2 {
+            return Err(anyhow!(
+                "Need at least 2 runs for meta-analysis (found {})",
+                runs.len()
+            ));
+        }
+
+        let observations: Vec<(DateTime<Utc>, f64, f64)> = runs
+            .iter()
+            .map(|run| {
+                let d = run.statistical_analysis.effect_size.cohens_d;
+                let variance = cohens_d_variance(d, run.total_requests);
+                (run.start_time, d, variance)
+            })
+            .collect();
+
+        let fixed_weights: Vec<f64> = observations.iter().map(|(_, _, v)| 1.0 / v).collect();
+        let sum_fixed_weights: f64 = fixed_weights.iter().sum();
+
+        let pooled_fixed_d: f64 = observations
+            .iter()
+            .zip(&fixed_weights)
+            .map(|((_, d, _), w)| w * d)
+            .sum::<f64>()
+            / sum_fixed_weights;
+
+        let q_statistic: f64 = observations
+            .iter()
+            .zip(&fixed_weights)
+            .map(|((_, d, _), w)| w * (d - pooled_fixed_d).powi(2))
+            .sum();
+        let degrees_of_freedom = (observations.len() - 1) as f64;
+
+        let sum_sq_fixed_weights: f64 = fixed_weights.iter().map(|w| w.powi(2)).sum();
+        let weight_denominator = sum_fixed_weights - sum_sq_fixed_weights / sum_fixed_weights;
+        let tau_squared = if weight_denominator > 0.0 {
+            ((q_statistic - degrees_of_freedom) / weight_denominator).max(0.0)
+        } else {
+            0.0
+        };
+
+        let i_squared = if q_statistic > 0.0 {
+            ((q_statistic - degrees_of_freedom) / q_statistic).clamp(0.0, 1.0) * 100.0
+        } else {
+            0.0
+        };
+
+        let random_weights: Vec<f64> = observations
+            .iter()
+            .map(|(_, _, v)| 1.0 / (v + tau_squared))
+            .collect();
+        let sum_random_weights: f64 = random_weights.iter().sum();
+        let pooled_random_d: f64 = observations
+            .iter()
+            .zip(&random_weights)
+            .map(|((_, d, _), w)| w * d)
+            .sum::<f64>()
+            / sum_random_weights;
+
+        const Z_95: f64 = 1.959964; // two-tailed 95% critical value
+
+        let fixed_effect = PooledEffect {
+            cohens_d: pooled_fixed_d,
+            standard_error: (1.0 / sum_fixed_weights).sqrt(),
+            confidence_interval: ConfidenceInterval {
+                lower: pooled_fixed_d - Z_95 * (1.0 / sum_fixed_weights).sqrt(),
+                upper: pooled_fixed_d + Z_95 * (1.0 / sum_fixed_weights).sqrt(),
+                confidence_level: 0.95,
+            },
+        };
+
+        let random_effect = PooledEffect {
+            cohens_d: pooled_random_d,
+            standard_error: (1.0 / sum_random_weights).sqrt(),
+            confidence_interval: ConfidenceInterval {
+                lower: pooled_random_d - Z_95 * (1.0 / sum_random_weights).sqrt(),
+                upper: pooled_random_d + Z_95 * (1.0 / sum_random_weights).sqrt(),
+                confidence_level: 0.95,
+            },
+        };
+
+        let forest_plot = observations
+            .iter()
+            .zip(&fixed_weights)
+            .map(|((run_date, d, variance), weight)| {
+                let se = variance.sqrt();
+                ForestPlotPoint {
+                    run_date: *run_date,
+                    cohens_d: *d,
+                    lower: d - Z_95 * se,
+                    upper: d + Z_95 * se,
+                    weight_pct: weight / sum_fixed_weights * 100.0,
+                }
+            })
+            .collect();
+
+        Ok(MetaAnalysis {
+            experiment_name: experiment_name.to_string(),
+            run_count: runs.len(),
+            fixed_effect,
+            random_effect,
+            heterogeneity: Heterogeneity {
+                q_statistic,
+                degrees_of_freedom,
+                i_squared,
+                tau_squared,
+            },
+            forest_plot,
+        })
+    }
+}
+
+/// Approximates the sampling variance of a Cohen's d estimate from the
+/// total request count of the run that produced it, assuming an even
+/// control/treatment split (Hedges & Olkin's large-sample approximation).
+fn cohens_d_variance(d: f64, total_requests: usize) -> f64 {
+    let n = (total_requests as f64 / 2.0).max(1.0);
+    2.0 / n + d.powi(2) / (4.0 * n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_weights(weights: &[f64]) -> ABTestConfig {
+        ABTestConfig {
+            name: "test".to_string(),
+            variants: weights
+                .iter()
+                .enumerate()
+                .map(|(i, &weight)| ModelVariant {
+                    name: format!("variant-{}", i),
+                    model_path: format!("models/{}", i),
+                    weight,
+                })
+                .collect(),
+            traffic_strategy: TrafficStrategy::WeightedRandom,
+            min_sample_size: 100,
+            confidence_level: 0.95,
+            duration_hours: 24,
+        }
+    }
+
+    #[test]
+    fn test_normalize_weights_rescales_relative_weights() {
+        let mut config = config_with_weights(&[70.0, 30.0]);
+        config.normalize_weights().unwrap();
+
+        assert!((config.variants[0].weight - 0.7).abs() < 1e-9);
+        assert!((config.variants[1].weight - 0.3).abs() < 1e-9);
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn test_normalize_weights_three_way_split() {
+        let mut config = config_with_weights(&[70.0, 30.0, 10.0]);
+        config.normalize_weights().unwrap();
+
+        let total: f64 = config.variants.iter().map(|v| v.weight).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!((config.variants[0].weight - 70.0 / 110.0).abs() < 1e-9);
+        assert!((config.variants[1].weight - 30.0 / 110.0).abs() < 1e-9);
+        assert!((config.variants[2].weight - 10.0 / 110.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_normalize_weights_rejects_all_zero() {
+        let mut config = config_with_weights(&[0.0, 0.0]);
+        assert!(config.normalize_weights().is_err());
+    }
+
+    #[test]
+    fn test_normalize_weights_rejects_negative() {
+        let mut config = config_with_weights(&[70.0, -30.0]);
+        assert!(config.normalize_weights().is_err());
+    }
+
+    #[test]
+    fn test_t_test_identical_samples_is_not_significant_not_nan() {
+        let analyzer = StatisticalAnalyzer::new(0.95);
+        let samples = vec![0.5, 0.5, 0.5, 0.5];
+
+        let result = analyzer.t_test(&samples, &samples).unwrap();
+
+        assert!(!result.significant);
+        assert!(!result.statistic.is_nan());
+        assert!(!result.p_value.is_nan());
+        assert_eq!(result.statistic, 0.0);
+        assert_eq!(result.p_value, 1.0);
+    }
+
+    #[test]
+    fn test_t_test_zero_variance_differing_means_is_significant_not_inf() {
+        let analyzer = StatisticalAnalyzer::new(0.95);
+        let control = vec![1.0, 1.0, 1.0];
+        let treatment = vec![2.0, 2.0, 2.0];
+
+        let result = analyzer.t_test(&control, &treatment).unwrap();
+
+        assert!(result.significant);
+        assert!(!result.statistic.is_nan());
+        assert!(!result.p_value.is_nan());
+        assert_eq!(result.p_value, 0.0);
+    }
+
+    #[test]
+    fn test_t_test_single_element_samples_does_not_error_with_nan() {
+        let analyzer = StatisticalAnalyzer::new(0.95);
+        let result = analyzer.t_test(&[0.9], &[0.4]).unwrap();
+
+        assert!(!result.statistic.is_nan());
+        assert!(!result.p_value.is_nan());
+    }
+
+    #[test]
+    fn test_cohens_d_identical_samples_is_negligible_not_nan() {
+        let analyzer = StatisticalAnalyzer::new(0.95);
+        let samples = vec![0.5, 0.5, 0.5];
+
+        let effect = analyzer.cohens_d(&samples, &samples).unwrap();
+
+        assert_eq!(effect.cohens_d, 0.0);
+        assert_eq!(effect.interpretation, "negligible");
+    }
+
+    #[test]
+    fn test_cohens_d_zero_variance_differing_means_is_large_not_inf() {
+        let analyzer = StatisticalAnalyzer::new(0.95);
+        let control = vec![1.0, 1.0, 1.0];
+        let treatment = vec![3.0, 3.0, 3.0];
+
+        let effect = analyzer.cohens_d(&control, &treatment).unwrap();
+
+        assert!(effect.cohens_d.is_finite());
+        assert_eq!(effect.interpretation, "large");
+    }
+
+    #[test]
+    fn test_chi_square_test_zero_success_in_both_groups_does_not_panic() {
+        let analyzer = StatisticalAnalyzer::new(0.95);
+        let result = analyzer.chi_square_test(0, 50, 0, 50).unwrap();
+
+        assert!(!result.statistic.is_nan());
+        assert!(!result.significant);
+    }
+
+    #[test]
+    fn test_ab_test_runner_new_auto_normalize() {
+        let config = config_with_weights(&[70.0, 30.0]);
+        let runner = ABTestRunner::new(config, true).unwrap();
+        assert!((runner.config.variants[0].weight - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ab_test_runner_new_strict_rejects_unnormalized() {
+        let config = config_with_weights(&[70.0, 30.0]);
+        assert!(ABTestRunner::new(config, false).is_err());
+    }
+
+    fn sample_request(latency_ms: f64) -> RequestMetrics {
+        RequestMetrics {
+            variant_name: "control".to_string(),
+            user_id: "user-1".to_string(),
+            latency_ms,
+            success: true,
+            quality_score: None,
+            user_feedback: None,
+            tokens_used: 10,
+            cost: 0.001,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_variant_metrics_percentile_and_mean_latency() {
+        let mut metrics = VariantMetrics::new("control".to_string());
+        for ms in 1..=1000 {
+            metrics.add_request(&sample_request(ms as f64));
+        }
+
+        assert_eq!(metrics.request_count, 1000);
+        assert!((metrics.mean_latency() - 500.5).abs() < 1.0);
+        assert!(metrics.percentile_latency(0.5) <= metrics.percentile_latency(0.95));
+        assert!(metrics.percentile_latency(0.95) <= metrics.percentile_latency(0.99));
+    }
+
+    #[test]
+    fn test_variant_metrics_reservoir_is_capped() {
+        let mut metrics = VariantMetrics::new("control".to_string());
+        for ms in 0..(LATENCY_RESERVOIR_CAP * 2) {
+            metrics.add_request(&sample_request(ms as f64));
+        }
+
+        assert_eq!(metrics.request_count, LATENCY_RESERVOIR_CAP * 2);
+        assert_eq!(metrics.latencies.len(), LATENCY_RESERVOIR_CAP);
+    }
+
+    #[test]
+    fn test_variant_metrics_no_samples_returns_zero() {
+        let metrics = VariantMetrics::new("control".to_string());
+        assert_eq!(metrics.mean_latency(), 0.0);
+        assert_eq!(metrics.percentile_latency(0.99), 0.0);
+    }
+
+    fn sample_report() -> ExperimentReport {
+        let now = Utc::now();
+        let mut variants = HashMap::new();
+        variants.insert(
+            "control".to_string(),
+            VariantSummary {
+                request_count: 1000,
+                latency_p50: 100.0,
+                latency_p95: 200.0,
+                latency_p99: 300.0,
+                mean_latency: 120.0,
+                success_rate: 0.95,
+                mean_quality: 0.8,
+                mean_feedback: 4.2,
+                cost_per_request: 0.002,
+            },
+        );
+
+        ExperimentReport {
+            experiment_name: "test-experiment".to_string(),
+            start_time: now,
+            end_time: now,
+            duration_hours: 1.0,
+            total_requests: 1000,
+            variants,
+            statistical_analysis: StatisticalAnalysis {
+                latency_t_test: StatisticalTest {
+                    test_name: "t-test".to_string(),
+                    statistic: 1.2345,
+                    p_value: 0.04321,
+                    significant: true,
+                    confidence_level: 0.95,
+                },
+                success_rate_chi_square: StatisticalTest {
+                    test_name: "chi-square".to_string(),
+                    statistic: 2.3456,
+                    p_value: 0.1234,
+                    significant: false,
+                    confidence_level: 0.95,
+                },
+                effect_size: EffectSize::new(0.3),
+                confidence_interval: ConfidenceInterval {
+                    lower: -1.0,
+                    upper: 1.0,
+                    confidence_level: 0.95,
+                },
+            },
+            promotion_decision: PromotionDecision {
+                should_promote: true,
+                winner: Some("control".to_string()),
+                confidence: 0.95,
+                reasoning: "all checks passed".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_experiment_report_display_includes_key_sections() {
+        let rendered = sample_report().to_string();
+
+        assert!(rendered.contains("Experiment Report: test-experiment"));
+        assert!(rendered.contains("Variant: control"));
+        assert!(rendered.contains("p=0.0432"));
+        assert!(rendered.contains("Should Promote: true"));
+    }
+
+    #[test]
+    fn test_experiment_report_to_markdown_includes_table_and_sections() {
+        let markdown = sample_report().to_markdown();
+
+        assert!(markdown.contains("# Experiment Report: test-experiment"));
+        assert!(markdown.contains("| control | 1000 |"));
+        assert!(markdown.contains("## Statistical Analysis"));
+        assert!(markdown.contains("## Promotion Decision"));
+    }
+
+    fn report_with_effect(start_time: DateTime<Utc>, cohens_d: f64) -> ExperimentReport {
+        let mut report = sample_report();
+        report.start_time = start_time;
+        report.statistical_analysis.effect_size = EffectSize::new(cohens_d);
+        report
+    }
+
+    #[test]
+    fn test_meta_analysis_requires_at_least_two_runs() {
+        let mut archive = ExperimentArchive::new();
+        archive.record(&sample_report()).unwrap();
+
+        assert!(archive.meta_analysis("test-experiment").is_err());
+    }
+
+    #[test]
+    fn test_meta_analysis_unknown_experiment_has_no_runs() {
+        let archive = ExperimentArchive::new();
+        assert!(archive.runs("unknown").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_meta_analysis_pools_consistent_effects_with_low_heterogeneity() {
+        let mut archive = ExperimentArchive::new();
+        let base_time = Utc::now();
+        for week in 0..4 {
+            let report = report_with_effect(base_time + Duration::days(7 * week), 0.5);
+            archive.record(&report).unwrap();
+        }
+
+        let meta = archive.meta_analysis("test-experiment").unwrap();
+
+        assert_eq!(meta.run_count, 4);
+        assert!((meta.fixed_effect.cohens_d - 0.5).abs() < 1e-9);
+        assert!((meta.random_effect.cohens_d - 0.5).abs() < 1e-9);
+        assert!(meta.heterogeneity.i_squared < 1.0);
+        assert_eq!(meta.forest_plot.len(), 4);
+        assert!((meta.forest_plot.iter().map(|p| p.weight_pct).sum::<f64>() - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_meta_analysis_flags_inconsistent_effects_with_high_heterogeneity() {
+        let mut archive = ExperimentArchive::new();
+        let base_time = Utc::now();
+        let effects = [1.5, -1.5, 1.5, -1.5];
+        for (week, &effect) in effects.iter().enumerate() {
+            let report = report_with_effect(base_time + Duration::days(7 * week as i64), effect);
+            archive.record(&report).unwrap();
+        }
+
+        let meta = archive.meta_analysis("test-experiment").unwrap();
+
+        assert!(meta.heterogeneity.i_squared > 50.0);
+        assert!(meta.heterogeneity.tau_squared > 0.0);
+    }
+
+    #[test]
+    fn test_archive_round_trips_reports() {
+        let mut archive = ExperimentArchive::new();
+        let report = sample_report();
+        archive.record(&report).unwrap();
+
+        let runs = archive.runs("test-experiment").unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].experiment_name, report.experiment_name);
+    }
+}
@@ -151,7 +151,7 @@ async fn run_experiment(
     println!();
 
     // Create runner
-    let runner = ABTestRunner::new(config.clone())?;
+    let runner = ABTestRunner::new(config.clone(), false)?;
 
     // Create mock predictors for each variant
     let mut predictors = HashMap::new();
@@ -12,21 +12,30 @@
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use cost_tracking::CostBudget;
+use dashmap::DashMap;
 use failsafe::{CircuitBreaker, Config as CircuitConfig, Error as CircuitError};
 use lazy_static::lazy_static;
 use moka::future::Cache;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use prometheus::{
-    register_counter_vec, register_gauge_vec, register_histogram_vec, CounterVec, Encoder,
-    GaugeVec, HistogramVec, TextEncoder,
+    register_counter, register_counter_vec, register_gauge, register_gauge_vec,
+    register_histogram_vec, Counter, CounterVec, Encoder, Gauge, GaugeVec, HistogramVec,
+    TextEncoder,
 };
 use pyo3::prelude::*;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::{Rng, SeedableRng};
 use redis::aio::ConnectionManager;
 use redis::AsyncCommands;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::io::{AsyncWriteExt, BufWriter as TokioBufWriter};
+use tokio::sync::mpsc;
 use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
@@ -52,7 +61,9 @@ lazy_static! {
     )
     .unwrap();
 
-    /// Cache hit/miss counter
+    /// Cache hit/miss counter. `operation` is `"hit"`/`"miss"`/`"error"` for
+    /// the positive cache, or `"negative_hit"` for a hit in the negative
+    /// cache (see `NegativeCacheEntry`).
     static ref CACHE_OPERATIONS: CounterVec = register_counter_vec!(
         "dspy_cache_operations_total",
         "Cache operations",
@@ -99,6 +110,74 @@ lazy_static! {
         &["model", "error_type"]
     )
     .unwrap();
+
+    /// Requests rejected for exceeding the configured cost budget
+    static ref BUDGET_EXCEEDED_TOTAL: CounterVec = register_counter_vec!(
+        "dspy_budget_exceeded_total",
+        "Total requests rejected for exceeding the cost budget",
+        &["model", "scope"]
+    )
+    .unwrap();
+
+    /// Time spent waiting for a rate limit token before dispatch
+    static ref RATE_LIMIT_WAIT: HistogramVec = register_histogram_vec!(
+        "dspy_rate_limit_wait_seconds",
+        "Time spent waiting for a rate limit token",
+        &["model"],
+        vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
+    )
+    .unwrap();
+
+    /// Requests waiting in the admission queue, by priority class (lower is
+    /// more urgent; see `AdmissionQueue`).
+    static ref ADMISSION_QUEUE_DEPTH: GaugeVec = register_gauge_vec!(
+        "dspy_admission_queue_depth",
+        "Requests waiting for an admission slot, by priority class",
+        &["priority_class"]
+    )
+    .unwrap();
+
+    /// Entries currently held in the in-process memory cache
+    static ref MEMORY_CACHE_ENTRIES: Gauge = register_gauge!(
+        "dspy_memory_cache_entries",
+        "Number of entries currently in the in-process memory cache"
+    )
+    .unwrap();
+
+    /// Entries evicted from the in-process memory cache (expiry or size pressure,
+    /// not explicit removals or replacements)
+    static ref MEMORY_CACHE_EVICTIONS_TOTAL: Counter = register_counter!(
+        "dspy_memory_cache_evictions_total",
+        "Total entries evicted from the in-process memory cache"
+    )
+    .unwrap();
+
+    /// Key count in the shared Redis cache, sampled periodically via DBSIZE
+    static ref REDIS_CACHE_KEYS: Gauge = register_gauge!(
+        "dspy_redis_cache_keys",
+        "Number of keys in the Redis cache, as of the last DBSIZE sample"
+    )
+    .unwrap();
+
+    /// `compressed_len / original_len` for the most recent cache entry
+    /// gzip-compressed before a Redis write (see
+    /// `maybe_compress_for_redis`). Lower is better; stays at `0.0` ("no
+    /// data yet") until the first entry crosses
+    /// `ServiceConfig::compress_threshold_bytes`.
+    static ref CACHE_COMPRESSION_RATIO: Gauge = register_gauge!(
+        "dspy_cache_compression_ratio",
+        "Ratio of compressed to original size for the most recent compressed cache write"
+    )
+    .unwrap();
+
+    /// Predictions that dispatched a hedged backup attempt because the
+    /// primary hadn't returned within `ModelConfig::hedge_after_ms`
+    static ref HEDGED_REQUESTS_TOTAL: CounterVec = register_counter_vec!(
+        "dspy_hedged_requests_total",
+        "Total predictions that dispatched a hedged backup attempt",
+        &["model"]
+    )
+    .unwrap();
 }
 
 // =============================================================================
@@ -128,6 +207,62 @@ pub struct ServiceConfig {
     #[serde(default = "default_redis_ttl")]
     pub redis_cache_ttl_secs: u64,
 
+    /// Randomize each Redis cache entry's effective TTL within
+    /// `±redis_cache_ttl_jitter_percent` of `redis_cache_ttl_secs` (see
+    /// `jittered_ttl_secs`), so entries written for a popular prompt around
+    /// the same time don't all expire in the same instant and stampede the
+    /// backend with a burst of simultaneous cache misses. `0.0` disables
+    /// jitter and uses `redis_cache_ttl_secs` exactly, matching prior
+    /// behavior. Only applied to `store_in_cache`, not the negative cache,
+    /// which is already short-lived and not a stampede risk in the same way.
+    #[serde(default = "default_redis_cache_ttl_jitter_percent")]
+    pub redis_cache_ttl_jitter_percent: f64,
+
+    /// Gzip-compress a cache entry before writing it to Redis once its
+    /// encoded size (after `CacheCodec::encode`) reaches this many bytes;
+    /// smaller entries are stored as-is, since gzip's own overhead would
+    /// eat into or erase the savings. `0` disables compression entirely
+    /// (every entry stored as-is), which also matches the behavior of
+    /// configs written before this field existed. Does not affect the
+    /// in-process memory cache, which already holds deserialized values.
+    /// See `maybe_compress_for_redis`/`maybe_decompress_from_redis`.
+    #[serde(default = "default_compress_threshold_bytes")]
+    pub compress_threshold_bytes: usize,
+
+    /// Deduplicate concurrent cache-miss requests that share the same cache
+    /// key (see `ProductionDSpyService::dispatch_with_single_flight`): only
+    /// the first ("leader") actually calls the backend, and every other
+    /// concurrent request for that key awaits the leader's result instead
+    /// of each independently calling the model, waiting on the same rate
+    /// limiter, and paying its own cost. `false` (default) preserves prior
+    /// behavior, where every cache-miss request dispatches independently.
+    /// Trade-off: with this on, a follower's response mirrors the leader's
+    /// output/tokens/cost/latency rather than reflecting its own dispatch
+    /// (only `request_id` is corrected back to the follower's own) - only
+    /// enable this for workloads where identical concurrent requests really
+    /// are interchangeable.
+    #[serde(default)]
+    pub single_flight_enabled: bool,
+
+    /// TTL in seconds for negative-cache entries — predictions that failed
+    /// with a deterministic error (see `is_deterministic_error`) and are
+    /// cached so the same doomed-to-fail input doesn't keep hammering the
+    /// backend and tripping the circuit breaker. Kept much shorter than
+    /// `memory_cache_ttl_secs`/`redis_cache_ttl_secs` since the error could
+    /// stop being deterministic (a content filter gets retrained, a backend
+    /// fix ships) and a stale negative entry would otherwise mask that for
+    /// the rest of its TTL.
+    #[serde(default = "default_negative_cache_ttl")]
+    pub negative_cache_ttl_secs: u64,
+
+    /// Cap on `redis_reconnector`'s exponential backoff between attempts to
+    /// re-establish the Redis connection after it couldn't be established
+    /// at startup. Doubling from `REDIS_RECONNECT_INITIAL_BACKOFF` without a
+    /// cap would mean a prolonged outage eventually makes recovery attempts
+    /// arbitrarily rare.
+    #[serde(default = "default_redis_reconnect_max_backoff_secs")]
+    pub redis_reconnect_max_backoff_secs: u64,
+
     /// Circuit breaker failure threshold
     #[serde(default = "default_failure_threshold")]
     pub circuit_breaker_failure_threshold: u32,
@@ -150,6 +285,285 @@ pub struct ServiceConfig {
     /// Default model variant
     #[serde(default = "default_variant")]
     pub default_variant: String,
+
+    /// Path to an append-only JSON-lines audit log. When set, every
+    /// completed prediction (success or error) is recorded with its
+    /// tokens, cost, and cache status for compliance purposes. `None`
+    /// disables audit logging entirely.
+    #[serde(default)]
+    pub audit_log_path: Option<String>,
+
+    /// What to do when a model's rate limit (`ModelConfig::max_rpm`) is
+    /// exhausted: wait for a token up to that model's `request_timeout_secs`,
+    /// or reject the request immediately.
+    #[serde(default)]
+    pub rate_limit_behavior: RateLimitBehavior,
+
+    /// Maximum number of predictions dispatched to the backend at once,
+    /// across all models and priorities. Additional requests wait in the
+    /// priority-aware `AdmissionQueue` instead of running unbounded.
+    #[serde(default = "default_max_concurrent_predictions")]
+    pub max_concurrent_predictions: usize,
+
+    /// Maximum number of requests allowed to wait in the admission queue
+    /// (across all priority classes) once `max_concurrent_predictions` is
+    /// saturated. Exceeding it fails the request immediately with a
+    /// `queue_full` error rather than queueing unboundedly.
+    #[serde(default = "default_max_queued_predictions")]
+    pub max_queued_predictions: usize,
+
+    /// Cumulative cost budget, reusing `cost_tracking::CostBudget` rather
+    /// than a bespoke type. This service tracks cost since startup, not
+    /// per calendar day, so `daily_limit_usd` caps each model's cumulative
+    /// cost and `monthly_limit_usd` caps the cost across all models
+    /// combined. `None` disables budget enforcement.
+    #[serde(default)]
+    pub cost_budget: Option<CostBudget>,
+
+    /// Per-model daily/monthly USD spending caps, checked against that
+    /// model's accumulated `CostMetrics` right before a cache-miss
+    /// prediction reaches the backend — skipped entirely for cache hits,
+    /// since they incur no new spend. Complements `cost_budget`: that field
+    /// enforces one limit uniformly across every model plus a global
+    /// monthly cap, while `budget_limits` lets individual models carry
+    /// their own caps instead, e.g. a cheap model with more headroom than
+    /// an expensive one. A model absent from `BudgetLimits::models` has no
+    /// per-model limit. `None` disables this check entirely.
+    #[serde(default)]
+    pub budget_limits: Option<BudgetLimits>,
+
+    /// How a model's circuit breaker decides to trip. Defaults to
+    /// failsafe's built-in consecutive-failures policy
+    /// (`circuit_breaker_failure_threshold`); see `CircuitBreakerMode` for
+    /// the error-rate alternative.
+    #[serde(default)]
+    pub circuit_breaker_mode: CircuitBreakerMode,
+
+    /// Serialization format used for cached predictions written to Redis;
+    /// the in-process memory cache holds deserialized values and is not
+    /// affected by it. See `CacheCodec` for the tradeoffs and migration
+    /// behavior when this changes on a running service.
+    #[serde(default)]
+    pub cache_codec: CacheCodec,
+
+    /// Whether request/response text is redacted (see `Redactor`) before it
+    /// reaches logs or the audit log. Cache keys are always derived from the
+    /// original, unredacted input regardless of this setting, so disabling
+    /// redaction never changes cache hit/miss behavior. Defaults to `true`;
+    /// set `false` only for trusted, non-PII workloads where the regex
+    /// passes would just add overhead.
+    #[serde(default = "default_redaction_enabled")]
+    pub redaction_enabled: bool,
+}
+
+/// Serialization format for `CachedPrediction` values. MessagePack encodes
+/// smaller and (de)serializes faster than JSON, which matters once cached
+/// outputs get long; JSON remains the default for readability when
+/// inspecting Redis by hand.
+///
+/// Every encoded value is tagged with a one-byte codec marker
+/// (`CacheCodec::marker`) ahead of the payload, so `CacheCodec::decode` can
+/// read values written under either codec regardless of what
+/// `ServiceConfig::cache_codec` is set to right now — entries written before
+/// a codec change stay readable through the migration window instead of
+/// erroring or silently missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheCodec {
+    #[default]
+    Json,
+    MessagePack,
+}
+
+impl CacheCodec {
+    fn marker(self) -> u8 {
+        match self {
+            CacheCodec::Json => 0,
+            CacheCodec::MessagePack => 1,
+        }
+    }
+
+    fn from_marker(marker: u8) -> Result<Self> {
+        match marker {
+            0 => Ok(CacheCodec::Json),
+            1 => Ok(CacheCodec::MessagePack),
+            other => Err(anyhow::anyhow!("Unknown cache codec marker: {}", other)),
+        }
+    }
+
+    /// Encodes `value` under this codec, prefixed with its marker byte.
+    /// Generic so both `CachedPrediction` and `NegativeCacheEntry` share the
+    /// same marker-byte migration scheme.
+    fn encode<T: Serialize>(self, value: &T) -> Result<Vec<u8>> {
+        let mut buf = vec![self.marker()];
+        match self {
+            CacheCodec::Json => serde_json::to_writer(&mut buf, value)?,
+            CacheCodec::MessagePack => rmp_serde::encode::write(&mut buf, value)?,
+        }
+        Ok(buf)
+    }
+
+    /// Decodes a value previously produced by `encode`, regardless of which
+    /// codec was active at the time — the marker byte picks the decoder.
+    fn decode<T: for<'de> Deserialize<'de>>(data: &[u8]) -> Result<T> {
+        let (&marker, payload) = data
+            .split_first()
+            .ok_or_else(|| anyhow::anyhow!("Empty cache entry"))?;
+        match Self::from_marker(marker)? {
+            CacheCodec::Json => Ok(serde_json::from_slice(payload)?),
+            CacheCodec::MessagePack => Ok(rmp_serde::from_slice(payload)?),
+        }
+    }
+}
+
+/// Whether `maybe_compress_for_redis` gzip-compressed a Redis payload.
+/// Stored as a one-byte marker ahead of the (possibly compressed) buffer,
+/// on top of - not instead of - `CacheCodec`'s own marker byte, so a
+/// payload written under one compression setting stays readable after
+/// `ServiceConfig::compress_threshold_bytes` changes, the same way
+/// `CacheCodec`'s marker survives a codec change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RedisPayloadEncoding {
+    Plain,
+    Gzip,
+}
+
+impl RedisPayloadEncoding {
+    fn marker(self) -> u8 {
+        match self {
+            RedisPayloadEncoding::Plain => 0,
+            RedisPayloadEncoding::Gzip => 1,
+        }
+    }
+
+    fn from_marker(marker: u8) -> Result<Self> {
+        match marker {
+            0 => Ok(RedisPayloadEncoding::Plain),
+            1 => Ok(RedisPayloadEncoding::Gzip),
+            other => Err(anyhow::anyhow!("Unknown Redis payload encoding marker: {}", other)),
+        }
+    }
+}
+
+/// Gzip-compresses `data` (an already `CacheCodec`-encoded buffer) and
+/// prefixes it with a `RedisPayloadEncoding` marker byte before it's
+/// written to Redis, when `data.len()` reaches `threshold_bytes` - smaller
+/// entries are stored as-is (still marker-prefixed) since gzip's own
+/// overhead would eat into or erase the savings. `threshold_bytes == 0`
+/// disables compression entirely, matching configs written before
+/// `ServiceConfig::compress_threshold_bytes` existed. Updates
+/// `CACHE_COMPRESSION_RATIO` whenever compression is actually applied.
+fn maybe_compress_for_redis(data: &[u8], threshold_bytes: usize) -> Result<Vec<u8>> {
+    if threshold_bytes == 0 || data.len() < threshold_bytes {
+        let mut buf = vec![RedisPayloadEncoding::Plain.marker()];
+        buf.extend_from_slice(data);
+        return Ok(buf);
+    }
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    std::io::Write::write_all(&mut encoder, data)?;
+    let compressed = encoder.finish()?;
+
+    CACHE_COMPRESSION_RATIO.set(compressed.len() as f64 / data.len() as f64);
+
+    let mut buf = vec![RedisPayloadEncoding::Gzip.marker()];
+    buf.extend_from_slice(&compressed);
+    Ok(buf)
+}
+
+/// Reverses `maybe_compress_for_redis`: reads the leading
+/// `RedisPayloadEncoding` marker and gzip-decompresses the rest when
+/// needed, returning a buffer ready for `CacheCodec::decode`.
+fn maybe_decompress_from_redis(data: &[u8]) -> Result<Vec<u8>> {
+    let (&marker, payload) = data
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("Empty Redis cache entry"))?;
+    match RedisPayloadEncoding::from_marker(marker)? {
+        RedisPayloadEncoding::Plain => Ok(payload.to_vec()),
+        RedisPayloadEncoding::Gzip => {
+            let mut decoder = flate2::read::GzDecoder::new(payload);
+            let mut decoded = Vec::new();
+            std::io::Read::read_to_end(&mut decoder, &mut decoded)?;
+            Ok(decoded)
+        }
+    }
+}
+
+/// How a model's circuit breaker decides to trip.
+///
+/// `circuit_breaker_failure_threshold` alone trips too slowly under high
+/// traffic (N failures is a tiny fraction of calls) and too quickly under
+/// low traffic (N failures might be nearly all of them). `ErrorRate` fixes
+/// this by tripping on a failure *ratio* instead of a raw count, guarded by
+/// a minimum request volume so a handful of failures right after startup
+/// can't trip a breaker that hasn't seen enough traffic to judge yet.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum CircuitBreakerMode {
+    /// failsafe's own policy: `circuit_breaker_failure_threshold`
+    /// consecutive failures trips the breaker.
+    ConsecutiveFailures,
+
+    /// Trips when the failure ratio over a trailing `window_secs` is at or
+    /// above `failure_rate_threshold`, but only once at least
+    /// `min_requests` calls have landed in that window. Implemented as a
+    /// rolling-window tracker (`FailureRateWindow`) wrapping the existing
+    /// failsafe breaker, since failsafe itself has no failure-ratio policy.
+    ErrorRate {
+        /// Fraction of requests in the window that must fail to trip, e.g. `0.5` for 50%.
+        failure_rate_threshold: f64,
+
+        /// Requests that must land in the window before the ratio check
+        /// is allowed to trip the breaker.
+        min_requests: u32,
+
+        /// Width of the trailing window used to compute the failure ratio.
+        #[serde(default = "default_error_rate_window_secs")]
+        window_secs: u64,
+    },
+}
+
+impl Default for CircuitBreakerMode {
+    fn default() -> Self {
+        Self::ConsecutiveFailures
+    }
+}
+
+fn default_error_rate_window_secs() -> u64 {
+    60
+}
+
+/// Per-model daily/monthly USD spending caps. See
+/// `ServiceConfig::budget_limits`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BudgetLimits {
+    /// Limits keyed by model name.
+    #[serde(default)]
+    pub models: HashMap<String, ModelBudgetLimit>,
+}
+
+/// Daily/monthly USD caps for a single model, checked against that model's
+/// cumulative `CostMetrics::total_cost_usd` (summed across variants). Like
+/// `cost_tracking::CostBudget`, these are cumulative totals rather than
+/// real calendar windows — `CostTracker` doesn't bucket by time, so "daily"
+/// and "monthly" describe the limit's intended cadence, not an
+/// automatically-resetting window.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ModelBudgetLimit {
+    pub daily_limit_usd: f64,
+    pub monthly_limit_usd: f64,
+}
+
+/// Behavior when a model's token bucket has no tokens available.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitBehavior {
+    /// Block (without holding the Tokio worker thread) until a token frees
+    /// up or the request deadline elapses.
+    #[default]
+    Wait,
+    /// Fail immediately with a `rate_limited` error.
+    Reject,
 }
 
 fn default_cache_size() -> u64 {
@@ -161,6 +575,18 @@ fn default_cache_ttl() -> u64 {
 fn default_redis_ttl() -> u64 {
     3600
 }
+fn default_redis_cache_ttl_jitter_percent() -> f64 {
+    10.0
+}
+fn default_compress_threshold_bytes() -> usize {
+    1024
+}
+fn default_negative_cache_ttl() -> u64 {
+    30
+}
+fn default_redis_reconnect_max_backoff_secs() -> u64 {
+    60
+}
 fn default_failure_threshold() -> u32 {
     5
 }
@@ -173,6 +599,15 @@ fn default_circuit_timeout() -> u64 {
 fn default_variant() -> String {
     "baseline".to_string()
 }
+fn default_max_concurrent_predictions() -> usize {
+    100
+}
+fn default_max_queued_predictions() -> usize {
+    500
+}
+fn default_redaction_enabled() -> bool {
+    true
+}
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ModelConfig {
@@ -192,6 +627,80 @@ pub struct ModelConfig {
     /// Request timeout in seconds
     #[serde(default = "default_request_timeout")]
     pub request_timeout_secs: u64,
+
+    /// Maximum requests per minute for this model. `None` disables rate
+    /// limiting. Enforced via a token bucket that refills continuously
+    /// (`max_rpm / 60` tokens/sec), so short bursts up to `max_rpm` are
+    /// still allowed.
+    #[serde(default)]
+    pub max_rpm: Option<u32>,
+
+    /// JSON Schema that `PredictionRequest.parameters` must satisfy for this
+    /// model. `None` skips validation entirely, so existing configs without
+    /// a schema keep working unchanged.
+    #[serde(default)]
+    pub parameters_schema: Option<serde_json::Value>,
+
+    /// If a prediction for this model hasn't returned within this many
+    /// milliseconds, dispatch a second backup attempt and serve whichever
+    /// of the two returns first. `None` (default) disables hedging. See
+    /// `ProductionDSpyService::execute_prediction_with_hedge`.
+    #[serde(default)]
+    pub hedge_after_ms: Option<u64>,
+
+    /// When hedging fires and `true`, cost is recorded for *both* the
+    /// primary and backup attempt rather than only the one that served the
+    /// response (`false`, default) — at the cost of this model's slower
+    /// requests briefly waiting on the loser just to account for it, since
+    /// we don't cancel it until we know its outcome.
+    #[serde(default)]
+    pub hedge_cost_both: bool,
+
+    /// Per-model override for `ServiceConfig::circuit_breaker_failure_threshold`.
+    /// `None` falls back to the global value. Lets a model with a much
+    /// different failure tolerance than the rest of the fleet — e.g. a
+    /// flaky local model that should trip fast, or an expensive model
+    /// that's worth a few more retries before tripping — have its own
+    /// sensitivity.
+    #[serde(default)]
+    pub circuit_breaker_failure_threshold: Option<u32>,
+
+    /// Per-model override for `ServiceConfig::circuit_breaker_success_threshold`.
+    /// `None` falls back to the global value.
+    #[serde(default)]
+    pub circuit_breaker_success_threshold: Option<u32>,
+
+    /// Per-model override for `ServiceConfig::circuit_breaker_timeout_secs`.
+    /// `None` falls back to the global value.
+    #[serde(default)]
+    pub circuit_breaker_timeout_secs: Option<u64>,
+
+    /// Python module containing the DSPy program to call for this model,
+    /// e.g. `"my_package.pipelines"`. Looked up once per call by
+    /// `PyO3Backend::complete` (`None`, default, keeps the placeholder
+    /// formatted-string behavior — useful for models without Python
+    /// wiring configured yet, and for tests). Must be set together with
+    /// `python_callable`.
+    #[serde(default)]
+    pub python_module: Option<String>,
+
+    /// Attribute within `python_module` to call: a callable accepting
+    /// `(input: str, **parameters)` and returning either a plain string or
+    /// an object exposing `.output`/`.completion` plus optional
+    /// `.input_tokens`/`.output_tokens` for exact token counts (falling
+    /// back to the length-based heuristic when absent).
+    #[serde(default)]
+    pub python_callable: Option<String>,
+
+    /// A/B test variant weights for this model, e.g. `{"baseline": 0.8,
+    /// "treatment": 0.2}`. Only consulted when `ServiceConfig::ab_testing_enabled`
+    /// is `true` and the request didn't specify `PredictionRequest::variant`
+    /// explicitly — see `ProductionDSpyService::resolve_variant`. Empty
+    /// (default) falls back to `ServiceConfig::default_variant` even with
+    /// A/B testing enabled, so configs without a weight table keep
+    /// behaving exactly as before.
+    #[serde(default)]
+    pub variants: HashMap<String, f64>,
 }
 
 fn default_max_retries() -> u32 {
@@ -201,6 +710,367 @@ fn default_request_timeout() -> u64 {
     30
 }
 
+/// Attempts for a single Redis GET/SET before treating it as a failure.
+const REDIS_MAX_ATTEMPTS: u32 = 3;
+/// Backoff between attempts, scaled by attempt number (20ms, 40ms, ...).
+const REDIS_RETRY_BACKOFF: Duration = Duration::from_millis(20);
+
+/// Backoff between `execute_prediction`'s retries after a backend timeout,
+/// scaled by attempt number (50ms, 100ms, ...).
+const BACKEND_RETRY_BACKOFF: Duration = Duration::from_millis(50);
+/// How often to sample Redis key count via `DBSIZE` for `dspy_redis_cache_keys`.
+const REDIS_CACHE_SIZE_SAMPLE_INTERVAL: Duration = Duration::from_secs(30);
+/// First backoff `redis_reconnector` waits after a failed reconnect attempt,
+/// doubling on each subsequent failure up to `ServiceConfig::redis_reconnect_max_backoff_secs`.
+const REDIS_RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Randomizes `base_secs` within `±jitter_percent` of its value, so cache
+/// entries for the same key written around the same time don't all expire
+/// at the same instant and stampede the backend with a burst of
+/// simultaneous cache misses for a popular prompt. `jitter_percent <= 0.0`
+/// disables jitter entirely and returns `base_secs` unchanged; values above
+/// `100.0` are clamped to `100.0` so the jittered TTL can never go negative.
+fn jittered_ttl_secs(base_secs: u64, jitter_percent: f64) -> u64 {
+    if jitter_percent <= 0.0 || base_secs == 0 {
+        return base_secs;
+    }
+
+    let jitter_fraction = (jitter_percent / 100.0).min(1.0);
+    let max_offset = base_secs as f64 * jitter_fraction;
+    let jittered = base_secs as f64 + rand::thread_rng().gen_range(-max_offset..=max_offset);
+    jittered.max(1.0).round() as u64
+}
+
+// =============================================================================
+// Rate Limiting
+// =============================================================================
+
+/// A token-bucket rate limiter. Capacity equals `max_rpm`, and tokens refill
+/// continuously at `max_rpm / 60` per second, so sustained throughput is
+/// capped at `max_rpm` requests per minute while short bursts up to the full
+/// bucket are still allowed immediately.
+struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    tokens: Mutex<f64>,
+    last_refill: Mutex<Instant>,
+}
+
+impl TokenBucket {
+    fn new(max_rpm: u32) -> Self {
+        let capacity = max_rpm.max(1) as f64;
+        Self {
+            capacity,
+            refill_per_sec: capacity / 60.0,
+            tokens: Mutex::new(capacity),
+            last_refill: Mutex::new(Instant::now()),
+        }
+    }
+
+    fn refill(&self) {
+        let mut last_refill = self.last_refill.lock();
+        let elapsed = last_refill.elapsed().as_secs_f64();
+        if elapsed <= 0.0 {
+            return;
+        }
+        let mut tokens = self.tokens.lock();
+        *tokens = (*tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        *last_refill = Instant::now();
+    }
+
+    fn try_acquire(&self) -> bool {
+        self.refill();
+        let mut tokens = self.tokens.lock();
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Poll for a token until one is available or `deadline` elapses.
+    /// Returns how long the caller waited and whether it succeeded.
+    async fn acquire_within(&self, deadline: Duration) -> (Duration, bool) {
+        let start = Instant::now();
+
+        if self.try_acquire() {
+            return (Duration::ZERO, true);
+        }
+
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed >= deadline {
+                return (elapsed, false);
+            }
+
+            let poll_interval = Duration::from_millis(25).min(deadline - elapsed);
+            tokio::time::sleep(poll_interval).await;
+
+            if self.try_acquire() {
+                return (start.elapsed(), true);
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Admission Queue
+// =============================================================================
+
+/// Number of priority classes `PredictionRequest::priority` is bucketed
+/// into. Class `0` is most urgent (interactive); class `PRIORITY_CLASSES - 1`
+/// is least urgent (batch/background).
+const PRIORITY_CLASSES: usize = 4;
+
+/// How long a waiter can go without being admitted before it's promoted
+/// into the next more urgent priority class, so a steady stream of
+/// high-priority traffic can't starve out low-priority requests forever.
+const ADMISSION_AGING_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often a waiting request re-checks whether it can be admitted.
+const ADMISSION_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+struct AdmissionState {
+    in_flight: usize,
+    /// One FIFO queue of waiter ids per priority class; class 0 is served
+    /// first whenever it's non-empty.
+    waiters: [VecDeque<u64>; PRIORITY_CLASSES],
+    next_id: u64,
+}
+
+/// A bounded, priority-aware gate in front of `ProductionDSpyService::predict_internal`.
+///
+/// Up to `max_concurrent` predictions run at once; anything beyond that
+/// waits in a per-priority-class queue (depth exported via
+/// `ADMISSION_QUEUE_DEPTH`) instead of in arrival order, so a burst of
+/// low-priority batch requests can't delay interactive ones once
+/// concurrency is saturated. Waiters older than `ADMISSION_AGING_INTERVAL`
+/// are promoted into the next more urgent class so they still eventually
+/// run. Exceeding `max_queued` total waiters fails immediately with a
+/// `queue_full` error rather than growing the queue unboundedly.
+struct AdmissionQueue {
+    max_concurrent: usize,
+    max_queued: usize,
+    aging_interval: Duration,
+    state: Mutex<AdmissionState>,
+    notify: tokio::sync::Notify,
+}
+
+impl AdmissionQueue {
+    fn new(max_concurrent: usize, max_queued: usize) -> Self {
+        Self::with_aging_interval(max_concurrent, max_queued, ADMISSION_AGING_INTERVAL)
+    }
+
+    /// Like [`AdmissionQueue::new`], but with an explicit aging interval
+    /// instead of `ADMISSION_AGING_INTERVAL`, so starvation-protection tests
+    /// don't have to wait out the production interval in real time.
+    fn with_aging_interval(max_concurrent: usize, max_queued: usize, aging_interval: Duration) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            max_queued,
+            aging_interval,
+            state: Mutex::new(AdmissionState {
+                in_flight: 0,
+                waiters: [VecDeque::new(), VecDeque::new(), VecDeque::new(), VecDeque::new()],
+                next_id: 0,
+            }),
+            notify: tokio::sync::Notify::new(),
+        }
+    }
+
+    /// Reserve a concurrency slot for a request at `priority` (lower is
+    /// more urgent), waiting if necessary. Errors with a `queue_full`
+    /// message if the bounded waiting room is already at capacity.
+    async fn acquire(&self, priority: u8) -> Result<AdmissionGuard<'_>> {
+        let mut class = (priority as usize).min(PRIORITY_CLASSES - 1);
+
+        let id = {
+            let mut state = self.state.lock();
+
+            // Fast path: nobody's waiting and a slot is free.
+            if state.in_flight < self.max_concurrent && state.waiters.iter().all(VecDeque::is_empty) {
+                state.in_flight += 1;
+                return Ok(AdmissionGuard { queue: self });
+            }
+
+            let queued: usize = state.waiters.iter().map(VecDeque::len).sum();
+            if queued >= self.max_queued {
+                anyhow::bail!(
+                    "queue_full: admission queue is full ({queued} requests already waiting)"
+                );
+            }
+
+            let id = state.next_id;
+            state.next_id += 1;
+            state.waiters[class].push_back(id);
+            ADMISSION_QUEUE_DEPTH.with_label_values(&[&class.to_string()]).inc();
+            id
+        };
+
+        let mut class_entered_at = Instant::now();
+
+        loop {
+            {
+                let mut state = self.state.lock();
+
+                if Self::is_next_locked(&state, self.max_concurrent, class, id) {
+                    state.waiters[class].pop_front();
+                    state.in_flight += 1;
+                    ADMISSION_QUEUE_DEPTH.with_label_values(&[&class.to_string()]).dec();
+                    return Ok(AdmissionGuard { queue: self });
+                }
+
+                if class > 0 && class_entered_at.elapsed() >= self.aging_interval {
+                    if let Some(pos) = state.waiters[class].iter().position(|waiter| *waiter == id) {
+                        state.waiters[class].remove(pos);
+                        ADMISSION_QUEUE_DEPTH.with_label_values(&[&class.to_string()]).dec();
+                        class -= 1;
+                        state.waiters[class].push_back(id);
+                        ADMISSION_QUEUE_DEPTH.with_label_values(&[&class.to_string()]).inc();
+                        class_entered_at = Instant::now();
+                    }
+                }
+            }
+
+            tokio::select! {
+                _ = self.notify.notified() => {}
+                _ = tokio::time::sleep(ADMISSION_POLL_INTERVAL) => {}
+            }
+        }
+    }
+
+    /// Whether waiter `id` in `class` is the one that should be admitted
+    /// right now: a slot is free, and no more urgent class still has
+    /// anyone waiting.
+    fn is_next_locked(state: &AdmissionState, max_concurrent: usize, class: usize, id: u64) -> bool {
+        if state.in_flight >= max_concurrent {
+            return false;
+        }
+        state.waiters[..class].iter().all(VecDeque::is_empty) && state.waiters[class].front() == Some(&id)
+    }
+
+    fn release(&self) {
+        {
+            let mut state = self.state.lock();
+            state.in_flight = state.in_flight.saturating_sub(1);
+        }
+        // Wake every waiter so the newly-freed slot is picked up by the
+        // highest-priority one instead of waiting for the next poll tick.
+        self.notify.notify_waiters();
+    }
+}
+
+/// RAII guard for a slot reserved by `AdmissionQueue::acquire`. Releasing
+/// the slot on drop (including on panic or early return) wakes queued
+/// waiters immediately.
+struct AdmissionGuard<'a> {
+    queue: &'a AdmissionQueue,
+}
+
+impl Drop for AdmissionGuard<'_> {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
+
+/// RAII guard for the `ACTIVE_PREDICTIONS` gauge and the `active_predictions`
+/// shutdown-drain counter. Both are incremented against `model` on
+/// construction and decremented against that same `model` on drop —
+/// captured once up front rather than read back off the `predict_internal`
+/// result, since an `Err` has no model to read and previously caused the
+/// decrement to land on an empty-string label, leaking the real model's
+/// gauge series upward forever under sustained failures.
+struct ActivePredictionGuard<'a> {
+    service: &'a ProductionDSpyService,
+    model: String,
+}
+
+impl<'a> ActivePredictionGuard<'a> {
+    fn new(service: &'a ProductionDSpyService, model: String) -> Self {
+        ACTIVE_PREDICTIONS.with_label_values(&[&model]).inc();
+        service.active_predictions.fetch_add(1, Ordering::SeqCst);
+        Self { service, model }
+    }
+}
+
+impl Drop for ActivePredictionGuard<'_> {
+    fn drop(&mut self) {
+        ACTIVE_PREDICTIONS.with_label_values(&[&self.model]).dec();
+        self.service.active_predictions.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// =============================================================================
+// Error-Rate Circuit Breaker
+// =============================================================================
+
+/// Rolling-window failure-ratio tracker backing `CircuitBreakerMode::ErrorRate`.
+/// Wraps (rather than replaces) a model's existing failsafe `CircuitBreaker`:
+/// failsafe still executes and counts each call, but `predict_internal`
+/// consults this tracker first and rejects early once the ratio trips,
+/// since failsafe itself has no failure-ratio policy. Built the same way
+/// as `TokenBucket`: a `parking_lot::Mutex` around the mutable state, no
+/// background task, pruned lazily on access rather than on a timer.
+struct FailureRateWindow {
+    window: Duration,
+    outcomes: Mutex<VecDeque<(Instant, bool)>>,
+}
+
+impl FailureRateWindow {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            outcomes: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    fn record(&self, success: bool) {
+        let mut outcomes = self.outcomes.lock();
+        outcomes.push_back((Instant::now(), success));
+        Self::prune(&mut outcomes, self.window);
+    }
+
+    /// `(failure_ratio, request_count)` over the trailing `window`.
+    /// `failure_ratio` is `0.0` when the window currently holds no data.
+    fn stats(&self) -> (f64, usize) {
+        let mut outcomes = self.outcomes.lock();
+        Self::prune(&mut outcomes, self.window);
+        if outcomes.is_empty() {
+            return (0.0, 0);
+        }
+        let failures = outcomes.iter().filter(|(_, success)| !success).count();
+        (failures as f64 / outcomes.len() as f64, outcomes.len())
+    }
+
+    fn prune(outcomes: &mut VecDeque<(Instant, bool)>, window: Duration) {
+        let cutoff = Instant::now() - window;
+        while matches!(outcomes.front(), Some((at, _)) if *at < cutoff) {
+            outcomes.pop_front();
+        }
+    }
+}
+
+/// Whether `CircuitBreakerMode::ErrorRate`'s ratio-and-volume condition is
+/// currently met for `(failure_ratio, request_count)` as returned by
+/// `FailureRateWindow::stats`.
+fn error_rate_breaker_is_open((failure_ratio, request_count): (f64, usize), failure_rate_threshold: f64, min_requests: u32) -> bool {
+    request_count as u32 >= min_requests && failure_ratio >= failure_rate_threshold
+}
+
+/// `hits / (hits + misses)` for `CACHE_OPERATIONS{level}` since process
+/// start, or `0.0` when there's no data yet rather than `NaN`.
+fn cache_hit_ratio(level: &str) -> f64 {
+    let hits = CACHE_OPERATIONS.with_label_values(&[level, "hit"]).get();
+    let misses = CACHE_OPERATIONS.with_label_values(&[level, "miss"]).get();
+    let total = hits + misses;
+    if total == 0.0 {
+        0.0
+    } else {
+        hits / total
+    }
+}
+
 // =============================================================================
 // Data Types
 // =============================================================================
@@ -214,6 +1084,20 @@ pub struct PredictionRequest {
     pub parameters: HashMap<String, serde_json::Value>,
     #[serde(default)]
     pub use_cache: bool,
+
+    /// Scheduling priority: `0` is most urgent (interactive), higher values
+    /// are progressively less urgent (batch/background). Clamped into
+    /// `AdmissionQueue`'s priority classes; see `ProductionDSpyService::predict`.
+    #[serde(default)]
+    pub priority: u8,
+
+    /// Externally-supplied correlation id (e.g. from an upstream service's
+    /// own request tracing) recorded alongside `request_id` in the
+    /// `predict` tracing span, so logs for one logical request can be
+    /// joined across service boundaries. `None` when the caller doesn't
+    /// supply one.
+    #[serde(default)]
+    pub correlation_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -244,14 +1128,357 @@ struct CachedPrediction {
     timestamp: DateTime<Utc>,
 }
 
+/// A sentinel recording that the last attempt for this cache key failed
+/// with an error classified as deterministic (see `is_deterministic_error`)
+/// — the input itself is doomed to fail again, not just unlucky timing.
+/// Cached under `ServiceConfig::negative_cache_ttl_secs`, much shorter than
+/// `CachedPrediction`'s TTL, in its own `negative_cache` (see
+/// `ProductionDSpyService::negative_cache`) rather than alongside positive
+/// entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NegativeCacheEntry {
+    error: String,
+    timestamp: DateTime<Utc>,
+}
+
 // =============================================================================
-// Cost Tracking
+// Backend
 // =============================================================================
 
-#[derive(Debug)]
-pub struct CostTracker {
-    costs: Arc<RwLock<HashMap<String, CostMetrics>>>,
-}
+/// A single completion call, resolved down to just what a backend needs:
+/// no cache/circuit-breaker/cost-tracking concerns leak in.
+#[derive(Debug, Clone)]
+pub struct CompletionRequest {
+    pub model: String,
+    pub variant: String,
+    pub input: String,
+    /// Forwarded from `PredictionRequest::parameters`; passed to
+    /// `ModelConfig::python_callable` as keyword arguments.
+    pub parameters: HashMap<String, serde_json::Value>,
+    /// Forwarded from `ModelConfig::python_module`. `None` keeps
+    /// `PyO3Backend`'s placeholder behavior for models without Python
+    /// wiring configured yet.
+    pub python_module: Option<String>,
+    /// Forwarded from `ModelConfig::python_callable`.
+    pub python_callable: Option<String>,
+}
+
+/// The result of a single completion call.
+#[derive(Debug, Clone)]
+pub struct Completion {
+    pub output: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+}
+
+/// Abstracts the call that actually produces a prediction, so
+/// `ProductionDSpyService` can be exercised in tests (caching, circuit
+/// breaking, cost tracking) without a live Python/DSPy process. Production
+/// deployments use `PyO3Backend`; tests use `MockBackend`.
+///
+/// Errors that are deterministic for the given input — it will fail the
+/// same way on retry, e.g. the input was rejected by a content filter or
+/// exceeded a length limit — should be returned with a message starting
+/// with one of `DETERMINISTIC_ERROR_PREFIXES` (see `is_deterministic_error`)
+/// so `predict_internal` negatively caches them instead of re-dispatching
+/// the same doomed request. Anything else is treated as transient and is
+/// never negatively cached.
+#[async_trait::async_trait]
+pub trait Backend: Send + Sync {
+    async fn complete(&self, request: CompletionRequest) -> Result<Completion>;
+}
+
+/// Message prefixes a `Backend::complete` error must start with to be
+/// treated as deterministic by `is_deterministic_error`.
+const DETERMINISTIC_ERROR_PREFIXES: &[&str] = &["Input too long", "Content filter triggered"];
+
+/// Whether `error` is deterministic for its input — i.e. retrying the exact
+/// same request would fail again the same way — as opposed to transient
+/// (a backend outage, a timeout, a momentarily open circuit breaker), which
+/// might well succeed if retried later and must never be negatively cached.
+/// `"Response validation failed"` is deliberately excluded even though it's
+/// reproducible for a *fixed* backend output, since
+/// `dispatch_and_cache_prediction` already retries it a few times first, and a backend that's misbehaving
+/// this way may well stop on its own.
+fn is_deterministic_error(error: &anyhow::Error) -> bool {
+    let message = error.to_string();
+    DETERMINISTIC_ERROR_PREFIXES
+        .iter()
+        .any(|prefix| message.starts_with(prefix))
+}
+
+/// Production backend: calls into DSPy via PyO3.
+#[derive(Debug, Default)]
+pub struct PyO3Backend;
+
+impl PyO3Backend {
+    /// Converts a JSON parameter value into the equivalent Python object,
+    /// so `PredictionRequest::parameters` can be passed straight through as
+    /// keyword arguments to the configured `python_callable`.
+    fn json_value_to_py(py: Python<'_>, value: &serde_json::Value) -> PyObject {
+        match value {
+            serde_json::Value::Null => py.None(),
+            serde_json::Value::Bool(b) => b.into_py(py),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => i.into_py(py),
+                None => n.as_f64().unwrap_or_default().into_py(py),
+            },
+            serde_json::Value::String(s) => s.into_py(py),
+            serde_json::Value::Array(items) => items
+                .iter()
+                .map(|item| Self::json_value_to_py(py, item))
+                .collect::<Vec<_>>()
+                .into_py(py),
+            serde_json::Value::Object(map) => {
+                let dict = pyo3::types::PyDict::new_bound(py);
+                for (key, value) in map {
+                    let _ = dict.set_item(key, Self::json_value_to_py(py, value));
+                }
+                dict.into_py(py)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for PyO3Backend {
+    async fn complete(&self, request: CompletionRequest) -> Result<Completion> {
+        let (output, input_tokens, output_tokens) = Python::with_gil(|py| -> Result<(String, Option<u64>, Option<u64>)> {
+            let (Some(module_name), Some(callable_name)) =
+                (request.python_module.as_deref(), request.python_callable.as_deref())
+            else {
+                // No Python callable configured for this model yet —
+                // placeholder so the rest of the pipeline (caching,
+                // circuit breaking, cost tracking) is still exercisable.
+                let result = format!(
+                    "Prediction for input '{}' using model {} ({})",
+                    request.input, request.model, request.variant
+                );
+                return Ok((result, None, None));
+            };
+
+            let module = pyo3::types::PyModule::import_bound(py, module_name)
+                .with_context(|| format!("Failed to import Python module '{}'", module_name))?;
+            let callable = module
+                .getattr(callable_name)
+                .with_context(|| format!("Python module '{}' has no attribute '{}'", module_name, callable_name))?;
+
+            let kwargs = pyo3::types::PyDict::new_bound(py);
+            for (key, value) in &request.parameters {
+                kwargs.set_item(key, Self::json_value_to_py(py, value))?;
+            }
+
+            let result = callable
+                .call((request.input.as_str(),), Some(&kwargs))
+                .with_context(|| format!("Python callable '{}.{}' raised", module_name, callable_name))?;
+
+            if let Ok(text) = result.extract::<String>() {
+                return Ok((text, None, None));
+            }
+
+            let output: String = result
+                .getattr("output")
+                .or_else(|_| result.getattr("completion"))
+                .context("Python callable's return value has neither .output nor .completion")?
+                .extract()
+                .context("Python callable's .output/.completion is not a string")?;
+            let input_tokens = result.getattr("input_tokens").ok().and_then(|v| v.extract::<u64>().ok());
+            let output_tokens = result.getattr("output_tokens").ok().and_then(|v| v.extract::<u64>().ok());
+
+            Ok((output, input_tokens, output_tokens))
+        })?;
+
+        // Fall back to a length-based heuristic only for whichever count
+        // the Python side didn't give us.
+        let input_tokens = input_tokens.unwrap_or((request.input.len() / 4) as u64);
+        let output_tokens = output_tokens.unwrap_or((output.len() / 4) as u64);
+
+        Ok(Completion {
+            output,
+            input_tokens,
+            output_tokens,
+        })
+    }
+}
+
+/// Test backend returning scripted responses keyed by input, falling back to
+/// a default completion for any unscripted input. Records every request it
+/// receives so tests can assert on what was actually dispatched.
+pub struct MockBackend {
+    responses: HashMap<String, Completion>,
+    errors: HashMap<String, String>,
+    default: Completion,
+    calls: Mutex<Vec<CompletionRequest>>,
+    delay: Option<Duration>,
+}
+
+impl MockBackend {
+    pub fn new() -> Self {
+        Self {
+            responses: HashMap::new(),
+            errors: HashMap::new(),
+            default: Completion {
+                output: "mock output".to_string(),
+                input_tokens: 10,
+                output_tokens: 10,
+            },
+            calls: Mutex::new(Vec::new()),
+            delay: None,
+        }
+    }
+
+    /// Script a response for a specific input string.
+    pub fn with_response(mut self, input: impl Into<String>, completion: Completion) -> Self {
+        self.responses.insert(input.into(), completion);
+        self
+    }
+
+    /// Script a failure for a specific input string.
+    pub fn with_error(mut self, input: impl Into<String>, message: impl Into<String>) -> Self {
+        self.errors.insert(input.into(), message.into());
+        self
+    }
+
+    /// Override the completion returned for inputs with no scripted response.
+    pub fn with_default(mut self, completion: Completion) -> Self {
+        self.default = completion;
+        self
+    }
+
+    /// Sleep for `delay` on every call before responding, so tests can
+    /// simulate a slow backend (e.g. for exercising hedged requests).
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// All requests this backend has received, in order.
+    pub fn calls(&self) -> Vec<CompletionRequest> {
+        self.calls.lock().clone()
+    }
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl Backend for MockBackend {
+    async fn complete(&self, request: CompletionRequest) -> Result<Completion> {
+        self.calls.lock().push(request.clone());
+
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        if let Some(message) = self.errors.get(&request.input) {
+            anyhow::bail!("{}", message);
+        }
+
+        Ok(self
+            .responses
+            .get(&request.input)
+            .cloned()
+            .unwrap_or_else(|| self.default.clone()))
+    }
+}
+
+// =============================================================================
+// Response Validation
+// =============================================================================
+
+/// Checks a backend's raw output before it's trusted for caching and
+/// counted as a circuit-breaker success. A rejection is treated as a
+/// prediction failure: it feeds the circuit breaker, skips caching, and is
+/// retried up to the model's `max_retries` like any other transient error.
+pub trait ResponseValidator: Send + Sync {
+    /// Returns `Ok(())` for an acceptable output, `Err(reason)` otherwise.
+    fn validate(&self, output: &str) -> Result<(), String>;
+}
+
+/// Rejects empty or whitespace-only outputs.
+#[derive(Debug, Default)]
+pub struct NonEmptyValidator;
+
+impl ResponseValidator for NonEmptyValidator {
+    fn validate(&self, output: &str) -> Result<(), String> {
+        if output.trim().is_empty() {
+            Err("output is empty".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Rejects outputs that aren't valid JSON matching a configured JSON Schema.
+pub struct JsonShapeValidator {
+    schema: serde_json::Value,
+}
+
+impl JsonShapeValidator {
+    pub fn new(schema: serde_json::Value) -> Self {
+        Self { schema }
+    }
+}
+
+impl ResponseValidator for JsonShapeValidator {
+    fn validate(&self, output: &str) -> Result<(), String> {
+        let instance: serde_json::Value =
+            serde_json::from_str(output).map_err(|e| format!("output is not valid JSON: {}", e))?;
+
+        let compiled = jsonschema::JSONSchema::compile(&self.schema)
+            .map_err(|e| format!("invalid validator schema: {}", e))?;
+
+        if let Err(errors) = compiled.validate(&instance) {
+            let messages: Vec<String> = errors.map(|e| e.to_string()).collect();
+            return Err(format!("output does not match schema: {}", messages.join("; ")));
+        }
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Cost Tracking
+// =============================================================================
+
+#[derive(Debug)]
+pub struct CostTracker {
+    // Sharded by `DashMap` so concurrent `record_prediction` calls for
+    // different model/variant keys don't contend on one lock; within a key,
+    // the integer counters are atomic and only `cost` (an `f64`, which has
+    // no atomic type) takes a short-lived per-entry lock.
+    costs: Arc<DashMap<String, CostCounters>>,
+}
+
+#[derive(Debug, Default)]
+struct CostCounters {
+    total_requests: AtomicU64,
+    total_input_tokens: AtomicU64,
+    total_output_tokens: AtomicU64,
+    cost: Mutex<CostAccumulator>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct CostAccumulator {
+    total_cost_usd: f64,
+    last_updated: Option<DateTime<Utc>>,
+}
+
+impl CostCounters {
+    fn to_metrics(&self) -> CostMetrics {
+        let cost = self.cost.lock();
+        CostMetrics {
+            total_requests: self.total_requests.load(Ordering::Relaxed),
+            total_input_tokens: self.total_input_tokens.load(Ordering::Relaxed),
+            total_output_tokens: self.total_output_tokens.load(Ordering::Relaxed),
+            total_cost_usd: cost.total_cost_usd,
+            last_updated: cost.last_updated,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct CostMetrics {
@@ -265,7 +1492,7 @@ pub struct CostMetrics {
 impl CostTracker {
     pub fn new() -> Self {
         Self {
-            costs: Arc::new(RwLock::new(HashMap::new())),
+            costs: Arc::new(DashMap::new()),
         }
     }
 
@@ -278,14 +1505,16 @@ impl CostTracker {
         cost: f64,
     ) {
         let key = format!("{}:{}", model, variant);
-        let mut costs = self.costs.write();
-        let metrics = costs.entry(key).or_default();
+        let counters = self.costs.entry(key).or_default();
 
-        metrics.total_requests += 1;
-        metrics.total_input_tokens += input_tokens;
-        metrics.total_output_tokens += output_tokens;
-        metrics.total_cost_usd += cost;
-        metrics.last_updated = Some(Utc::now());
+        counters.total_requests.fetch_add(1, Ordering::Relaxed);
+        counters.total_input_tokens.fetch_add(input_tokens, Ordering::Relaxed);
+        counters.total_output_tokens.fetch_add(output_tokens, Ordering::Relaxed);
+        {
+            let mut acc = counters.cost.lock();
+            acc.total_cost_usd += cost;
+            acc.last_updated = Some(Utc::now());
+        }
 
         // Update Prometheus metrics
         TOKEN_USAGE
@@ -301,11 +1530,29 @@ impl CostTracker {
 
     pub fn get_metrics(&self, model: &str, variant: &str) -> Option<CostMetrics> {
         let key = format!("{}:{}", model, variant);
-        self.costs.read().get(&key).cloned()
+        self.costs.get(&key).map(|counters| counters.to_metrics())
     }
 
     pub fn get_all_metrics(&self) -> HashMap<String, CostMetrics> {
-        self.costs.read().clone()
+        self.costs
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().to_metrics()))
+            .collect()
+    }
+
+    /// Total cost accumulated across all variants of a single model.
+    pub fn model_cost(&self, model: &str) -> f64 {
+        let prefix = format!("{}:", model);
+        self.costs
+            .iter()
+            .filter(|entry| entry.key().starts_with(&prefix))
+            .map(|entry| entry.value().to_metrics().total_cost_usd)
+            .sum()
+    }
+
+    /// Total cost accumulated across every model and variant.
+    pub fn total_cost(&self) -> f64 {
+        self.costs.iter().map(|entry| entry.value().to_metrics().total_cost_usd).sum()
     }
 }
 
@@ -315,6 +1562,199 @@ impl Default for CostTracker {
     }
 }
 
+// =============================================================================
+// Redaction
+// =============================================================================
+
+lazy_static! {
+    static ref EMAIL_PATTERN: Regex = Regex::new(r"[\w.+-]+@[\w-]+\.[\w.-]+").unwrap();
+    static ref CREDIT_CARD_PATTERN: Regex = Regex::new(r"\b(?:\d[ -]?){13,16}\b").unwrap();
+    static ref PHONE_PATTERN: Regex =
+        Regex::new(r"\b(?:\+?1[ -]?)?\(?\d{3}\)?[ -]?\d{3}[ -]?\d{4}\b").unwrap();
+}
+
+/// Strips PII-shaped substrings out of text before it leaves the hot path
+/// for a log line or the audit log. Cache keys are always derived from the
+/// original, unredacted `PredictionRequest.input` (see
+/// `ProductionDSpyService::make_cache_key`), so redaction never affects
+/// cache hit/miss behavior — only what gets written down for humans to read.
+pub trait Redactor: Send + Sync {
+    fn redact(&self, text: &str) -> String;
+}
+
+/// Default `Redactor`: masks email addresses, credit-card numbers, and
+/// phone numbers with a fixed placeholder. This is pattern-matching, not a
+/// PII classifier — it catches the common shapes, not everything that could
+/// identify someone.
+#[derive(Debug, Default)]
+pub struct RegexRedactor;
+
+impl Redactor for RegexRedactor {
+    fn redact(&self, text: &str) -> String {
+        let redacted = EMAIL_PATTERN.replace_all(text, "[REDACTED_EMAIL]");
+        let redacted = CREDIT_CARD_PATTERN.replace_all(&redacted, "[REDACTED_CARD]");
+        PHONE_PATTERN.replace_all(&redacted, "[REDACTED_PHONE]").into_owned()
+    }
+}
+
+/// No-op `Redactor` used when `ServiceConfig::redaction_enabled` is `false`.
+#[derive(Debug, Default)]
+pub struct NoopRedactor;
+
+impl Redactor for NoopRedactor {
+    fn redact(&self, text: &str) -> String {
+        text.to_string()
+    }
+}
+
+// =============================================================================
+// Audit Log
+// =============================================================================
+
+/// A single compliance-audit record for one completed prediction request.
+/// `input` and `output` have already passed through the service's
+/// `Redactor` by the time they land here.
+#[derive(Debug, Clone, Serialize)]
+struct AuditRecord {
+    timestamp: DateTime<Utc>,
+    request_id: String,
+    model: String,
+    variant: String,
+    status: String,
+    input: String,
+    output: Option<String>,
+    input_tokens: Option<u64>,
+    output_tokens: Option<u64>,
+    cost_usd: Option<f64>,
+    cached: Option<bool>,
+    error: Option<String>,
+}
+
+/// Background task that owns the audit log file and serializes writes off
+/// the prediction hot path. Flushes after every record and, as a backstop,
+/// on a fixed interval in case the channel goes quiet with buffered writes
+/// still pending.
+async fn audit_log_writer(
+    path: String,
+    mut rx: mpsc::UnboundedReceiver<AuditRecord>,
+) {
+    let file = match tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .await
+    {
+        Ok(file) => file,
+        Err(e) => {
+            error!(path = %path, error = %e, "Failed to open audit log file; audit logging disabled");
+            return;
+        }
+    };
+
+    let mut writer = TokioBufWriter::new(file);
+    let mut flush_interval = tokio::time::interval(Duration::from_secs(5));
+
+    loop {
+        tokio::select! {
+            record = rx.recv() => {
+                match record {
+                    Some(record) => {
+                        match serde_json::to_string(&record) {
+                            Ok(line) => {
+                                if let Err(e) = writer.write_all(line.as_bytes()).await {
+                                    error!(error = %e, "Failed to write audit log record");
+                                    continue;
+                                }
+                                let _ = writer.write_all(b"\n").await;
+                                if let Err(e) = writer.flush().await {
+                                    warn!(error = %e, "Failed to flush audit log");
+                                }
+                            }
+                            Err(e) => error!(error = %e, "Failed to serialize audit log record"),
+                        }
+                    }
+                    None => break,
+                }
+            }
+            _ = flush_interval.tick() => {
+                if let Err(e) = writer.flush().await {
+                    warn!(error = %e, "Failed to flush audit log on interval");
+                }
+            }
+        }
+    }
+
+    let _ = writer.flush().await;
+}
+
+/// Retries establishing the Redis connection while it's `None` — e.g.
+/// because Redis was unreachable when the service started — so the service
+/// transparently regains Redis caching once Redis comes back, instead of
+/// running memory-cache-only until a full restart. `check_cache` and
+/// `store_in_cache` read `redis_conn` fresh on every call, so they pick up
+/// the restored connection as soon as this task installs it; `health()`
+/// flips `redis_connected` back to `true` the same way.
+///
+/// Backs off exponentially between attempts, doubling from
+/// `REDIS_RECONNECT_INITIAL_BACKOFF` up to `max_backoff`, so a prolonged
+/// outage doesn't spam reconnect attempts. Exits once connected: a
+/// `ConnectionManager` that's already up reconnects on its own after
+/// momentary drops (see `check_cache`'s retry-loop comment), so there's
+/// nothing left for this task to do once the initial connection succeeds.
+async fn redis_reconnector(
+    redis_client: redis::Client,
+    redis_conn: Arc<tokio::sync::Mutex<Option<ConnectionManager>>>,
+    max_backoff: Duration,
+) {
+    let mut backoff = REDIS_RECONNECT_INITIAL_BACKOFF;
+
+    loop {
+        {
+            let conn_guard = redis_conn.lock().await;
+            if conn_guard.is_some() {
+                return;
+            }
+        }
+
+        match ConnectionManager::new(redis_client.clone()).await {
+            Ok(conn) => {
+                let mut conn_guard = redis_conn.lock().await;
+                if conn_guard.is_none() {
+                    info!("Redis connection re-established");
+                    *conn_guard = Some(conn);
+                }
+                return;
+            }
+            Err(e) => {
+                warn!(error = %e, backoff_secs = backoff.as_secs_f64(), "Redis reconnect attempt failed; backing off");
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(max_backoff);
+            }
+        }
+    }
+}
+
+/// Periodically samples the Redis key count via `DBSIZE` and publishes it as
+/// `dspy_redis_cache_keys`, so cache occupancy is visible even though moka's
+/// eviction listener only covers the in-process tier.
+async fn redis_cache_size_sampler(redis_conn: Arc<tokio::sync::Mutex<Option<ConnectionManager>>>) {
+    let mut sample_interval = tokio::time::interval(REDIS_CACHE_SIZE_SAMPLE_INTERVAL);
+
+    loop {
+        sample_interval.tick().await;
+
+        let mut conn_guard = redis_conn.lock().await;
+        let Some(conn) = conn_guard.as_mut() else {
+            continue;
+        };
+
+        match redis::cmd("DBSIZE").query_async::<_, i64>(conn).await {
+            Ok(count) => REDIS_CACHE_KEYS.set(count as f64),
+            Err(e) => warn!(error = %e, "Redis DBSIZE sample failed"),
+        }
+    }
+}
+
 // =============================================================================
 // Health Check
 // =============================================================================
@@ -328,6 +1768,20 @@ pub struct HealthStatus {
     pub python_initialized: bool,
     pub cache_size: u64,
     pub circuit_breakers: HashMap<String, String>,
+    /// Remaining global cost budget in USD, per `ServiceConfig::cost_budget`.
+    /// `None` when no budget is configured.
+    pub cost_budget_remaining_usd: Option<f64>,
+    /// Memory-cache hits divided by (hits + misses) on `CACHE_OPERATIONS{level="memory"}`
+    /// since process start. `0.0` when there have been no memory cache lookups yet.
+    pub memory_cache_hit_ratio: f64,
+    /// Same as `memory_cache_hit_ratio`, but for `CACHE_OPERATIONS{level="redis"}`.
+    /// `0.0` when Redis isn't configured or hasn't been queried yet.
+    pub redis_cache_hit_ratio: f64,
+    /// Failures recorded per model since process start (see
+    /// `ProductionDSpyService::record_circuit_outcome`). Meant to let
+    /// operators alert on a model trending toward its breaker's
+    /// `circuit_breaker_failure_threshold` before it actually opens.
+    pub failure_counts: HashMap<String, u64>,
 }
 
 // =============================================================================
@@ -336,18 +1790,67 @@ pub struct HealthStatus {
 
 pub struct ProductionDSpyService {
     config: ServiceConfig,
+    backend: Box<dyn Backend>,
     memory_cache: Cache<String, CachedPrediction>,
+    negative_cache: Cache<String, NegativeCacheEntry>,
     redis_client: redis::Client,
     redis_conn: Arc<tokio::sync::Mutex<Option<ConnectionManager>>>,
     circuit_breakers: Arc<RwLock<HashMap<String, Arc<CircuitBreaker>>>>,
+    /// Failures recorded per model since process start, regardless of
+    /// `CircuitBreakerMode` - failsafe's `CircuitBreaker` trait exposes
+    /// `.state()` but no failure count, so this is tracked independently in
+    /// `record_circuit_outcome` and surfaced via `HealthStatus::failure_counts`.
+    failure_counts: Arc<RwLock<HashMap<String, AtomicU64>>>,
+    error_rate_windows: Arc<RwLock<HashMap<String, Arc<FailureRateWindow>>>>,
+    rate_limiters: Arc<RwLock<HashMap<String, Arc<TokenBucket>>>>,
+    admission_queue: Arc<AdmissionQueue>,
     cost_tracker: Arc<CostTracker>,
+    audit_tx: Option<mpsc::UnboundedSender<AuditRecord>>,
     start_time: Instant,
+    shutting_down: Arc<AtomicBool>,
+    active_predictions: Arc<AtomicI64>,
+    response_validator: Option<Box<dyn ResponseValidator>>,
+    redactor: Arc<dyn Redactor>,
+    /// Per-cache-key in-flight computation, used by
+    /// `dispatch_with_single_flight` when `ServiceConfig::single_flight_enabled`
+    /// is set. Entries are removed as soon as the computation they guard
+    /// resolves - this map only ever holds groups that are *currently*
+    /// being computed, never a cache of past results.
+    single_flight: Arc<DashMap<String, Arc<tokio::sync::OnceCell<Result<PredictionResponse, String>>>>>,
+    /// RNG backing weighted A/B variant selection in `resolve_variant`.
+    /// Seeded once at construction and reused across calls rather than
+    /// reseeding per-request, so draws stay independent instead of
+    /// correlating with system-clock resolution.
+    variant_rng: Mutex<rand::rngs::StdRng>,
+}
+
+/// Outcome of a call to `ProductionDSpyService::shutdown`. `completed` is
+/// how many of the predictions in flight when `shutdown` was called
+/// finished before `grace` elapsed; `abandoned` is how many were still
+/// running when the grace period ran out and were left to finish on their
+/// own. `cache_entries_persisted` is `None` when no Redis connection is
+/// configured, or `Some(n)` (`n` may be `0`) with how many memory-cache
+/// entries were written to Redis for the next pod to start warm.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ShutdownSummary {
+    pub completed: i64,
+    pub abandoned: i64,
+    pub cache_entries_persisted: Option<usize>,
 }
 
 impl ProductionDSpyService {
-    /// Create a new production service
+    /// Create a new production service backed by live Python/DSPy (`PyO3Backend`).
     #[instrument(skip(config))]
     pub async fn new(config: ServiceConfig) -> Result<Self> {
+        Self::new_with_backend(config, Box::new(PyO3Backend)).await
+    }
+
+    /// Create a new production service with an explicit backend. Production
+    /// code should use [`ProductionDSpyService::new`]; this exists so tests
+    /// can swap in a [`MockBackend`] and exercise caching, circuit breaking,
+    /// and cost tracking deterministically.
+    #[instrument(skip(config, backend))]
+    pub async fn new_with_backend(config: ServiceConfig, backend: Box<dyn Backend>) -> Result<Self> {
         info!(
             service_name = %config.service_name,
             service_version = %config.service_version,
@@ -358,10 +1861,18 @@ impl ProductionDSpyService {
         pyo3::prepare_freethreaded_python();
         info!("Python interpreter initialized");
 
-        // Build memory cache
+        // Build memory cache. The eviction listener only counts entries moka
+        // dropped on its own (expiry or size pressure, via `was_evicted()`);
+        // explicit removals and replacements aren't cache pressure and
+        // shouldn't inflate the eviction counter.
         let memory_cache = Cache::builder()
             .max_capacity(config.memory_cache_size)
             .time_to_live(Duration::from_secs(config.memory_cache_ttl_secs))
+            .eviction_listener(|_key, _value, cause| {
+                if cause.was_evicted() {
+                    MEMORY_CACHE_EVICTIONS_TOTAL.inc();
+                }
+            })
             .build();
         info!(
             size = config.memory_cache_size,
@@ -369,6 +1880,15 @@ impl ProductionDSpyService {
             "Memory cache initialized"
         );
 
+        // Separate, short-lived cache for deterministic prediction errors
+        // (see `is_deterministic_error`), keyed the same way as
+        // `memory_cache` but kept apart so its own much shorter TTL doesn't
+        // require a per-entry expiry policy on the positive cache.
+        let negative_cache = Cache::builder()
+            .max_capacity(config.memory_cache_size)
+            .time_to_live(Duration::from_secs(config.negative_cache_ttl_secs))
+            .build();
+
         // Connect to Redis
         let redis_client = redis::Client::open(config.redis_url.clone())
             .context("Failed to create Redis client")?;
@@ -384,64 +1904,193 @@ impl ProductionDSpyService {
             }
         };
 
-        // Initialize circuit breakers for each model
+        // Initialize circuit breakers for each model, using that model's own
+        // ModelConfig::circuit_breaker_* overrides when set and falling back
+        // to the global ServiceConfig values otherwise.
         let mut circuit_breakers = HashMap::new();
-        for (model_key, _) in &config.models {
+        let mut failure_counts = HashMap::new();
+        let mut error_rate_windows = HashMap::new();
+        for (model_key, model_config) in &config.models {
+            let failure_threshold = model_config
+                .circuit_breaker_failure_threshold
+                .unwrap_or(config.circuit_breaker_failure_threshold);
+            let success_threshold = model_config
+                .circuit_breaker_success_threshold
+                .unwrap_or(config.circuit_breaker_success_threshold);
+            let timeout_secs = model_config
+                .circuit_breaker_timeout_secs
+                .unwrap_or(config.circuit_breaker_timeout_secs);
+
             let cb_config = CircuitConfig::new()
-                .failure_threshold(config.circuit_breaker_failure_threshold)
-                .success_threshold(config.circuit_breaker_success_threshold)
-                .timeout(Duration::from_secs(config.circuit_breaker_timeout_secs));
+                .failure_threshold(failure_threshold)
+                .success_threshold(success_threshold)
+                .timeout(Duration::from_secs(timeout_secs));
 
             let circuit_breaker = Arc::new(CircuitBreaker::new(cb_config));
             circuit_breakers.insert(model_key.clone(), circuit_breaker);
+            failure_counts.insert(model_key.clone(), AtomicU64::new(0));
+
+            if let CircuitBreakerMode::ErrorRate { window_secs, .. } = &config.circuit_breaker_mode {
+                error_rate_windows.insert(
+                    model_key.clone(),
+                    Arc::new(FailureRateWindow::new(Duration::from_secs(*window_secs))),
+                );
+            }
 
             info!(
                 model = %model_key,
-                failure_threshold = config.circuit_breaker_failure_threshold,
+                failure_threshold,
                 "Circuit breaker initialized"
             );
         }
 
+        // Initialize rate limiters for models with max_rpm configured
+        let mut rate_limiters = HashMap::new();
+        for (model_key, model_config) in &config.models {
+            if let Some(max_rpm) = model_config.max_rpm {
+                rate_limiters.insert(model_key.clone(), Arc::new(TokenBucket::new(max_rpm)));
+                info!(model = %model_key, max_rpm, "Rate limiter initialized");
+            }
+        }
+
+        let admission_queue = Arc::new(AdmissionQueue::new(
+            config.max_concurrent_predictions,
+            config.max_queued_predictions,
+        ));
+
         let cost_tracker = Arc::new(CostTracker::new());
 
+        let audit_tx = if let Some(path) = &config.audit_log_path {
+            let (tx, rx) = mpsc::unbounded_channel();
+            tokio::spawn(audit_log_writer(path.clone(), rx));
+            info!(path = %path, "Audit logging enabled");
+            Some(tx)
+        } else {
+            None
+        };
+
+        let redis_conn = Arc::new(tokio::sync::Mutex::new(redis_conn));
+        tokio::spawn(redis_cache_size_sampler(redis_conn.clone()));
+        tokio::spawn(redis_reconnector(
+            redis_client.clone(),
+            redis_conn.clone(),
+            Duration::from_secs(config.redis_reconnect_max_backoff_secs),
+        ));
+
+        let redactor: Arc<dyn Redactor> = if config.redaction_enabled {
+            Arc::new(RegexRedactor)
+        } else {
+            Arc::new(NoopRedactor)
+        };
+
         Ok(Self {
             config,
+            backend,
             memory_cache,
+            negative_cache,
             redis_client,
-            redis_conn: Arc::new(tokio::sync::Mutex::new(redis_conn)),
+            redis_conn,
             circuit_breakers: Arc::new(RwLock::new(circuit_breakers)),
+            failure_counts: Arc::new(RwLock::new(failure_counts)),
+            error_rate_windows: Arc::new(RwLock::new(error_rate_windows)),
+            rate_limiters: Arc::new(RwLock::new(rate_limiters)),
+            admission_queue,
             cost_tracker,
+            audit_tx,
             start_time: Instant::now(),
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            active_predictions: Arc::new(AtomicI64::new(0)),
+            response_validator: None,
+            redactor,
+            single_flight: Arc::new(DashMap::new()),
+            variant_rng: Mutex::new(rand::rngs::StdRng::from_entropy()),
         })
     }
 
+    /// Validate every backend response with `validator` before it's cached
+    /// or counted as a circuit-breaker success.
+    pub fn with_response_validator(mut self, validator: Box<dyn ResponseValidator>) -> Self {
+        self.response_validator = Some(validator);
+        self
+    }
+
     /// Make a prediction with full production features
-    #[instrument(skip(self, request), fields(request_id = %request.request_id, model = %request.model))]
+    #[instrument(
+        skip(self, request),
+        fields(
+            request_id = %request.request_id,
+            model = %request.model,
+            correlation_id = request.correlation_id.as_deref().unwrap_or("")
+        )
+    )]
     pub async fn predict(&self, request: PredictionRequest) -> Result<PredictionResponse> {
         let start = Instant::now();
-        let variant = request
-            .variant
-            .clone()
-            .unwrap_or_else(|| self.config.default_variant.clone());
+        let variant = self.resolve_variant(&request.model, request.variant.as_deref());
+        let request_id = request.request_id.clone();
+        let model = request.model.clone();
+        let redacted_input = self.redactor.redact(&request.input);
 
         info!(
             request_id = %request.request_id,
             model = %request.model,
             variant = %variant,
+            input = %redacted_input,
             "Processing prediction request"
         );
 
-        // Increment active predictions gauge
-        ACTIVE_PREDICTIONS
-            .with_label_values(&[&request.model])
-            .inc();
+        if self.shutting_down.load(Ordering::SeqCst) {
+            ERRORS_TOTAL
+                .with_label_values(&[&request.model, "service_unavailable"])
+                .inc();
+            let result = Err(anyhow::anyhow!(
+                "Service is shutting down; rejecting new request"
+            ));
+            self.record_audit(&request.request_id, &request.model, &variant, &redacted_input, &result);
+            return result;
+        }
+
+        if let Some(model_config) = self.config.models.get(&request.model) {
+            if let Err(e) = self.validate_parameters(model_config, &request.parameters) {
+                ERRORS_TOTAL
+                    .with_label_values(&[&request.model, "invalid_parameters"])
+                    .inc();
+                let result = Err(e);
+                self.record_audit(&request.request_id, &request.model, &variant, &redacted_input, &result);
+                return result;
+            }
+        }
+
+        if let Some(e) = self.check_budget(&request.model) {
+            let result = Err(e);
+            self.record_audit(&request.request_id, &request.model, &variant, &redacted_input, &result);
+            return result;
+        }
+
+        // Wait for an admission slot, honoring priority, before dispatching
+        // to the backend. Held until `predict_internal` finishes so the
+        // admitted concurrency actually reflects in-flight backend calls.
+        let _admission_guard = match self.admission_queue.acquire(request.priority).await {
+            Ok(guard) => guard,
+            Err(e) => {
+                ERRORS_TOTAL
+                    .with_label_values(&[&request.model, "queue_full"])
+                    .inc();
+                let result = Err(e);
+                self.record_audit(&request.request_id, &request.model, &variant, &redacted_input, &result);
+                return result;
+            }
+        };
+
+        // Tracks this request against `model` (captured once, above) for the
+        // whole time it's in flight, regardless of how `predict_internal`
+        // returns. Dropped explicitly right after `predict_internal`
+        // finishes so the gauge reflects in-flight backend calls, same as
+        // `_admission_guard` above.
+        let active_guard = ActivePredictionGuard::new(self, model.clone());
 
         let result = self.predict_internal(request, variant.clone()).await;
 
-        // Decrement active predictions gauge
-        ACTIVE_PREDICTIONS
-            .with_label_values(&[&result.as_ref().map(|r| r.model.as_str()).unwrap_or("")])
-            .dec();
+        drop(active_guard);
 
         match &result {
             Ok(response) => {
@@ -478,9 +2127,65 @@ impl ProductionDSpyService {
             }
         }
 
+        self.record_audit(&request_id, &model, &variant, &redacted_input, &result);
+
         result
     }
 
+    /// Hand a completed prediction's outcome off to the audit log writer
+    /// task over an unbounded channel, so a slow or backed-up disk never
+    /// adds latency to the prediction hot path. A no-op when audit
+    /// logging isn't configured. `redacted_input` has already passed
+    /// through `self.redactor`; the output is redacted here, right before
+    /// it's written down.
+    fn record_audit(
+        &self,
+        request_id: &str,
+        model: &str,
+        variant: &str,
+        redacted_input: &str,
+        result: &Result<PredictionResponse>,
+    ) {
+        let Some(tx) = &self.audit_tx else {
+            return;
+        };
+
+        let record = match result {
+            Ok(response) => AuditRecord {
+                timestamp: Utc::now(),
+                request_id: request_id.to_string(),
+                model: model.to_string(),
+                variant: variant.to_string(),
+                status: "success".to_string(),
+                input: redacted_input.to_string(),
+                output: Some(self.redactor.redact(&response.output)),
+                input_tokens: Some(response.metadata.input_tokens),
+                output_tokens: Some(response.metadata.output_tokens),
+                cost_usd: Some(response.metadata.cost_usd),
+                cached: Some(response.metadata.cached),
+                error: None,
+            },
+            Err(e) => AuditRecord {
+                timestamp: Utc::now(),
+                request_id: request_id.to_string(),
+                model: model.to_string(),
+                variant: variant.to_string(),
+                status: "error".to_string(),
+                input: redacted_input.to_string(),
+                output: None,
+                input_tokens: None,
+                output_tokens: None,
+                cost_usd: None,
+                cached: None,
+                error: Some(e.to_string()),
+            },
+        };
+
+        if tx.send(record).is_err() {
+            warn!("Audit log writer task is gone; dropping audit record");
+        }
+    }
+
     async fn predict_internal(
         &self,
         request: PredictionRequest,
@@ -490,7 +2195,7 @@ impl ProductionDSpyService {
 
         // Check cache if enabled
         if request.use_cache {
-            if let Some(cached) = self.check_cache(&request).await? {
+            if let Some(cached) = self.check_cache(&request, &variant).await? {
                 info!(
                     request_id = %request.request_id,
                     cache_level = %cached.1,
@@ -525,6 +2230,39 @@ impl ProductionDSpyService {
                     },
                 });
             }
+
+            if let Some(negative) = self.check_negative_cache(&request, &variant).await? {
+                info!(
+                    request_id = %request.request_id,
+                    "Negative cache hit; rejecting without dispatching to backend"
+                );
+                anyhow::bail!("{}", negative.error);
+            }
+        }
+
+        if request.use_cache && self.config.single_flight_enabled {
+            return self.dispatch_with_single_flight(request, variant, start).await;
+        }
+
+        self.dispatch_and_cache_prediction(request, variant, start).await
+    }
+
+    /// Dispatch `request` to the backend and cache the result on success.
+    /// This is everything `predict_internal` does after a cache miss - budget
+    /// check, circuit breaker, rate limiting, retries, cost accounting, and
+    /// writing the result back to cache - factored out so
+    /// `dispatch_with_single_flight` can share it as the computation a
+    /// single-flight "leader" runs on behalf of every concurrent follower.
+    async fn dispatch_and_cache_prediction(
+        &self,
+        request: PredictionRequest,
+        variant: String,
+        start: Instant,
+    ) -> Result<PredictionResponse> {
+        // Only reached on a cache miss, so this never blocks a request that
+        // costs nothing.
+        if let Some(e) = self.check_budget_limits(&request.model) {
+            return Err(e);
         }
 
         // Get circuit breaker
@@ -536,43 +2274,158 @@ impl ProductionDSpyService {
                 .context("Circuit breaker not found for model")?
         };
 
-        // Update circuit breaker state metric
-        let state_value = match circuit_breaker.state() {
-            failsafe::State::Closed => 0.0,
-            failsafe::State::Open => 1.0,
-            failsafe::State::HalfOpen => 2.0,
-        };
-        CIRCUIT_BREAKER_STATE
-            .with_label_values(&[&request.model])
-            .set(state_value);
+        // Update circuit breaker state metric. In `ErrorRate` mode the
+        // gauge reflects the rolling-window tracker's own open/closed
+        // computation instead of failsafe's consecutive-failures state,
+        // since that's the policy actually gating calls for this model.
+        if let CircuitBreakerMode::ErrorRate {
+            failure_rate_threshold,
+            min_requests,
+            ..
+        } = &self.config.circuit_breaker_mode
+        {
+            let window = self.error_rate_windows.read().get(&request.model).cloned();
+            if let Some(window) = window {
+                let is_open = error_rate_breaker_is_open(window.stats(), *failure_rate_threshold, *min_requests);
+                CIRCUIT_BREAKER_STATE
+                    .with_label_values(&[&request.model])
+                    .set(if is_open { 1.0 } else { 0.0 });
 
-        // Execute prediction with circuit breaker
-        let prediction_result = circuit_breaker
-            .call(|| self.execute_prediction(&request, &variant))
-            .await;
-
-        let (output, input_tokens, output_tokens) = match prediction_result {
-            Ok(result) => result,
-            Err(CircuitError::Inner(e)) => {
-                ERRORS_TOTAL
-                    .with_label_values(&[&request.model, "execution_error"])
-                    .inc();
-                return Err(e);
-            }
-            Err(CircuitError::Rejected) => {
-                ERRORS_TOTAL
-                    .with_label_values(&[&request.model, "circuit_breaker_open"])
-                    .inc();
-                anyhow::bail!("Circuit breaker open for model {}", request.model);
+                if is_open {
+                    ERRORS_TOTAL
+                        .with_label_values(&[&request.model, "circuit_breaker_open"])
+                        .inc();
+                    anyhow::bail!("Circuit breaker open (error rate) for model {}", request.model);
+                }
             }
-        };
+        } else {
+            let state_value = match circuit_breaker.state() {
+                failsafe::State::Closed => 0.0,
+                failsafe::State::Open => 1.0,
+                failsafe::State::HalfOpen => 2.0,
+            };
+            CIRCUIT_BREAKER_STATE
+                .with_label_values(&[&request.model])
+                .set(state_value);
+        }
 
-        // Calculate cost
+        // Model config is needed both for the rate limit deadline and, on
+        // success, for cost calculation.
         let model_config = self
             .config
             .models
             .get(&request.model)
             .context("Model not configured")?;
+
+        // Enforce the per-model rate limit before dispatching to the
+        // circuit breaker.
+        let rate_limiter = {
+            let limiters = self.rate_limiters.read();
+            limiters.get(&request.model).cloned()
+        };
+
+        if let Some(limiter) = rate_limiter.as_ref() {
+            match self.config.rate_limit_behavior {
+                RateLimitBehavior::Wait => {
+                    let deadline = Duration::from_secs(model_config.request_timeout_secs);
+                    let (waited, acquired) = limiter.acquire_within(deadline).await;
+
+                    if waited > Duration::ZERO {
+                        RATE_LIMIT_WAIT
+                            .with_label_values(&[&request.model])
+                            .observe(waited.as_secs_f64());
+                    }
+
+                    if !acquired {
+                        ERRORS_TOTAL
+                            .with_label_values(&[&request.model, "rate_limited"])
+                            .inc();
+                        anyhow::bail!(
+                            "Rate limit exceeded for model {} (waited {:?})",
+                            request.model,
+                            waited
+                        );
+                    }
+                }
+                RateLimitBehavior::Reject => {
+                    if !limiter.try_acquire() {
+                        ERRORS_TOTAL
+                            .with_label_values(&[&request.model, "rate_limited"])
+                            .inc();
+                        anyhow::bail!("Rate limit exceeded for model {}", request.model);
+                    }
+                }
+            }
+        }
+
+        // Execute prediction with circuit breaker, retrying response
+        // validation failures up to `max_retries` times. Other failures
+        // (backend errors, open breaker) are not retried here.
+        let mut attempt: u32 = 0;
+        let (output, input_tokens, output_tokens) = loop {
+            let prediction_result = self
+                .execute_prediction_with_hedge(&circuit_breaker, &request, &variant, model_config, rate_limiter.as_ref())
+                .await;
+
+            // `Rejected` means the circuit was already open and the
+            // backend was never called, so it doesn't carry any
+            // information about the model's error rate.
+            match &prediction_result {
+                Ok(_) => self.record_circuit_outcome(&request.model, true),
+                Err(CircuitError::Inner(_)) => self.record_circuit_outcome(&request.model, false),
+                Err(CircuitError::Rejected) => {}
+            }
+
+            match prediction_result {
+                Ok(result) => break result,
+                Err(CircuitError::Inner(e)) => {
+                    let is_invalid_response = e.to_string().starts_with("Response validation failed");
+                    if is_invalid_response && attempt < model_config.max_retries {
+                        attempt += 1;
+                        warn!(
+                            request_id = %request.request_id,
+                            model = %request.model,
+                            attempt,
+                            error = %e,
+                            "Retrying after invalid response"
+                        );
+                        continue;
+                    }
+                    ERRORS_TOTAL
+                        .with_label_values(&[
+                            &request.model,
+                            if is_invalid_response {
+                                "invalid_response"
+                            } else {
+                                "execution_error"
+                            },
+                        ])
+                        .inc();
+
+                    if request.use_cache && is_deterministic_error(&e) {
+                        self.store_negative_cache(
+                            &request,
+                            &NegativeCacheEntry {
+                                error: e.to_string(),
+                                timestamp: Utc::now(),
+                            },
+                            &variant,
+                        )
+                        .await;
+                    }
+
+                    return Err(e);
+                }
+                Err(CircuitError::Rejected) => {
+                    ERRORS_TOTAL
+                        .with_label_values(&[&request.model, "circuit_breaker_open"])
+                        .inc();
+                    anyhow::bail!("Circuit breaker open for model {}", request.model);
+                }
+            }
+        };
+
+        // Calculate cost
         let cost = self.calculate_cost(model_config, input_tokens, output_tokens);
 
         // Record cost
@@ -592,7 +2445,7 @@ impl ProductionDSpyService {
                 output_tokens,
                 timestamp: Utc::now(),
             };
-            self.store_in_cache(&request, &cached).await?;
+            self.store_in_cache(&request, &cached, &variant).await?;
         }
 
         Ok(PredictionResponse {
@@ -612,42 +2465,329 @@ impl ProductionDSpyService {
         })
     }
 
+    /// Deduplicates concurrent cache-miss requests that share the same
+    /// cache key: only the first ("leader") actually runs
+    /// `dispatch_and_cache_prediction`; every other concurrent request for
+    /// the same key ("follower") awaits the leader's result instead of
+    /// independently dispatching to the backend. See
+    /// `ServiceConfig::single_flight_enabled` for the trade-off this
+    /// implies - a follower's `PredictionResponse` mirrors the leader's
+    /// output/tokens/cost/latency rather than its own; only `request_id` is
+    /// corrected back to the follower's own before returning.
+    async fn dispatch_with_single_flight(
+        &self,
+        request: PredictionRequest,
+        variant: String,
+        start: Instant,
+    ) -> Result<PredictionResponse> {
+        let cache_key = self.make_cache_key(&request, &variant);
+        let cell = self
+            .single_flight
+            .entry(cache_key.clone())
+            .or_insert_with(|| Arc::new(tokio::sync::OnceCell::new()))
+            .clone();
+
+        let is_leader = !cell.initialized();
+        let request_id = request.request_id.clone();
+        let result = cell
+            .get_or_init(|| async { self.dispatch_and_cache_prediction(request, variant, start).await.map_err(|e| e.to_string()) })
+            .await
+            .clone();
+
+        // Only a caller that found the cell still uninitialized (the leader,
+        // or a follower that joined while the computation was running)
+        // removes it, and only if it's still the same group - a follower
+        // racing in just after this group resolved and just before it's
+        // removed would otherwise have its own, newer group torn out from
+        // under it.
+        if is_leader {
+            self.single_flight
+                .remove_if(&cache_key, |_, v| Arc::ptr_eq(v, &cell));
+        }
+
+        match result {
+            Ok(mut response) => {
+                response.request_id = request_id;
+                Ok(response)
+            }
+            Err(e) => Err(anyhow::anyhow!(e)),
+        }
+    }
+
+    /// Races `execute_prediction` against a hedged backup attempt, per
+    /// `model_config.hedge_after_ms`: if the primary hasn't returned by
+    /// then, a second attempt is dispatched and whichever returns first is
+    /// served. Both attempts go through the same circuit breaker as a
+    /// normal call. The backup is only dispatched if the breaker isn't
+    /// already open and the rate limiter has a spare token right now
+    /// (checked without waiting), so hedging never adds load during an
+    /// incident — it just falls back to waiting out the primary attempt.
+    ///
+    /// When `model_config.hedge_cost_both` is `false` (the default), the
+    /// loser is dropped as soon as the winner returns. When `true`, the
+    /// loser is awaited afterward purely to record its cost too, which
+    /// means a hedged request's caller-visible latency becomes the slower
+    /// of the two attempts instead of the faster one in that mode.
+    async fn execute_prediction_with_hedge(
+        &self,
+        circuit_breaker: &Arc<CircuitBreaker>,
+        request: &PredictionRequest,
+        variant: &str,
+        model_config: &ModelConfig,
+        rate_limiter: Option<&Arc<TokenBucket>>,
+    ) -> std::result::Result<(String, u64, u64), CircuitError<anyhow::Error>> {
+        let Some(hedge_after_ms) = model_config.hedge_after_ms else {
+            return circuit_breaker.call(|| self.execute_prediction(request, variant, model_config)).await;
+        };
+
+        let primary = circuit_breaker.call(|| self.execute_prediction(request, variant, model_config));
+        tokio::pin!(primary);
+
+        tokio::select! {
+            result = &mut primary => return result,
+            _ = tokio::time::sleep(Duration::from_millis(hedge_after_ms)) => {}
+        }
+
+        let breaker_is_open = matches!(circuit_breaker.state(), failsafe::State::Open);
+        let rate_limit_ok = rate_limiter.map(|limiter| limiter.try_acquire()).unwrap_or(true);
+
+        if breaker_is_open || !rate_limit_ok {
+            return primary.await;
+        }
+
+        HEDGED_REQUESTS_TOTAL
+            .with_label_values(&[&request.model])
+            .inc();
+
+        let backup = circuit_breaker.call(|| self.execute_prediction(request, variant, model_config));
+        tokio::pin!(backup);
+
+        tokio::select! {
+            primary_result = &mut primary => {
+                if model_config.hedge_cost_both {
+                    let backup_result = backup.await;
+                    self.record_hedge_loser_cost(model_config, variant, backup_result);
+                }
+                primary_result
+            }
+            backup_result = &mut backup => {
+                if model_config.hedge_cost_both {
+                    let primary_result = primary.await;
+                    self.record_hedge_loser_cost(model_config, variant, primary_result);
+                }
+                backup_result
+            }
+        }
+    }
+
+    /// Accounts cost for a hedged attempt that lost the race but is still
+    /// worth billing for, per `ModelConfig::hedge_cost_both`. A no-op if
+    /// the losing attempt itself errored.
+    fn record_hedge_loser_cost(
+        &self,
+        model_config: &ModelConfig,
+        variant: &str,
+        loser_result: std::result::Result<(String, u64, u64), CircuitError<anyhow::Error>>,
+    ) {
+        if let Ok((_, input_tokens, output_tokens)) = loser_result {
+            let cost = self.calculate_cost(model_config, input_tokens, output_tokens);
+            self.cost_tracker
+                .record_prediction(&model_config.name, variant, input_tokens, output_tokens, cost);
+        }
+    }
+
+    /// Dispatches to `self.backend`, retrying with backoff when a single
+    /// attempt doesn't return within `model_config.request_timeout_secs`,
+    /// up to `model_config.max_retries` additional attempts. Each timeout
+    /// increments `ERRORS_TOTAL{error_type="timeout"}`; the final timeout
+    /// (no retries left) is returned as an `Err` containing "timeout", so
+    /// it's surfaced to the caller the same way any other backend failure
+    /// is - including counting as a circuit-breaker failure, since this is
+    /// called from inside `circuit_breaker.call` in
+    /// `execute_prediction_with_hedge`. Only timeouts are retried here;
+    /// other backend errors (e.g. a deterministic content-filter
+    /// rejection) are returned immediately, and response-validation
+    /// failures are retried one layer up, in `dispatch_and_cache_prediction`.
+    #[instrument(skip(self, request, model_config), fields(request_id = %request.request_id))]
     async fn execute_prediction(
         &self,
         request: &PredictionRequest,
         variant: &str,
+        model_config: &ModelConfig,
     ) -> Result<(String, u64, u64)> {
         debug!(
             request_id = %request.request_id,
             model = %request.model,
             variant = %variant,
-            "Executing Python prediction"
+            "Executing prediction"
         );
 
-        // Execute Python code
-        let output = Python::with_gil(|py| {
-            // Simulate DSpy prediction
-            // In production, this would call actual DSpy code
-            let result = format!(
-                "Prediction for input '{}' using model {} ({})",
-                request.input, request.model, variant
-            );
+        let timeout = Duration::from_secs(model_config.request_timeout_secs);
+        let completion_request = CompletionRequest {
+            model: request.model.clone(),
+            variant: variant.to_string(),
+            input: request.input.clone(),
+            parameters: request.parameters.clone(),
+            python_module: model_config.python_module.clone(),
+            python_callable: model_config.python_callable.clone(),
+        };
 
-            Ok::<String, anyhow::Error>(result)
-        })?;
+        let mut attempt = 0;
+        let completion = loop {
+            match tokio::time::timeout(timeout, self.backend.complete(completion_request.clone())).await {
+                Ok(result) => break result?,
+                Err(_) => {
+                    ERRORS_TOTAL.with_label_values(&[&request.model, "timeout"]).inc();
+
+                    if attempt < model_config.max_retries {
+                        attempt += 1;
+                        warn!(
+                            request_id = %request.request_id,
+                            model = %request.model,
+                            attempt,
+                            timeout_secs = timeout.as_secs(),
+                            "Backend call timed out; retrying"
+                        );
+                        tokio::time::sleep(BACKEND_RETRY_BACKOFF * attempt).await;
+                        continue;
+                    }
+
+                    anyhow::bail!(
+                        "Request timeout calling backend for model {} after {} attempt(s)",
+                        request.model,
+                        attempt + 1
+                    );
+                }
+            }
+        };
 
-        // Simulate token counting
-        let input_tokens = (request.input.len() / 4) as u64;
-        let output_tokens = (output.len() / 4) as u64;
+        if let Some(validator) = &self.response_validator {
+            if let Err(reason) = validator.validate(&completion.output) {
+                anyhow::bail!("Response validation failed: {}", reason);
+            }
+        }
 
         debug!(
             request_id = %request.request_id,
-            input_tokens = input_tokens,
-            output_tokens = output_tokens,
+            input_tokens = completion.input_tokens,
+            output_tokens = completion.output_tokens,
             "Prediction executed"
         );
 
-        Ok((output, input_tokens, output_tokens))
+        Ok((completion.output, completion.input_tokens, completion.output_tokens))
+    }
+
+    /// Validate `parameters` against the model's `parameters_schema`, if
+    /// one is configured. A missing schema skips validation entirely so
+    /// existing configs keep working unchanged.
+    fn validate_parameters(
+        &self,
+        model_config: &ModelConfig,
+        parameters: &HashMap<String, serde_json::Value>,
+    ) -> Result<()> {
+        let Some(schema) = &model_config.parameters_schema else {
+            return Ok(());
+        };
+
+        let compiled = jsonschema::JSONSchema::compile(schema)
+            .map_err(|e| anyhow::anyhow!("Invalid parameters_schema for model {}: {}", model_config.name, e))?;
+
+        let instance = serde_json::to_value(parameters).context("Failed to serialize parameters")?;
+
+        if let Err(errors) = compiled.validate(&instance) {
+            let messages: Vec<String> = errors.map(|e| e.to_string()).collect();
+            anyhow::bail!("Invalid parameters: {}", messages.join("; "));
+        }
+
+        Ok(())
+    }
+
+    /// Check the configured `cost_budget` against cost accumulated so far.
+    /// Returns `Some(error)` when either the model's own cumulative cost or
+    /// the cost across all models combined is over budget; `None` when
+    /// there's no configured budget or the request is within it.
+    fn check_budget(&self, model: &str) -> Option<anyhow::Error> {
+        let budget = self.config.cost_budget.as_ref()?;
+
+        let model_cost = self.cost_tracker.model_cost(model);
+        if budget.is_daily_exceeded(model_cost) {
+            BUDGET_EXCEEDED_TOTAL.with_label_values(&[model, "model"]).inc();
+            ERRORS_TOTAL.with_label_values(&[model, "budget_exceeded"]).inc();
+            return Some(anyhow::anyhow!(
+                "Cost budget exceeded for model {} (${:.4} of ${:.4})",
+                model,
+                model_cost,
+                budget.daily_limit_usd
+            ));
+        }
+
+        let global_cost = self.cost_tracker.total_cost();
+        if budget.is_monthly_exceeded(global_cost) {
+            BUDGET_EXCEEDED_TOTAL.with_label_values(&[model, "global"]).inc();
+            ERRORS_TOTAL.with_label_values(&[model, "budget_exceeded"]).inc();
+            return Some(anyhow::anyhow!(
+                "Global cost budget exceeded (${:.4} of ${:.4})",
+                global_cost,
+                budget.monthly_limit_usd
+            ));
+        }
+
+        None
+    }
+
+    /// Check `ServiceConfig::budget_limits` for `model` against its
+    /// accumulated cost metrics. Returns `Some(error)` once the model's
+    /// cumulative cost crosses either its daily or monthly cap; `None` when
+    /// there's no configured limit for this model or it's still within
+    /// both. Unlike `check_budget`, which gates every request up front
+    /// (including cache hits), this is only checked on a cache miss — see
+    /// `predict_internal` — since a cache hit spends nothing.
+    fn check_budget_limits(&self, model: &str) -> Option<anyhow::Error> {
+        let limit = self.config.budget_limits.as_ref()?.models.get(model)?;
+        let model_cost = self.cost_tracker.model_cost(model);
+
+        if model_cost >= limit.daily_limit_usd {
+            ERRORS_TOTAL.with_label_values(&[model, "budget_exceeded"]).inc();
+            return Some(anyhow::anyhow!(
+                "Per-model budget exceeded for model {} (${:.4} of daily ${:.4})",
+                model,
+                model_cost,
+                limit.daily_limit_usd
+            ));
+        }
+
+        if model_cost >= limit.monthly_limit_usd {
+            ERRORS_TOTAL.with_label_values(&[model, "budget_exceeded"]).inc();
+            return Some(anyhow::anyhow!(
+                "Per-model budget exceeded for model {} (${:.4} of monthly ${:.4})",
+                model,
+                model_cost,
+                limit.monthly_limit_usd
+            ));
+        }
+
+        None
+    }
+
+    /// Feed a prediction's outcome into the model's `failure_counts` entry
+    /// and, when `CircuitBreakerMode::ErrorRate` is configured and a window
+    /// was built for this model, its `FailureRateWindow`. The window update
+    /// is a no-op in `ConsecutiveFailures` mode, where failsafe's own
+    /// breaker already tracked this outcome via `.call(..)`; `failure_counts`
+    /// is updated unconditionally, since failsafe's `CircuitBreaker` trait
+    /// exposes no failure count of its own for `HealthStatus` to read.
+    fn record_circuit_outcome(&self, model: &str, success: bool) {
+        if !success {
+            if let Some(counter) = self.failure_counts.read().get(model) {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let windows = self.error_rate_windows.read();
+        let Some(window) = windows.get(model) else {
+            return;
+        };
+        window.record(success);
     }
 
     fn calculate_cost(&self, model_config: &ModelConfig, input_tokens: u64, output_tokens: u64) -> f64 {
@@ -656,21 +2796,188 @@ impl ProductionDSpyService {
         input_cost + output_cost
     }
 
-    fn make_cache_key(&self, request: &PredictionRequest) -> String {
+    /// Resolves the effective variant for a request: `requested` wins
+    /// whenever the caller supplied one explicitly. Otherwise, when
+    /// `ServiceConfig::ab_testing_enabled` is set and `model` has a
+    /// non-empty `ModelConfig::variants` weight table, draws a variant by
+    /// weighted random from `variant_rng`; any other case (A/B testing
+    /// off, model not configured, or an empty/all-zero weight table)
+    /// falls back to `ServiceConfig::default_variant`, matching prior
+    /// behavior for configs that don't use weighted variants.
+    fn resolve_variant(&self, model: &str, requested: Option<&str>) -> String {
+        if let Some(variant) = requested {
+            return variant.to_string();
+        }
+
+        if self.config.ab_testing_enabled {
+            if let Some(model_config) = self.config.models.get(model) {
+                if !model_config.variants.is_empty() {
+                    return self.pick_weighted_variant(&model_config.variants);
+                }
+            }
+        }
+
+        self.config.default_variant.clone()
+    }
+
+    /// Weighted-random pick from a `{variant: weight}` table. Variant names
+    /// are sorted first so the draw sequence from `variant_rng` is
+    /// reproducible given the same RNG state, regardless of the
+    /// `HashMap`'s iteration order. Falls back to `default_variant` if the
+    /// weights are all zero/negative (`WeightedIndex::new` rejects that).
+    fn pick_weighted_variant(&self, variants: &HashMap<String, f64>) -> String {
+        let mut names: Vec<&String> = variants.keys().collect();
+        names.sort();
+        let weights: Vec<f64> = names.iter().map(|name| variants[*name].max(0.0)).collect();
+
+        match WeightedIndex::new(&weights) {
+            Ok(dist) => {
+                let idx = dist.sample(&mut *self.variant_rng.lock());
+                names[idx].clone()
+            }
+            Err(_) => self.config.default_variant.clone(),
+        }
+    }
+
+    /// Cache key schema: `dspy:cache:{model}:{digest}`, where `digest` is a
+    /// hex-encoded `DefaultHasher` digest of `(model, request.input,
+    /// variant, canonical_parameters)`, `canonical_parameters` is
+    /// `request.parameters` re-serialized as JSON with its keys sorted (via
+    /// a `BTreeMap`), so the same parameter set always hashes the same way
+    /// regardless of the `HashMap`'s iteration order. `variant` must be the
+    /// *effective* variant (with `ServiceConfig::default_variant` already
+    /// substituted for `None`), not `request.variant` directly —
+    /// otherwise an explicit `Some("baseline")` and an implicit default
+    /// `None` for the same variant would hash differently and miss each
+    /// other's cache entries. Hashing `variant` and `parameters` in means
+    /// two requests that only differ in one of those (e.g. an A/B test's
+    /// baseline vs. treatment) get distinct cache entries instead of
+    /// silently sharing one. The leading `dspy:cache:{model}:` namespace
+    /// isn't part of the hash input, just a prefix — it's what lets
+    /// `purge_model` find every key for a model with a single Redis `SCAN
+    /// MATCH` instead of hashing every possible input.
+    fn make_cache_key(&self, request: &PredictionRequest, variant: &str) -> String {
         use std::collections::hash_map::DefaultHasher;
+        use std::collections::BTreeMap;
         use std::hash::{Hash, Hasher};
 
+        let canonical_parameters: BTreeMap<&String, &serde_json::Value> =
+            request.parameters.iter().collect();
+
         let mut hasher = DefaultHasher::new();
         request.model.hash(&mut hasher);
         request.input.hash(&mut hasher);
-        format!("{:x}", hasher.finish())
+        variant.hash(&mut hasher);
+        serde_json::to_string(&canonical_parameters)
+            .unwrap_or_default()
+            .hash(&mut hasher);
+        format!("dspy:cache:{}:{:x}", request.model, hasher.finish())
+    }
+
+    /// Remove the cache entry for `(model, input, variant, parameters)` from
+    /// both the memory cache and Redis, so the next matching request is a
+    /// guaranteed miss. `variant` is the *effective* variant - pass `None`
+    /// to mean `ServiceConfig::default_variant`, same as
+    /// `PredictionRequest::variant`. `parameters` must match the
+    /// `PredictionRequest::parameters` the entry was cached with -
+    /// `make_cache_key` hashes them in, so passing the wrong (or an empty)
+    /// map recomputes a different key and silently fails to find an entry
+    /// that was actually cached with non-empty parameters. Returns how many
+    /// of the two cache levels actually had the entry (`0`, `1`, or `2`).
+    /// Intended for dropping stale predictions after a prompt template
+    /// changes, without restarting the service.
+    pub async fn invalidate(
+        &self,
+        model: &str,
+        input: &str,
+        variant: Option<&str>,
+        parameters: HashMap<String, serde_json::Value>,
+    ) -> Result<usize> {
+        let effective_variant = variant.unwrap_or(&self.config.default_variant);
+        let cache_key = self.make_cache_key(
+            &PredictionRequest {
+                request_id: String::new(),
+                model: model.to_string(),
+                variant: None,
+                input: input.to_string(),
+                parameters,
+                use_cache: true,
+                priority: 0,
+                correlation_id: None,
+            },
+            effective_variant,
+        );
+
+        let mut removed = 0usize;
+        if self.memory_cache.remove(&cache_key).await.is_some() {
+            removed += 1;
+        }
+
+        let mut conn_guard = self.redis_conn.lock().await;
+        if let Some(conn) = conn_guard.as_mut() {
+            let deleted: u64 = conn.del(&cache_key).await?;
+            if deleted > 0 {
+                removed += 1;
+            }
+        }
+
+        debug!(cache_key = %cache_key, removed, "Invalidated cache entry");
+        Ok(removed)
+    }
+
+    /// Delete every cached prediction for `model` across both cache
+    /// levels, using the `dspy:cache:{model}:` prefix `make_cache_key`
+    /// gives every entry for that model. Memory cache entries are dropped
+    /// by scanning the in-process cache (cheap; it's bounded by
+    /// `ServiceConfig::memory_cache_size`); Redis entries are found via
+    /// `SCAN MATCH` rather than `KEYS`, since `KEYS` blocks the whole Redis
+    /// instance on a large keyspace. Returns the total number of entries
+    /// removed across both levels.
+    pub async fn purge_model(&self, model: &str) -> Result<usize> {
+        let prefix = format!("dspy:cache:{}:", model);
+        let mut removed = 0usize;
+
+        let memory_keys: Vec<String> = self
+            .memory_cache
+            .iter()
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, _)| (*key).clone())
+            .collect();
+        for key in &memory_keys {
+            self.memory_cache.invalidate(key).await;
+        }
+        removed += memory_keys.len();
+
+        let mut conn_guard = self.redis_conn.lock().await;
+        if let Some(conn) = conn_guard.as_mut() {
+            use futures::StreamExt;
+
+            let pattern = format!("{}*", prefix);
+            let mut redis_keys = Vec::new();
+            {
+                let mut iter: redis::AsyncIter<'_, String> = conn.scan_match(&pattern).await?;
+                while let Some(key) = iter.next().await {
+                    redis_keys.push(key);
+                }
+            }
+
+            for key in &redis_keys {
+                let deleted: u64 = conn.del(key).await?;
+                removed += deleted as usize;
+            }
+        }
+
+        info!(model, removed, "Purged model cache entries");
+        Ok(removed)
     }
 
+    #[instrument(skip(self, request), fields(request_id = %request.request_id))]
     async fn check_cache(
         &self,
         request: &PredictionRequest,
+        variant: &str,
     ) -> Result<Option<(CachedPrediction, String)>> {
-        let cache_key = self.make_cache_key(request);
+        let cache_key = self.make_cache_key(request, variant);
 
         // Check memory cache first
         if let Some(cached) = self.memory_cache.get(&cache_key).await {
@@ -684,12 +2991,36 @@ impl ProductionDSpyService {
             .with_label_values(&["memory", "miss"])
             .inc();
 
-        // Check Redis cache
+        // Check Redis cache. `Ok(None)` is a real miss (key doesn't exist);
+        // `Err(_)` is a connection-level problem worth a couple of retries
+        // before falling back to treating it as a miss.
         let mut conn_guard = self.redis_conn.lock().await;
         if let Some(conn) = conn_guard.as_mut() {
-            match conn.get::<_, String>(&cache_key).await {
-                Ok(data) => {
-                    if let Ok(cached) = serde_json::from_str::<CachedPrediction>(&data) {
+            let mut outcome = None;
+            for attempt in 1..=REDIS_MAX_ATTEMPTS {
+                match conn.get::<_, Option<Vec<u8>>>(&cache_key).await {
+                    Ok(value) => {
+                        outcome = Some(value);
+                        break;
+                    }
+                    Err(e) => {
+                        warn!(cache_key = %cache_key, attempt, error = %e, "Redis GET failed; retrying");
+                        if attempt < REDIS_MAX_ATTEMPTS {
+                            tokio::time::sleep(REDIS_RETRY_BACKOFF * attempt).await;
+                        }
+                    }
+                }
+            }
+
+            match outcome {
+                Some(Some(data)) => {
+                    // `maybe_decompress_from_redis` reads the leading
+                    // compression marker, then `decode` reads the leading
+                    // codec marker underneath it, so entries written under
+                    // any previous `compress_threshold_bytes`/`cache_codec`
+                    // setting stay readable through a migration window.
+                    let decoded = maybe_decompress_from_redis(&data).and_then(|payload| CacheCodec::decode::<CachedPrediction>(&payload));
+                    if let Ok(cached) = decoded {
                         // Store in memory cache for future hits
                         self.memory_cache
                             .insert(cache_key.clone(), cached.clone())
@@ -702,19 +3033,29 @@ impl ProductionDSpyService {
                         return Ok(Some((cached, "redis".to_string())));
                     }
                 }
-                Err(_) => {
+                Some(None) => {
                     CACHE_OPERATIONS
                         .with_label_values(&["redis", "miss"])
                         .inc();
                 }
+                None => {
+                    // Every attempt errored; the connection manager handles
+                    // auto-reconnect on its own, so just log and degrade to
+                    // a miss rather than failing the request.
+                    warn!(cache_key = %cache_key, "Redis GET failed after {} attempts", REDIS_MAX_ATTEMPTS);
+                    CACHE_OPERATIONS
+                        .with_label_values(&["redis", "error"])
+                        .inc();
+                }
             }
         }
 
         Ok(None)
     }
 
-    async fn store_in_cache(&self, request: &PredictionRequest, cached: &CachedPrediction) -> Result<()> {
-        let cache_key = self.make_cache_key(request);
+    #[instrument(skip(self, request, cached), fields(request_id = %request.request_id))]
+    async fn store_in_cache(&self, request: &PredictionRequest, cached: &CachedPrediction, variant: &str) -> Result<()> {
+        let cache_key = self.make_cache_key(request, variant);
 
         // Store in memory cache
         self.memory_cache
@@ -722,25 +3063,119 @@ impl ProductionDSpyService {
             .await;
         debug!(cache_key = %cache_key, "Stored in memory cache");
 
-        // Store in Redis cache
+        // Store in Redis cache, retrying momentary connection errors a
+        // couple of times before giving up on this write.
         let mut conn_guard = self.redis_conn.lock().await;
         if let Some(conn) = conn_guard.as_mut() {
-            let data = serde_json::to_string(cached)?;
-            let ttl_secs = self.config.redis_cache_ttl_secs as usize;
+            let data = maybe_compress_for_redis(
+                &self.config.cache_codec.encode(cached)?,
+                self.config.compress_threshold_bytes,
+            )?;
+            let ttl_secs = jittered_ttl_secs(
+                self.config.redis_cache_ttl_secs,
+                self.config.redis_cache_ttl_jitter_percent,
+            );
 
-            if let Err(e) = conn
-                .set_ex::<_, _, ()>(&cache_key, data, ttl_secs)
-                .await
-            {
-                warn!(error = %e, "Failed to store in Redis cache");
-            } else {
+            let mut succeeded = false;
+            for attempt in 1..=REDIS_MAX_ATTEMPTS {
+                match conn.set_ex::<_, _, ()>(&cache_key, data.clone(), ttl_secs).await {
+                    Ok(()) => {
+                        succeeded = true;
+                        break;
+                    }
+                    Err(e) => {
+                        warn!(cache_key = %cache_key, attempt, error = %e, "Redis SET failed; retrying");
+                        if attempt < REDIS_MAX_ATTEMPTS {
+                            tokio::time::sleep(REDIS_RETRY_BACKOFF * attempt).await;
+                        }
+                    }
+                }
+            }
+
+            if succeeded {
                 debug!(cache_key = %cache_key, ttl_secs = ttl_secs, "Stored in Redis cache");
+            } else {
+                warn!(cache_key = %cache_key, "Redis SET failed after {} attempts", REDIS_MAX_ATTEMPTS);
             }
         }
 
         Ok(())
     }
 
+    /// Looks up `request` in the negative cache (see `NegativeCacheEntry`).
+    /// A hit means the last attempt for this exact key failed with an error
+    /// classified as deterministic, and is recent enough that it's still
+    /// within `ServiceConfig::negative_cache_ttl_secs` — the request is
+    /// rejected again immediately with the cached error, rather than
+    /// re-dispatching to a backend and circuit breaker that will only fail
+    /// the same way.
+    #[instrument(skip(self, request), fields(request_id = %request.request_id))]
+    async fn check_negative_cache(&self, request: &PredictionRequest, variant: &str) -> Result<Option<NegativeCacheEntry>> {
+        let cache_key = self.negative_cache_key(request, variant);
+
+        if let Some(entry) = self.negative_cache.get(&cache_key).await {
+            CACHE_OPERATIONS
+                .with_label_values(&["memory", "negative_hit"])
+                .inc();
+            debug!(cache_key = %cache_key, "Negative cache hit (memory)");
+            return Ok(Some(entry));
+        }
+
+        let mut conn_guard = self.redis_conn.lock().await;
+        if let Some(conn) = conn_guard.as_mut() {
+            if let Ok(Some(data)) = conn.get::<_, Option<Vec<u8>>>(&cache_key).await {
+                if let Ok(entry) = CacheCodec::decode::<NegativeCacheEntry>(&data) {
+                    self.negative_cache.insert(cache_key.clone(), entry.clone()).await;
+                    CACHE_OPERATIONS
+                        .with_label_values(&["redis", "negative_hit"])
+                        .inc();
+                    debug!(cache_key = %cache_key, "Negative cache hit (redis)");
+                    return Ok(Some(entry));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Records that `request` just failed with a deterministic error, so
+    /// `check_negative_cache` can short-circuit identical requests until
+    /// `ServiceConfig::negative_cache_ttl_secs` elapses. A separate cache
+    /// (and, in Redis, a separate key namespace via `negative_cache_key`)
+    /// from `store_in_cache`'s, since it carries its own much shorter TTL.
+    /// Best-effort: a Redis write failure here just means the negative
+    /// cache falls back to memory-only for this entry, not a failed
+    /// request.
+    #[instrument(skip(self, request), fields(request_id = %request.request_id))]
+    async fn store_negative_cache(&self, request: &PredictionRequest, entry: &NegativeCacheEntry, variant: &str) {
+        let cache_key = self.negative_cache_key(request, variant);
+
+        self.negative_cache.insert(cache_key.clone(), entry.clone()).await;
+        debug!(cache_key = %cache_key, "Stored in negative cache (memory)");
+
+        let mut conn_guard = self.redis_conn.lock().await;
+        if let Some(conn) = conn_guard.as_mut() {
+            let ttl_secs = self.config.negative_cache_ttl_secs;
+            match self.config.cache_codec.encode(entry) {
+                Ok(data) => {
+                    if let Err(e) = conn.set_ex::<_, _, ()>(&cache_key, data, ttl_secs).await {
+                        warn!(cache_key = %cache_key, error = %e, "Redis SET failed for negative cache entry");
+                    } else {
+                        debug!(cache_key = %cache_key, ttl_secs, "Stored in negative cache (redis)");
+                    }
+                }
+                Err(e) => warn!(cache_key = %cache_key, error = %e, "Failed to encode negative cache entry"),
+            }
+        }
+    }
+
+    /// `make_cache_key`'s key, namespaced so negative entries never collide
+    /// with (or get misread as) a positive `CachedPrediction` in Redis,
+    /// which shares the same underlying key space.
+    fn negative_cache_key(&self, request: &PredictionRequest, variant: &str) -> String {
+        format!("neg:{}", self.make_cache_key(request, variant))
+    }
+
     /// Get health status
     pub async fn health(&self) -> HealthStatus {
         let redis_connected = {
@@ -748,7 +3183,9 @@ impl ProductionDSpyService {
             conn_guard.is_some()
         };
 
+        self.memory_cache.run_pending_tasks().await;
         let cache_size = self.memory_cache.entry_count();
+        MEMORY_CACHE_ENTRIES.set(cache_size as f64);
 
         let circuit_breakers: HashMap<String, String> = {
             let breakers = self.circuit_breakers.read();
@@ -765,6 +3202,20 @@ impl ProductionDSpyService {
                 .collect()
         };
 
+        let cost_budget_remaining_usd = self
+            .config
+            .cost_budget
+            .as_ref()
+            .map(|budget| budget.remaining_monthly(self.cost_tracker.total_cost()));
+
+        let failure_counts: HashMap<String, u64> = {
+            let counts = self.failure_counts.read();
+            counts
+                .iter()
+                .map(|(k, v)| (k.clone(), v.load(Ordering::Relaxed)))
+                .collect()
+        };
+
         HealthStatus {
             status: "healthy".to_string(),
             version: self.config.service_version.clone(),
@@ -773,11 +3224,19 @@ impl ProductionDSpyService {
             python_initialized: true,
             cache_size,
             circuit_breakers,
+            cost_budget_remaining_usd,
+            memory_cache_hit_ratio: cache_hit_ratio("memory"),
+            redis_cache_hit_ratio: cache_hit_ratio("redis"),
+            failure_counts,
         }
     }
 
     /// Get readiness status
     pub async fn ready(&self) -> bool {
+        if self.shutting_down.load(Ordering::SeqCst) {
+            return false;
+        }
+
         // Check if critical components are ready
         let redis_ok = {
             let mut conn_guard = self.redis_conn.lock().await;
@@ -793,6 +3252,103 @@ impl ProductionDSpyService {
         redis_ok
     }
 
+    /// Stop accepting new prediction requests and wait for in-flight ones to
+    /// finish. New requests submitted to `predict` after this call starts
+    /// are rejected immediately with a `service_unavailable` error; callers
+    /// should flip their readiness probe (see `ready`) to stop receiving new
+    /// traffic before or alongside calling this. Returns once
+    /// `ACTIVE_PREDICTIONS` has drained to zero or `grace` elapses,
+    /// whichever comes first - any predictions still running past the grace
+    /// period are left to finish on their own and simply won't have their
+    /// cache writes or audit records awaited here. Once the drain finishes
+    /// (or gives up), persists the memory cache to Redis (see
+    /// `persist_memory_cache_to_redis`) so the next pod starts warm.
+    #[instrument(skip(self))]
+    pub async fn shutdown(&self, grace: Duration) -> Result<ShutdownSummary> {
+        info!(grace_secs = grace.as_secs(), "Beginning graceful shutdown");
+        self.shutting_down.store(true, Ordering::SeqCst);
+
+        let initial_in_flight = self.active_predictions.load(Ordering::SeqCst);
+
+        let start = Instant::now();
+        let final_in_flight = loop {
+            let in_flight = self.active_predictions.load(Ordering::SeqCst);
+            if in_flight <= 0 {
+                break in_flight;
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= grace {
+                warn!(
+                    in_flight,
+                    "Grace period elapsed with predictions still in flight"
+                );
+                break in_flight;
+            }
+
+            tokio::time::sleep(Duration::from_millis(25).min(grace - elapsed)).await;
+        };
+
+        // Any in-flight cache writes have completed by the time their
+        // prediction finished, so once the drain above completes there's
+        // nothing left buffered on the Redis connection to flush.
+        let completed = (initial_in_flight - final_in_flight).max(0);
+        let abandoned = final_in_flight.max(0);
+        let cache_entries_persisted = self.persist_memory_cache_to_redis().await;
+
+        info!(
+            elapsed_ms = start.elapsed().as_millis(),
+            completed,
+            abandoned,
+            cache_entries_persisted = ?cache_entries_persisted,
+            "Graceful shutdown drain complete"
+        );
+        Ok(ShutdownSummary {
+            completed,
+            abandoned,
+            cache_entries_persisted,
+        })
+    }
+
+    /// Writes every current memory-cache entry to Redis (if
+    /// `ServiceConfig::redis_url` yielded a connection) with
+    /// `ServiceConfig::redis_cache_ttl_secs`, so the next pod to start up
+    /// begins with a warm Redis cache instead of refilling it one cache
+    /// miss at a time. Each key is written at most once, with no retries -
+    /// shutdown is already time-boxed by the caller's grace period, and a
+    /// handful of misses here just means a few cold cache entries on the
+    /// next pod, not a correctness problem. Returns `None` when no Redis
+    /// connection is configured.
+    async fn persist_memory_cache_to_redis(&self) -> Option<usize> {
+        let mut conn_guard = self.redis_conn.lock().await;
+        let conn = conn_guard.as_mut()?;
+
+        let ttl_secs = self.config.redis_cache_ttl_secs;
+        let mut persisted = 0usize;
+        for (key, cached) in self.memory_cache.iter() {
+            let data = match self
+                .config
+                .cache_codec
+                .encode(&cached)
+                .and_then(|encoded| maybe_compress_for_redis(&encoded, self.config.compress_threshold_bytes))
+            {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!(cache_key = %key, error = %e, "Failed to encode cache entry for shutdown persistence");
+                    continue;
+                }
+            };
+
+            match conn.set_ex::<_, _, ()>(&*key, data, ttl_secs).await {
+                Ok(()) => persisted += 1,
+                Err(e) => warn!(cache_key = %key, error = %e, "Redis SET failed while persisting cache entry for shutdown"),
+            }
+        }
+
+        debug!(persisted, "Persisted memory cache to Redis on shutdown");
+        Some(persisted)
+    }
+
     /// Get Prometheus metrics
     pub fn metrics(&self) -> Result<String> {
         let encoder = TextEncoder::new();
@@ -833,6 +3389,16 @@ mod tests {
                 cost_per_1k_output_tokens: 0.002,
                 max_retries: 3,
                 request_timeout_secs: 30,
+                max_rpm: None,
+                parameters_schema: None,
+                hedge_after_ms: None,
+                hedge_cost_both: false,
+                circuit_breaker_failure_threshold: None,
+                circuit_breaker_success_threshold: None,
+                circuit_breaker_timeout_secs: None,
+                python_module: None,
+                python_callable: None,
+                variants: HashMap::new(),
             },
         );
 
@@ -843,18 +3409,118 @@ mod tests {
             memory_cache_size: 100,
             memory_cache_ttl_secs: 60,
             redis_cache_ttl_secs: 300,
+            redis_cache_ttl_jitter_percent: 0.0,
+            compress_threshold_bytes: default_compress_threshold_bytes(),
+            single_flight_enabled: false,
+            negative_cache_ttl_secs: 30,
+            redis_reconnect_max_backoff_secs: 60,
             circuit_breaker_failure_threshold: 5,
             circuit_breaker_success_threshold: 2,
             circuit_breaker_timeout_secs: 60,
             models,
             ab_testing_enabled: false,
             default_variant: "baseline".to_string(),
+            audit_log_path: None,
+            rate_limit_behavior: RateLimitBehavior::Wait,
+            max_concurrent_predictions: default_max_concurrent_predictions(),
+            max_queued_predictions: default_max_queued_predictions(),
+            cost_budget: None,
+            budget_limits: None,
+            circuit_breaker_mode: CircuitBreakerMode::ConsecutiveFailures,
+            cache_codec: CacheCodec::Json,
+            redaction_enabled: true,
         }
     }
 
-    #[tokio::test]
-    async fn test_cost_tracker() {
-        let tracker = CostTracker::new();
+    fn sample_cached_prediction() -> CachedPrediction {
+        CachedPrediction {
+            output: "the answer is 42".to_string(),
+            input_tokens: 10,
+            output_tokens: 5,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_cache_codec_json_round_trips() {
+        let cached = sample_cached_prediction();
+        let encoded = CacheCodec::Json.encode(&cached).unwrap();
+        let decoded = CacheCodec::decode(&encoded).unwrap();
+        assert_eq!(decoded.output, cached.output);
+    }
+
+    #[test]
+    fn test_cache_codec_message_pack_round_trips() {
+        let cached = sample_cached_prediction();
+        let encoded = CacheCodec::MessagePack.encode(&cached).unwrap();
+        let decoded = CacheCodec::decode(&encoded).unwrap();
+        assert_eq!(decoded.output, cached.output);
+    }
+
+    #[test]
+    fn test_cache_codec_decode_handles_either_codec_regardless_of_current_default() {
+        let cached = sample_cached_prediction();
+        let json_encoded = CacheCodec::Json.encode(&cached).unwrap();
+        let msgpack_encoded = CacheCodec::MessagePack.encode(&cached).unwrap();
+
+        // A MessagePack entry stays readable even if the marker byte is the
+        // only thing distinguishing it from a JSON one.
+        assert_eq!(CacheCodec::decode(&json_encoded).unwrap().output, cached.output);
+        assert_eq!(CacheCodec::decode(&msgpack_encoded).unwrap().output, cached.output);
+    }
+
+    #[test]
+    fn test_cache_codec_decode_rejects_unknown_marker() {
+        assert!(CacheCodec::decode(&[255, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_maybe_compress_for_redis_round_trips_below_threshold_uncompressed() {
+        let data = b"short value".to_vec();
+        let wrapped = maybe_compress_for_redis(&data, 1024).unwrap();
+
+        assert_eq!(wrapped[0], RedisPayloadEncoding::Plain.marker());
+        assert_eq!(maybe_decompress_from_redis(&wrapped).unwrap(), data);
+    }
+
+    #[test]
+    fn test_maybe_compress_for_redis_round_trips_above_threshold_compressed() {
+        // Long, repetitive so gzip's overhead doesn't swamp the savings.
+        let data = "the answer is 42".repeat(1000).into_bytes();
+        let wrapped = maybe_compress_for_redis(&data, 1024).unwrap();
+
+        assert_eq!(wrapped[0], RedisPayloadEncoding::Gzip.marker());
+        assert!(
+            wrapped.len() < data.len(),
+            "compressed, marker-prefixed buffer should still be smaller than the original for repetitive input"
+        );
+        assert_eq!(maybe_decompress_from_redis(&wrapped).unwrap(), data);
+    }
+
+    #[test]
+    fn test_maybe_compress_for_redis_zero_threshold_disables_compression() {
+        let data = "the answer is 42".repeat(1000).into_bytes();
+        let wrapped = maybe_compress_for_redis(&data, 0).unwrap();
+
+        assert_eq!(wrapped[0], RedisPayloadEncoding::Plain.marker());
+        assert_eq!(maybe_decompress_from_redis(&wrapped).unwrap(), data);
+    }
+
+    #[test]
+    fn test_maybe_compress_for_redis_records_compression_ratio() {
+        let data = "the answer is 42".repeat(1000).into_bytes();
+        let wrapped = maybe_compress_for_redis(&data, 1024).unwrap();
+
+        // The marker byte is excluded from the ratio on both sides, so it
+        // reflects the compressor's own work rather than our bookkeeping.
+        let expected_ratio = (wrapped.len() - 1) as f64 / data.len() as f64;
+        assert!((CACHE_COMPRESSION_RATIO.get() - expected_ratio).abs() < f64::EPSILON);
+        assert!(CACHE_COMPRESSION_RATIO.get() < 1.0, "repetitive input should compress below its original size");
+    }
+
+    #[tokio::test]
+    async fn test_cost_tracker() {
+        let tracker = CostTracker::new();
 
         tracker.record_prediction("gpt-4", "baseline", 100, 50, 0.005);
         tracker.record_prediction("gpt-4", "baseline", 200, 100, 0.010);
@@ -866,6 +3532,39 @@ mod tests {
         assert_eq!(metrics.total_cost_usd, 0.015);
     }
 
+    #[tokio::test]
+    async fn test_cost_tracker_under_concurrent_load() {
+        const TASKS_PER_MODEL: usize = 50;
+        let tracker = Arc::new(CostTracker::new());
+        let mut handles = Vec::new();
+
+        // Tasks hammering distinct models exercise different DashMap shards;
+        // tasks sharing "shared-model" exercise the same shard concurrently.
+        for model in ["model-a", "model-b", "shared-model", "shared-model"] {
+            for _ in 0..TASKS_PER_MODEL {
+                let tracker = tracker.clone();
+                handles.push(tokio::spawn(async move {
+                    tracker.record_prediction(model, "baseline", 10, 5, 0.001);
+                }));
+            }
+        }
+
+        for handle in handles {
+            handle.await.expect("task should not panic");
+        }
+
+        let model_a = tracker.get_metrics("model-a", "baseline").unwrap();
+        assert_eq!(model_a.total_requests, TASKS_PER_MODEL as u64);
+        assert_eq!(model_a.total_input_tokens, (TASKS_PER_MODEL * 10) as u64);
+
+        let shared = tracker.get_metrics("shared-model", "baseline").unwrap();
+        assert_eq!(shared.total_requests, (TASKS_PER_MODEL * 2) as u64);
+        assert!((shared.total_cost_usd - (TASKS_PER_MODEL * 2) as f64 * 0.001).abs() < 1e-9);
+
+        let all = tracker.get_all_metrics();
+        assert_eq!(all.len(), 3);
+    }
+
     #[tokio::test]
     async fn test_cache_key_generation() {
         let config = test_config();
@@ -880,11 +3579,1856 @@ mod tests {
             input: "test input".to_string(),
             parameters: HashMap::new(),
             use_cache: true,
+            priority: 0,
+            correlation_id: None,
         };
 
-        let key1 = service.make_cache_key(&request);
-        let key2 = service.make_cache_key(&request);
+        let key1 = service.make_cache_key(&request, "baseline");
+        let key2 = service.make_cache_key(&request, "baseline");
 
         assert_eq!(key1, key2);
     }
+
+    #[tokio::test]
+    async fn test_cache_key_distinguishes_variant_and_parameters() {
+        let config = test_config();
+        let service = ProductionDSpyService::new(config)
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let baseline_key = service.make_cache_key(&request, "baseline");
+        let treatment_key = service.make_cache_key(&request, "treatment");
+        assert_ne!(
+            baseline_key, treatment_key,
+            "requests differing only in variant must not share a cache entry"
+        );
+
+        let mut with_params = request.clone();
+        with_params.parameters.insert("temperature".to_string(), serde_json::json!(0.7));
+        let params_key = service.make_cache_key(&with_params, "baseline");
+        assert_ne!(
+            baseline_key, params_key,
+            "requests differing only in parameters must not share a cache entry"
+        );
+
+        // Canonicalization means key order doesn't matter: two parameter
+        // maps built with keys inserted in a different order must still
+        // produce the same cache key.
+        let mut reordered_params = request.clone();
+        reordered_params.parameters.insert("b".to_string(), serde_json::json!(2));
+        reordered_params.parameters.insert("a".to_string(), serde_json::json!(1));
+        let mut other_order_params = request.clone();
+        other_order_params.parameters.insert("a".to_string(), serde_json::json!(1));
+        other_order_params.parameters.insert("b".to_string(), serde_json::json!(2));
+        assert_eq!(
+            service.make_cache_key(&reordered_params, "baseline"),
+            service.make_cache_key(&other_order_params, "baseline"),
+            "parameter insertion order must not affect the cache key"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_variant_prefers_explicit_request_variant() {
+        let mut config = test_config();
+        config.ab_testing_enabled = true;
+        config
+            .models
+            .get_mut("gpt-3.5-turbo")
+            .unwrap()
+            .variants
+            .insert("treatment".to_string(), 1.0);
+        let service = ProductionDSpyService::new(config)
+            .await
+            .expect("Failed to create service");
+
+        assert_eq!(
+            service.resolve_variant("gpt-3.5-turbo", Some("explicit")),
+            "explicit",
+            "an explicit request variant must never be overridden by A/B selection"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_variant_falls_back_to_default_without_weights_configured() {
+        let mut config = test_config();
+        config.ab_testing_enabled = true;
+        // No `variants` weight table configured for this model.
+        let service = ProductionDSpyService::new(config)
+            .await
+            .expect("Failed to create service");
+
+        assert_eq!(service.resolve_variant("gpt-3.5-turbo", None), "baseline");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_variant_weighted_distribution_matches_configured_weights() {
+        let mut config = test_config();
+        config.ab_testing_enabled = true;
+        {
+            let variants = &mut config.models.get_mut("gpt-3.5-turbo").unwrap().variants;
+            variants.insert("baseline".to_string(), 0.8);
+            variants.insert("treatment".to_string(), 0.2);
+        }
+        let service = ProductionDSpyService::new(config)
+            .await
+            .expect("Failed to create service");
+
+        const SAMPLES: usize = 20_000;
+        let mut treatment_count = 0usize;
+        for _ in 0..SAMPLES {
+            match service.resolve_variant("gpt-3.5-turbo", None).as_str() {
+                "treatment" => treatment_count += 1,
+                "baseline" => {}
+                other => panic!("unexpected variant: {other}"),
+            }
+        }
+
+        let empirical_treatment_share = treatment_count as f64 / SAMPLES as f64;
+        assert!(
+            (empirical_treatment_share - 0.2).abs() < 0.02,
+            "empirical treatment share {empirical_treatment_share} should be within tolerance of configured weight 0.2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_cache_hit_ratio_and_failure_counts_consistent_with_raw_counters() {
+        let backend = MockBackend::new().with_response(
+            "health ratio input",
+            Completion {
+                output: "health ratio output".to_string(),
+                input_tokens: 5,
+                output_tokens: 5,
+            },
+        );
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "health ratio input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        // First call is a memory-cache miss, second is a hit - both recorded
+        // against the same process-wide `CACHE_OPERATIONS` counters every
+        // other test in this module also shares, so the assertions below
+        // recompute the expected ratio from those same live counters
+        // instead of asserting an exact isolated value.
+        let first = service.predict(request.clone()).await.unwrap();
+        assert!(!first.metadata.cached);
+        let second = service.predict(request).await.unwrap();
+        assert!(second.metadata.cached);
+
+        let health = service.health().await;
+
+        let memory_hits = CACHE_OPERATIONS.with_label_values(&["memory", "hit"]).get();
+        let memory_misses = CACHE_OPERATIONS.with_label_values(&["memory", "miss"]).get();
+        let expected_memory_ratio = memory_hits / (memory_hits + memory_misses);
+        assert_eq!(health.memory_cache_hit_ratio, expected_memory_ratio);
+        assert!(health.memory_cache_hit_ratio > 0.0, "the second identical request should have hit the memory cache");
+
+        // No Redis connection is configured in `test_config`, so the redis
+        // counters never move and the ratio stays at its `0.0` no-data default.
+        assert_eq!(health.redis_cache_hit_ratio, 0.0);
+
+        assert_eq!(
+            health.failure_counts.get("gpt-3.5-turbo").copied(),
+            Some(0),
+            "both requests succeeded, so this model should show zero recorded failures"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_failure_count_increments_on_backend_error() {
+        let backend = MockBackend::new().with_error("boom", "simulated backend failure");
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "boom".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        assert!(service.predict(request).await.is_err());
+
+        let health = service.health().await;
+        assert_eq!(health.failure_counts.get("gpt-3.5-turbo").copied(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_removes_entry_so_next_request_is_a_miss() {
+        let backend = MockBackend::new().with_response(
+            "test input",
+            Completion {
+                output: "first output".to_string(),
+                input_tokens: 5,
+                output_tokens: 5,
+            },
+        );
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let first = service.predict(request.clone()).await.unwrap();
+        assert!(!first.metadata.cached);
+        let second = service.predict(request.clone()).await.unwrap();
+        assert!(second.metadata.cached, "second identical request should hit the cache");
+
+        let removed = service
+            .invalidate("gpt-3.5-turbo", "test input", None, HashMap::new())
+            .await
+            .expect("invalidate should succeed with no Redis connection configured");
+        assert_eq!(removed, 1, "only the memory cache entry exists without a real Redis connection");
+
+        let third = service.predict(request).await.unwrap();
+        assert!(!third.metadata.cached, "invalidated entry must be a miss on the next request");
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_with_non_empty_parameters_finds_the_entry() {
+        let backend = MockBackend::new().with_response(
+            "test input",
+            Completion {
+                output: "first output".to_string(),
+                input_tokens: 5,
+                output_tokens: 5,
+            },
+        );
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let mut parameters = HashMap::new();
+        parameters.insert("temperature".to_string(), serde_json::json!(0.7));
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: parameters.clone(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        service.predict(request.clone()).await.unwrap();
+        let second = service.predict(request.clone()).await.unwrap();
+        assert!(second.metadata.cached, "second identical request should hit the cache");
+
+        let removed = service
+            .invalidate("gpt-3.5-turbo", "test input", None, parameters)
+            .await
+            .expect("invalidate should succeed with no Redis connection configured");
+        assert_eq!(
+            removed, 1,
+            "invalidate must recompute the same hash the entry was cached under, parameters included"
+        );
+
+        let third = service.predict(request).await.unwrap();
+        assert!(!third.metadata.cached, "invalidated entry must be a miss on the next request");
+    }
+
+    #[tokio::test]
+    async fn test_purge_model_removes_all_entries_for_that_model() {
+        let backend = MockBackend::new();
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        for input in ["input one", "input two"] {
+            service
+                .predict(PredictionRequest {
+                    request_id: Uuid::new_v4().to_string(),
+                    model: "gpt-3.5-turbo".to_string(),
+                    variant: None,
+                    input: input.to_string(),
+                    parameters: HashMap::new(),
+                    use_cache: true,
+                    priority: 0,
+                    correlation_id: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let removed = service.purge_model("gpt-3.5-turbo").await.expect("purge should succeed");
+        assert_eq!(removed, 2);
+
+        let repeat = service
+            .predict(PredictionRequest {
+                request_id: Uuid::new_v4().to_string(),
+                model: "gpt-3.5-turbo".to_string(),
+                variant: None,
+                input: "input one".to_string(),
+                parameters: HashMap::new(),
+                use_cache: true,
+                priority: 0,
+                correlation_id: None,
+            })
+            .await
+            .unwrap();
+        assert!(!repeat.metadata.cached, "purged entries must be misses on the next request");
+    }
+
+    #[tokio::test]
+    async fn test_predict_does_not_share_cache_entries_across_variants() {
+        let backend = MockBackend::new()
+            .with_response(
+                "test input",
+                Completion {
+                    output: "baseline output".to_string(),
+                    input_tokens: 5,
+                    output_tokens: 5,
+                },
+            );
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let baseline_request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: Some("baseline".to_string()),
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+        let baseline_response = service
+            .predict(baseline_request)
+            .await
+            .expect("baseline prediction should succeed");
+        assert!(!baseline_response.metadata.cached);
+
+        let treatment_request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: Some("treatment".to_string()),
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+        let treatment_response = service
+            .predict(treatment_request)
+            .await
+            .expect("treatment prediction should succeed");
+
+        assert!(
+            !treatment_response.metadata.cached,
+            "a different variant must not be served from the baseline variant's cache entry"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_predict_negatively_caches_deterministic_error() {
+        let backend = MockBackend::new()
+            .with_error("flagged input", "Content filter triggered: disallowed content");
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = || PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "flagged input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let negative_hits_before = CACHE_OPERATIONS.with_label_values(&["memory", "negative_hit"]).get();
+
+        let first_err = service
+            .predict(request())
+            .await
+            .expect_err("content filter rejection should surface as an error");
+        assert!(first_err.to_string().contains("Content filter triggered"));
+
+        let second_err = service
+            .predict(request())
+            .await
+            .expect_err("a deterministically-failing input should keep failing");
+        assert_eq!(
+            second_err.to_string(),
+            first_err.to_string(),
+            "the second attempt should be served the same cached error"
+        );
+
+        assert_eq!(
+            CACHE_OPERATIONS.with_label_values(&["memory", "negative_hit"]).get() - negative_hits_before,
+            1.0,
+            "the second attempt should be a negative cache hit rather than a fresh backend call"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_predict_does_not_negatively_cache_transient_error() {
+        let backend = MockBackend::new().with_error("boom", "simulated backend failure");
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = || PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "boom".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let negative_hits_before = CACHE_OPERATIONS.with_label_values(&["memory", "negative_hit"]).get();
+
+        service
+            .predict(request())
+            .await
+            .expect_err("backend failure should surface");
+        service
+            .predict(request())
+            .await
+            .expect_err("backend failure should surface again");
+
+        assert_eq!(
+            CACHE_OPERATIONS.with_label_values(&["memory", "negative_hit"]).get(),
+            negative_hits_before,
+            "a transient backend error must never be negatively cached"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_records_completed_predictions() {
+        let audit_path = std::env::temp_dir().join(format!("audit_log_test_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&audit_path);
+
+        let mut config = test_config();
+        config.audit_log_path = Some(audit_path.to_string_lossy().to_string());
+        let service = ProductionDSpyService::new(config)
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: "audit-test-1".to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+        service.predict(request).await.expect("prediction should succeed");
+
+        // The audit writer runs in a background task; give it a few
+        // scheduler turns to pick up the record and flush it to disk.
+        let mut contents = String::new();
+        for _ in 0..20 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            if let Ok(data) = std::fs::read_to_string(&audit_path) {
+                if !data.is_empty() {
+                    contents = data;
+                    break;
+                }
+            }
+        }
+
+        assert!(!contents.is_empty(), "expected at least one audit record to be written");
+        let record: serde_json::Value = serde_json::from_str(contents.lines().next().unwrap()).unwrap();
+        assert_eq!(record["request_id"], "audit-test-1");
+        assert_eq!(record["model"], "gpt-3.5-turbo");
+        assert_eq!(record["status"], "success");
+
+        let _ = std::fs::remove_file(&audit_path);
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_redacts_pii_but_serves_original_output() {
+        let audit_path = std::env::temp_dir().join(format!("audit_log_redact_test_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&audit_path);
+
+        let backend = MockBackend::new().with_response(
+            "my email is jane@example.com",
+            Completion {
+                output: "Contact jane@example.com for details".to_string(),
+                input_tokens: 10,
+                output_tokens: 10,
+            },
+        );
+        let mut config = test_config();
+        config.audit_log_path = Some(audit_path.to_string_lossy().to_string());
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: "audit-redact-1".to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "my email is jane@example.com".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+        let response = service.predict(request).await.expect("prediction should succeed");
+
+        // The caller still gets the real, unredacted output - only the
+        // audit trail is scrubbed.
+        assert_eq!(response.output, "Contact jane@example.com for details");
+
+        let mut contents = String::new();
+        for _ in 0..20 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            if let Ok(data) = std::fs::read_to_string(&audit_path) {
+                if !data.is_empty() {
+                    contents = data;
+                    break;
+                }
+            }
+        }
+
+        assert!(!contents.is_empty(), "expected at least one audit record to be written");
+        let record: serde_json::Value = serde_json::from_str(contents.lines().next().unwrap()).unwrap();
+        assert_eq!(record["input"], "my email is [REDACTED_EMAIL]");
+        assert_eq!(record["output"], "Contact [REDACTED_EMAIL] for details");
+
+        let _ = std::fs::remove_file(&audit_path);
+    }
+
+    #[test]
+    fn test_regex_redactor_masks_email_phone_and_card_patterns() {
+        let redactor = RegexRedactor;
+        assert_eq!(
+            redactor.redact("reach me at jane@example.com"),
+            "reach me at [REDACTED_EMAIL]"
+        );
+        assert_eq!(
+            redactor.redact("call 555-123-4567 anytime"),
+            "call [REDACTED_PHONE] anytime"
+        );
+        assert_eq!(
+            redactor.redact("card: 4111 1111 1111 1111"),
+            "card: [REDACTED_CARD]"
+        );
+        assert_eq!(redactor.redact("nothing sensitive here"), "nothing sensitive here");
+    }
+
+    #[test]
+    fn test_noop_redactor_passes_text_through_unchanged() {
+        let redactor = NoopRedactor;
+        assert_eq!(redactor.redact("jane@example.com"), "jane@example.com");
+    }
+
+    #[test]
+    fn test_token_bucket_limits_bursts_and_refills() {
+        let bucket = TokenBucket::new(60); // 1 token/sec
+
+        // Burst: the full capacity is available immediately.
+        for _ in 0..60 {
+            assert!(bucket.try_acquire());
+        }
+        assert!(!bucket.try_acquire(), "bucket should be empty after a full burst");
+
+        // After ~50ms, roughly 0.05 tokens should have refilled - not yet a
+        // whole token.
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(!bucket.try_acquire());
+    }
+
+    #[tokio::test]
+    async fn test_admission_queue_admits_up_to_max_concurrent_immediately() {
+        let queue = AdmissionQueue::new(2, 10);
+
+        let first = queue.acquire(0).await.expect("first slot should be free");
+        let second = queue.acquire(0).await.expect("second slot should be free");
+        drop(first);
+        drop(second);
+    }
+
+    #[tokio::test]
+    async fn test_admission_queue_rejects_when_waiting_room_is_full() {
+        let queue = Arc::new(AdmissionQueue::new(1, 1));
+
+        // Hold the only slot, then fill the single waiting slot.
+        let holder = queue.acquire(0).await.unwrap();
+        let _waiter = {
+            let queue = queue.clone();
+            tokio::spawn(async move { queue.acquire(0).await })
+        };
+        // Give the spawned task a chance to enqueue before testing overflow.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let err = queue
+            .acquire(0)
+            .await
+            .expect_err("a third request should overflow the bounded waiting room");
+        assert!(err.to_string().contains("queue_full"));
+
+        drop(holder);
+    }
+
+    #[tokio::test]
+    async fn test_admission_queue_serves_higher_priority_first() {
+        let queue = Arc::new(AdmissionQueue::new(1, 10));
+
+        // Occupy the only slot so subsequent acquires have to queue.
+        let holder = queue.acquire(0).await.unwrap();
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let mut handles = Vec::new();
+        for (label, priority) in [("low", 3), ("low2", 3), ("high", 0)] {
+            let queue = queue.clone();
+            let order = order.clone();
+            handles.push(tokio::spawn(async move {
+                let guard = queue.acquire(priority).await.unwrap();
+                order.lock().push(label);
+                // Hold briefly so the next waiter's admission is observable.
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                drop(guard);
+            }));
+        }
+        // Let all three requests enqueue behind `holder` before releasing it.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(holder);
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // "high" was enqueued last but should still be admitted before the
+        // two lower-priority waiters that arrived earlier.
+        assert_eq!(order.lock()[0], "high");
+    }
+
+    #[tokio::test]
+    async fn test_admission_queue_ages_up_starved_low_priority_waiter() {
+        let aging_interval = Duration::from_millis(100);
+        let queue = Arc::new(AdmissionQueue::with_aging_interval(1, 10, aging_interval));
+        let holder = queue.acquire(0).await.unwrap();
+
+        let low_priority_class = (PRIORITY_CLASSES - 1) as u8;
+        let queue_clone = queue.clone();
+        let low = tokio::spawn(async move { queue_clone.acquire(low_priority_class).await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // Free the slot, then starve the low-priority waiter with a
+        // continuous stream of higher-priority arrivals past the aging
+        // interval; each one releases immediately so the single slot is
+        // always being re-contended for before `low` can become next.
+        drop(holder);
+        let deadline = Instant::now() + aging_interval * (PRIORITY_CLASSES as u32) + Duration::from_secs(1);
+        while Instant::now() < deadline && !low.is_finished() {
+            if let Ok(guard) = queue.acquire(0).await {
+                drop(guard);
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let result = tokio::time::timeout(Duration::from_secs(2), low)
+            .await
+            .expect("starved low-priority waiter should eventually be aged up and admitted");
+        assert!(result.unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_failure_rate_window_tracks_trailing_outcomes_only() {
+        let window = FailureRateWindow::new(Duration::from_millis(50));
+        assert_eq!(window.stats(), (0.0, 0));
+
+        window.record(false);
+        window.record(false);
+        window.record(true);
+        let (failure_ratio, request_count) = window.stats();
+        assert_eq!(request_count, 3);
+        assert!((failure_ratio - (2.0 / 3.0)).abs() < 1e-9);
+
+        std::thread::sleep(Duration::from_millis(60));
+        // All three outcomes have aged out of the window.
+        assert_eq!(window.stats(), (0.0, 0));
+    }
+
+    #[test]
+    fn test_error_rate_breaker_requires_minimum_volume_before_tripping() {
+        let window = FailureRateWindow::new(Duration::from_secs(60));
+
+        // A single failure is a 100% failure ratio, but `min_requests`
+        // guards against tripping on a brand-new, low-volume model.
+        window.record(false);
+        assert!(!error_rate_breaker_is_open(window.stats(), 0.5, 5));
+
+        for _ in 0..4 {
+            window.record(false);
+        }
+        // Now at 5 requests, all failures: both the ratio and volume
+        // conditions are satisfied.
+        assert!(error_rate_breaker_is_open(window.stats(), 0.5, 5));
+    }
+
+    #[test]
+    fn test_error_rate_breaker_requires_ratio_above_threshold() {
+        let window = FailureRateWindow::new(Duration::from_secs(60));
+
+        for _ in 0..8 {
+            window.record(true);
+        }
+        for _ in 0..2 {
+            window.record(false);
+        }
+        // 20% failure ratio at sufficient volume, but below the 50% threshold.
+        assert!(!error_rate_breaker_is_open(window.stats(), 0.5, 5));
+
+        for _ in 0..8 {
+            window.record(false);
+        }
+        // Now well above the 50% threshold.
+        assert!(error_rate_breaker_is_open(window.stats(), 0.5, 5));
+    }
+
+    fn rate_limited_request(model: &str) -> PredictionRequest {
+        PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: model.to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_rejects_when_exhausted() {
+        let mut config = test_config();
+        config.rate_limit_behavior = RateLimitBehavior::Reject;
+        config.models.get_mut("gpt-3.5-turbo").unwrap().max_rpm = Some(1);
+        let service = Arc::new(
+            ProductionDSpyService::new(config)
+                .await
+                .expect("Failed to create service"),
+        );
+
+        let first = service.predict(rate_limited_request("gpt-3.5-turbo")).await;
+        assert!(first.is_ok(), "first request should consume the only token");
+
+        let second = service.predict(rate_limited_request("gpt-3.5-turbo")).await;
+        let err = second.expect_err("second request should be rejected immediately");
+        assert!(err.to_string().contains("Rate limit exceeded"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_waits_then_succeeds() {
+        let mut config = test_config();
+        config.rate_limit_behavior = RateLimitBehavior::Wait;
+        {
+            let model_config = config.models.get_mut("gpt-3.5-turbo").unwrap();
+            model_config.max_rpm = Some(60); // 1 token/sec refill
+            model_config.request_timeout_secs = 2;
+        }
+        let service = Arc::new(
+            ProductionDSpyService::new(config)
+                .await
+                .expect("Failed to create service"),
+        );
+
+        // Drain the full 60-token burst.
+        for _ in 0..60 {
+            service
+                .predict(rate_limited_request("gpt-3.5-turbo"))
+                .await
+                .expect("burst requests should succeed immediately");
+        }
+
+        // The next request has no token available and must wait for a
+        // refill, which happens well within the 2s deadline.
+        let start = Instant::now();
+        service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect("second request should succeed after waiting for a refill");
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_shared_across_concurrent_requests() {
+        let mut config = test_config();
+        config.rate_limit_behavior = RateLimitBehavior::Reject;
+        config.models.get_mut("gpt-3.5-turbo").unwrap().max_rpm = Some(1);
+        let service = Arc::new(
+            ProductionDSpyService::new(config)
+                .await
+                .expect("Failed to create service"),
+        );
+
+        let tasks: Vec<_> = (0..5)
+            .map(|_| {
+                let service = Arc::clone(&service);
+                tokio::spawn(async move {
+                    service
+                        .predict(rate_limited_request("gpt-3.5-turbo"))
+                        .await
+                        .is_ok()
+                })
+            })
+            .collect();
+
+        let mut successes = 0;
+        for task in tasks {
+            if task.await.unwrap() {
+                successes += 1;
+            }
+        }
+
+        // Exactly one token was available across all concurrently-launched
+        // requests, regardless of how the Arc<TokenBucket> was cloned.
+        assert_eq!(successes, 1);
+    }
+
+    #[tokio::test]
+    async fn test_predict_rejects_requests_once_model_budget_exceeded() {
+        let backend = MockBackend::new().with_default(Completion {
+            output: "output".to_string(),
+            input_tokens: 1000,
+            output_tokens: 1000,
+        });
+        let mut config = test_config();
+        config.cost_budget = Some(
+            CostBudget::builder()
+                .daily_limit(0.001) // one prediction's cost already exceeds this
+                .monthly_limit(1000.0)
+                .build(),
+        );
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect("first request should succeed and push cost over budget");
+
+        let err = service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect_err("second request should be rejected for exceeding the model's cost budget");
+        assert!(err.to_string().contains("budget exceeded"));
+
+        let health = service.health().await;
+        assert_eq!(health.cost_budget_remaining_usd, Some(1000.0 - health_global_cost(&service)));
+    }
+
+    fn health_global_cost(service: &ProductionDSpyService) -> f64 {
+        service.cost_tracker.total_cost()
+    }
+
+    #[tokio::test]
+    async fn test_predict_rejects_requests_once_per_model_budget_limit_exceeded() {
+        let backend = MockBackend::new().with_default(Completion {
+            output: "output".to_string(),
+            input_tokens: 1000,
+            output_tokens: 1000,
+        });
+        let mut config = test_config();
+        let mut models = HashMap::new();
+        models.insert(
+            "gpt-3.5-turbo".to_string(),
+            ModelBudgetLimit {
+                daily_limit_usd: 0.001, // one prediction's cost already exceeds this
+                monthly_limit_usd: 1000.0,
+            },
+        );
+        config.budget_limits = Some(BudgetLimits { models });
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect("first request should succeed and push cost over the per-model limit");
+
+        let err = service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect_err("second request should be rejected for exceeding the model's budget limit");
+        assert!(err.to_string().contains("budget exceeded"));
+    }
+
+    #[tokio::test]
+    async fn test_predict_skips_budget_limit_check_on_cache_hit() {
+        let backend = MockBackend::new().with_default(Completion {
+            output: "output".to_string(),
+            input_tokens: 1000,
+            output_tokens: 1000,
+        });
+        let mut config = test_config();
+        let mut models = HashMap::new();
+        models.insert(
+            "gpt-3.5-turbo".to_string(),
+            ModelBudgetLimit {
+                daily_limit_usd: 0.001, // one prediction's cost already exceeds this
+                monthly_limit_usd: 1000.0,
+            },
+        );
+        config.budget_limits = Some(BudgetLimits { models });
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let mut request = rate_limited_request("gpt-3.5-turbo");
+        request.use_cache = true;
+
+        service
+            .predict(request.clone())
+            .await
+            .expect("first request should succeed and push cost over the per-model limit");
+
+        service
+            .predict(request)
+            .await
+            .expect("cache hit should bypass the per-model budget limit check entirely");
+    }
+
+    #[tokio::test]
+    async fn test_predict_allowed_when_no_budget_configured() {
+        let backend = MockBackend::new();
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        assert!(service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .is_ok());
+        assert_eq!(service.health().await.cost_budget_remaining_usd, None);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_rejects_new_requests() {
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(MockBackend::new()))
+            .await
+            .expect("Failed to create service");
+
+        service
+            .shutdown(Duration::from_secs(1))
+            .await
+            .expect("shutdown should complete with no in-flight predictions");
+
+        assert!(!service.ready().await, "service should report not-ready while shutting down");
+
+        let err = service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect_err("requests submitted after shutdown should be rejected");
+        assert!(err.to_string().contains("shutting down"));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_in_flight_predictions_to_drain() {
+        let backend = MockBackend::new().with_delay(Duration::from_millis(100));
+        let service = Arc::new(
+            ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+                .await
+                .expect("Failed to create service"),
+        );
+
+        let predicting_service = Arc::clone(&service);
+        let slow_prediction = tokio::spawn(async move {
+            predicting_service
+                .predict(rate_limited_request("gpt-3.5-turbo"))
+                .await
+                .expect("slow prediction should still succeed once shutdown lets it finish")
+        });
+
+        // Give the slow prediction a moment to be admitted and start
+        // counting against ACTIVE_PREDICTIONS before shutdown begins.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let start = Instant::now();
+        let summary = service
+            .shutdown(Duration::from_secs(2))
+            .await
+            .expect("shutdown should complete once the in-flight prediction finishes");
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.abandoned, 0);
+
+        slow_prediction.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_gives_up_after_grace_period() {
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(MockBackend::new()))
+            .await
+            .expect("Failed to create service");
+
+        // Simulate a prediction that never finishes.
+        service.active_predictions.fetch_add(1, Ordering::SeqCst);
+
+        let start = Instant::now();
+        let summary = service
+            .shutdown(Duration::from_millis(50))
+            .await
+            .expect("shutdown should give up after the grace period rather than hang forever");
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(summary.completed, 0);
+        assert_eq!(summary.abandoned, 1);
+    }
+
+    #[test]
+    fn test_jittered_ttl_secs_stays_within_bounds() {
+        let base = 300u64;
+        for _ in 0..1000 {
+            let jittered = jittered_ttl_secs(base, 10.0);
+            assert!(jittered >= 270 && jittered <= 330, "jittered TTL {jittered} outside ±10% of {base}");
+        }
+    }
+
+    #[test]
+    fn test_jittered_ttl_secs_disabled_returns_base_unchanged() {
+        assert_eq!(jittered_ttl_secs(300, 0.0), 300);
+        assert_eq!(jittered_ttl_secs(300, -5.0), 300);
+        assert_eq!(jittered_ttl_secs(0, 10.0), 0);
+    }
+
+    #[tokio::test]
+    async fn test_single_flight_dispatches_concurrent_identical_requests_once() {
+        const CONCURRENT_CALLERS: usize = 10;
+
+        let mut config = test_config();
+        config.single_flight_enabled = true;
+        let backend = MockBackend::new().with_delay(Duration::from_millis(50));
+        let service = Arc::new(
+            ProductionDSpyService::new_with_backend(config, Box::new(backend))
+                .await
+                .expect("Failed to create service"),
+        );
+
+        let mut handles = Vec::new();
+        for _ in 0..CONCURRENT_CALLERS {
+            let service = Arc::clone(&service);
+            handles.push(tokio::spawn(async move {
+                service
+                    .predict(PredictionRequest {
+                        request_id: Uuid::new_v4().to_string(),
+                        model: "gpt-3.5-turbo".to_string(),
+                        variant: None,
+                        input: "single-flight test input".to_string(),
+                        parameters: HashMap::new(),
+                        use_cache: true,
+                        priority: 0,
+                        correlation_id: None,
+                    })
+                    .await
+                    .expect("prediction should succeed")
+            }));
+        }
+
+        let mut request_ids = std::collections::HashSet::new();
+        for handle in handles {
+            let response = handle.await.unwrap();
+            request_ids.insert(response.request_id);
+        }
+
+        // Every caller got its own request_id back even though only one
+        // actually dispatched to the backend.
+        assert_eq!(request_ids.len(), CONCURRENT_CALLERS);
+
+        let metrics = service
+            .cost_tracker
+            .get_metrics("gpt-3.5-turbo", "baseline")
+            .expect("leader's dispatch should have recorded cost metrics");
+        assert_eq!(metrics.total_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_predict_rejects_parameters_violating_schema() {
+        let mut config = test_config();
+        config.models.get_mut("gpt-3.5-turbo").unwrap().parameters_schema = Some(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "temperature": { "type": "number", "minimum": 0.0, "maximum": 2.0 }
+            },
+            "additionalProperties": false
+        }));
+        let service = ProductionDSpyService::new(config)
+            .await
+            .expect("Failed to create service");
+
+        let mut parameters = HashMap::new();
+        parameters.insert("temprature".to_string(), serde_json::json!(0.7)); // typo'd key
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters,
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let err = service
+            .predict(request)
+            .await
+            .expect_err("unknown parameter key should be rejected");
+        assert!(err.to_string().contains("Invalid parameters"));
+    }
+
+    #[tokio::test]
+    async fn test_predict_allows_parameters_matching_schema() {
+        let mut config = test_config();
+        config.models.get_mut("gpt-3.5-turbo").unwrap().parameters_schema = Some(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "temperature": { "type": "number", "minimum": 0.0, "maximum": 2.0 }
+            },
+            "additionalProperties": false
+        }));
+        let service = ProductionDSpyService::new(config)
+            .await
+            .expect("Failed to create service");
+
+        let mut parameters = HashMap::new();
+        parameters.insert("temperature".to_string(), serde_json::json!(0.7));
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters,
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        assert!(service.predict(request).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_returns_scripted_response_and_records_calls() {
+        let backend = MockBackend::new().with_response(
+            "hello",
+            Completion {
+                output: "scripted output".to_string(),
+                input_tokens: 3,
+                output_tokens: 7,
+            },
+        );
+
+        let completion = backend
+            .complete(CompletionRequest {
+                model: "gpt-3.5-turbo".to_string(),
+                variant: "baseline".to_string(),
+                input: "hello".to_string(),
+                parameters: HashMap::new(),
+                python_module: None,
+                python_callable: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(completion.output, "scripted output");
+        assert_eq!(completion.input_tokens, 3);
+        assert_eq!(completion.output_tokens, 7);
+        assert_eq!(backend.calls().len(), 1);
+        assert_eq!(backend.calls()[0].input, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_predict_with_mock_backend_tracks_cost_and_caches() {
+        let backend = MockBackend::new().with_response(
+            "test input",
+            Completion {
+                output: "deterministic output".to_string(),
+                input_tokens: 100,
+                output_tokens: 50,
+            },
+        );
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let response = service.predict(request.clone()).await.expect("prediction should succeed");
+        assert_eq!(response.output, "deterministic output");
+        assert!(!response.metadata.cached);
+
+        let metrics = service
+            .cost_metrics()
+            .get("gpt-3.5-turbo:baseline")
+            .cloned()
+            .expect("cost metrics should be recorded");
+        assert_eq!(metrics.total_input_tokens, 100);
+        assert_eq!(metrics.total_output_tokens, 50);
+
+        // Second request with the same input is served from cache without
+        // calling back into the backend.
+        let cached_response = service.predict(request).await.expect("cached prediction should succeed");
+        assert!(cached_response.metadata.cached);
+        assert_eq!(cached_response.output, "deterministic output");
+    }
+
+    #[tokio::test]
+    async fn test_predict_hedges_slow_backend_but_bills_only_the_winner_by_default() {
+        let backend = MockBackend::new()
+            .with_delay(Duration::from_millis(150))
+            .with_default(Completion {
+                output: "slow output".to_string(),
+                input_tokens: 10,
+                output_tokens: 10,
+            });
+        let mut config = test_config();
+        config.models.get_mut("gpt-3.5-turbo").unwrap().hedge_after_ms = Some(20);
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "hedge me".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let response = service.predict(request).await.expect("hedged prediction should succeed");
+        assert_eq!(response.output, "slow output");
+
+        let metrics = service
+            .cost_metrics()
+            .get("gpt-3.5-turbo:baseline")
+            .cloned()
+            .expect("cost metrics should be recorded");
+        assert_eq!(
+            metrics.total_input_tokens, 10,
+            "only the attempt that served the response should be billed by default"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_predict_hedging_with_hedge_cost_both_bills_both_attempts() {
+        let backend = MockBackend::new()
+            .with_delay(Duration::from_millis(150))
+            .with_default(Completion {
+                output: "slow output".to_string(),
+                input_tokens: 10,
+                output_tokens: 10,
+            });
+        let mut config = test_config();
+        {
+            let model_config = config.models.get_mut("gpt-3.5-turbo").unwrap();
+            model_config.hedge_after_ms = Some(20);
+            model_config.hedge_cost_both = true;
+        }
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "hedge me".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        service.predict(request).await.expect("hedged prediction should succeed");
+
+        let metrics = service
+            .cost_metrics()
+            .get("gpt-3.5-turbo:baseline")
+            .cloned()
+            .expect("cost metrics should be recorded");
+        assert_eq!(
+            metrics.total_input_tokens, 20,
+            "hedge_cost_both should bill both the primary and backup attempt"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_predict_does_not_hedge_when_primary_returns_before_hedge_after_ms() {
+        let backend = MockBackend::new().with_default(Completion {
+            output: "fast output".to_string(),
+            input_tokens: 10,
+            output_tokens: 10,
+        });
+        let mut config = test_config();
+        config.models.get_mut("gpt-3.5-turbo").unwrap().hedge_after_ms = Some(500);
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "fast request".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        service.predict(request).await.expect("fast prediction should succeed");
+
+        let metrics = service
+            .cost_metrics()
+            .get("gpt-3.5-turbo:baseline")
+            .cloned()
+            .expect("cost metrics should be recorded");
+        assert_eq!(
+            metrics.total_input_tokens, 10,
+            "a backend that responds before hedge_after_ms elapses should never trigger a backup attempt"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_predict_with_mock_backend_opens_circuit_breaker() {
+        let backend = MockBackend::new().with_error("boom", "simulated backend failure");
+        let mut config = test_config();
+        config.circuit_breaker_failure_threshold = 1;
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let failing_request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "boom".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+        let err = service
+            .predict(failing_request)
+            .await
+            .expect_err("scripted backend failure should surface");
+        assert!(err.to_string().contains("simulated backend failure"));
+
+        let health = service.health().await;
+        assert_eq!(
+            health.circuit_breakers.get("gpt-3.5-turbo").map(String::as_str),
+            Some("open"),
+            "a single failure should open the breaker once failure_threshold is 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_per_model_circuit_breaker_threshold_override() {
+        let backend = MockBackend::new().with_error("boom", "simulated backend failure");
+
+        let mut config = test_config();
+        config.circuit_breaker_failure_threshold = 5; // global default: tolerant
+
+        let mut flaky_model = config.models.get("gpt-3.5-turbo").unwrap().clone();
+        flaky_model.name = "flaky-local-model".to_string();
+        flaky_model.circuit_breaker_failure_threshold = Some(1); // overridden: trips fast
+        config.models.insert("flaky-local-model".to_string(), flaky_model);
+
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        for model in ["gpt-3.5-turbo", "flaky-local-model"] {
+            let failing_request = PredictionRequest {
+                request_id: Uuid::new_v4().to_string(),
+                model: model.to_string(),
+                variant: None,
+                input: "boom".to_string(),
+                parameters: HashMap::new(),
+                use_cache: false,
+                priority: 0,
+                correlation_id: None,
+            };
+            service
+                .predict(failing_request)
+                .await
+                .expect_err("scripted backend failure should surface");
+        }
+
+        let health = service.health().await;
+        assert_eq!(
+            health.circuit_breakers.get("flaky-local-model").map(String::as_str),
+            Some("open"),
+            "a model with a failure_threshold override of 1 should open after a single failure"
+        );
+        assert_eq!(
+            health.circuit_breakers.get("gpt-3.5-turbo").map(String::as_str),
+            Some("closed"),
+            "a model using the global default threshold of 5 should stay closed after only one failure"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_predict_error_does_not_leak_active_predictions_gauge() {
+        let backend = MockBackend::new().with_error("boom", "simulated backend failure");
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let failing_request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "boom".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+        service
+            .predict(failing_request)
+            .await
+            .expect_err("scripted backend failure should surface");
+
+        assert_eq!(
+            ACTIVE_PREDICTIONS.with_label_values(&["gpt-3.5-turbo"]).get(),
+            0.0,
+            "an errored prediction must release its slot under the model label it was acquired under"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_rate_mode_respects_minimum_request_volume() {
+        let backend = MockBackend::new().with_error("boom", "simulated backend failure");
+        let mut config = test_config();
+        config.circuit_breaker_mode = CircuitBreakerMode::ErrorRate {
+            failure_rate_threshold: 0.5,
+            min_requests: 4,
+            window_secs: 60,
+        };
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let failing_request = || PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "boom".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        // Two failures is a 100% failure ratio, but below `min_requests`,
+        // so the error-rate breaker must not trip yet: the error surfaced
+        // is still the backend's own, not "circuit breaker open".
+        for _ in 0..2 {
+            let err = service
+                .predict(failing_request())
+                .await
+                .expect_err("scripted backend failure should surface");
+            assert!(err.to_string().contains("simulated backend failure"));
+        }
+
+        // Two more failures reach `min_requests` at a 100% failure ratio,
+        // well above `failure_rate_threshold`: the breaker should now trip.
+        for _ in 0..2 {
+            let _ = service.predict(failing_request()).await;
+        }
+
+        let err = service
+            .predict(failing_request())
+            .await
+            .expect_err("breaker should be open once volume and ratio thresholds are met");
+        assert!(err.to_string().contains("Circuit breaker open"));
+    }
+
+    /// A `Backend` that always returns a fixed output and counts how many
+    /// times it was called, used to prove cache/retry behavior independent
+    /// of `MockBackend`'s per-input scripting.
+    struct CountingBackend {
+        output: String,
+        calls: Arc<AtomicI64>,
+    }
+
+    #[async_trait::async_trait]
+    impl Backend for CountingBackend {
+        async fn complete(&self, _request: CompletionRequest) -> Result<Completion> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Completion {
+                output: self.output.clone(),
+                input_tokens: 10,
+                output_tokens: 0,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_predict_rejects_and_does_not_cache_invalid_response() {
+        let calls = Arc::new(AtomicI64::new(0));
+        let backend = CountingBackend {
+            output: "   ".to_string(),
+            calls: calls.clone(),
+        };
+        let mut config = test_config();
+        config.models.get_mut("gpt-3.5-turbo").unwrap().max_retries = 2;
+        // High enough that the repeated validation failures below don't
+        // also trip the circuit breaker, which would otherwise reject
+        // later attempts instead of letting them reach the backend.
+        config.circuit_breaker_failure_threshold = 100;
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service")
+            .with_response_validator(Box::new(NonEmptyValidator));
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let err = service
+            .predict(request.clone())
+            .await
+            .expect_err("empty output should be rejected by the validator");
+        assert!(err.to_string().contains("Response validation failed"));
+        // The initial attempt plus both configured retries.
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        // A second identical request still calls the backend again instead
+        // of being served from cache, proving the rejected output was never
+        // stored.
+        let err = service
+            .predict(request)
+            .await
+            .expect_err("empty output should still be rejected on retry");
+        assert!(err.to_string().contains("Response validation failed"));
+        assert_eq!(calls.load(Ordering::SeqCst), 6);
+    }
+
+    #[tokio::test]
+    async fn test_execute_prediction_succeeds_within_timeout_without_retry() {
+        let backend = MockBackend::new().with_delay(Duration::from_millis(120));
+        let mut config = test_config();
+        {
+            let model_config = config.models.get_mut("gpt-3.5-turbo").unwrap();
+            model_config.request_timeout_secs = 1;
+            model_config.max_retries = 3;
+        }
+        // High enough that the timeouts below (were there any) wouldn't
+        // also trip the circuit breaker.
+        config.circuit_breaker_failure_threshold = 100;
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        // The backend's delay is well within the 1s timeout, so this
+        // should succeed without needing a retry.
+        let response = service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect("prediction within the timeout should succeed");
+        assert!(!response.metadata.cached);
+    }
+
+    #[tokio::test]
+    async fn test_execute_prediction_fails_after_exhausting_retries_on_timeout() {
+        let backend = MockBackend::new().with_delay(Duration::from_millis(150));
+        let mut config = test_config();
+        {
+            let model_config = config.models.get_mut("gpt-3.5-turbo").unwrap();
+            model_config.request_timeout_secs = 0;
+            model_config.max_retries = 0;
+        }
+        config.circuit_breaker_failure_threshold = 100;
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let err = service
+            .predict(rate_limited_request("gpt-3.5-turbo"))
+            .await
+            .expect_err("a zero-second timeout with no retries left should fail");
+        assert!(err.to_string().contains("timeout"));
+    }
+
+    #[tokio::test]
+    async fn test_predict_allows_valid_response_with_validator_and_caches() {
+        let backend = MockBackend::new().with_response(
+            "test input",
+            Completion {
+                output: "a real answer".to_string(),
+                input_tokens: 10,
+                output_tokens: 4,
+            },
+        );
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service")
+            .with_response_validator(Box::new(NonEmptyValidator));
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: true,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let response = service
+            .predict(request.clone())
+            .await
+            .expect("valid output should pass the validator");
+        assert_eq!(response.output, "a real answer");
+        assert!(!response.metadata.cached);
+
+        let cached_response = service
+            .predict(request)
+            .await
+            .expect("second request should be served from cache");
+        assert!(cached_response.metadata.cached);
+    }
+
+    #[tokio::test]
+    async fn test_json_shape_validator_rejects_non_matching_output() {
+        let backend = MockBackend::new().with_response(
+            "test input",
+            Completion {
+                output: "not json".to_string(),
+                input_tokens: 1,
+                output_tokens: 1,
+            },
+        );
+        let mut config = test_config();
+        config.models.get_mut("gpt-3.5-turbo").unwrap().max_retries = 0;
+        let schema = serde_json::json!({
+            "type": "object",
+            "required": ["answer"],
+            "properties": { "answer": { "type": "string" } }
+        });
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service")
+            .with_response_validator(Box::new(JsonShapeValidator::new(schema)));
+
+        let request = PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: "gpt-3.5-turbo".to_string(),
+            variant: None,
+            input: "test input".to_string(),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: None,
+        };
+
+        let err = service
+            .predict(request)
+            .await
+            .expect_err("non-JSON output should be rejected by the schema validator");
+        assert!(err.to_string().contains("Response validation failed"));
+    }
+
+    #[tokio::test]
+    async fn test_health_cache_size_tracks_memory_cache_entry_count() {
+        let backend = MockBackend::new().with_default(Completion {
+            output: "output".to_string(),
+            input_tokens: 1,
+            output_tokens: 1,
+        });
+        let service = ProductionDSpyService::new_with_backend(test_config(), Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        assert_eq!(service.health().await.cache_size, 0);
+
+        let mut request = rate_limited_request("gpt-3.5-turbo");
+        request.use_cache = true;
+        service
+            .predict(request)
+            .await
+            .expect("prediction should succeed and populate the memory cache");
+
+        assert_eq!(service.health().await.cache_size, 1);
+    }
+
+    #[tokio::test]
+    async fn test_redis_reconnector_recovers_after_transient_outage() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Reserve a port, then immediately drop the listener so nothing is
+        // listening on it yet — the first `ConnectionManager::new` attempt
+        // inside `redis_reconnector` must fail with connection-refused, the
+        // same way it would against a Redis that's genuinely down.
+        let port = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+
+        // Bring up a minimal fake Redis server after a short delay, standing
+        // in for Redis coming back after an outage. `ConnectionManager::new`
+        // only needs the TCP handshake to succeed (RESP2, no greeting), so
+        // just accepting the connection is enough.
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            let Ok(listener) = tokio::net::TcpListener::bind(("127.0.0.1", port)).await else {
+                return;
+            };
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 1024];
+                    loop {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => break,
+                            Ok(_) => {
+                                if socket.write_all(b"+OK\r\n").await.is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let redis_client = redis::Client::open(format!("redis://127.0.0.1:{}", port)).unwrap();
+        let redis_conn: Arc<tokio::sync::Mutex<Option<ConnectionManager>>> = Arc::new(tokio::sync::Mutex::new(None));
+
+        tokio::time::timeout(
+            Duration::from_secs(10),
+            redis_reconnector(redis_client, redis_conn.clone(), Duration::from_secs(2)),
+        )
+        .await
+        .expect("redis_reconnector should recover once the fake server starts accepting connections");
+
+        assert!(
+            redis_conn.lock().await.is_some(),
+            "the connection should be installed once the fake Redis server becomes reachable"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_persists_memory_cache_to_redis() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // A fake Redis server that's already listening before the service
+        // is constructed, so `new_with_backend`'s single synchronous
+        // `ConnectionManager::new` attempt succeeds and `redis_conn` is
+        // populated from the start.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    break;
+                };
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 1024];
+                    loop {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => break,
+                            Ok(_) => {
+                                if socket.write_all(b"+OK\r\n").await.is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let mut config = test_config();
+        config.redis_url = format!("redis://127.0.0.1:{}", port);
+        let backend = MockBackend::new().with_default(Completion {
+            output: "output".to_string(),
+            input_tokens: 1,
+            output_tokens: 1,
+        });
+        let service = ProductionDSpyService::new_with_backend(config, Box::new(backend))
+            .await
+            .expect("Failed to create service");
+
+        let mut request = rate_limited_request("gpt-3.5-turbo");
+        request.use_cache = true;
+        service
+            .predict(request)
+            .await
+            .expect("prediction should succeed and populate the memory cache");
+
+        let summary = service
+            .shutdown(Duration::from_secs(1))
+            .await
+            .expect("shutdown should complete with no in-flight predictions");
+        assert_eq!(summary.cache_entries_persisted, Some(1));
+    }
 }
@@ -5,15 +5,18 @@
 use axum::{
     extract::{Extension, Json, State},
     http::{HeaderMap, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
     response::{IntoResponse, Response},
     routing::{get, post},
     Router,
 };
 use complete_production_service::{
-    HealthStatus, PredictionRequest, PredictionResponse, ProductionDSpyService, ServiceConfig,
+    CacheCodec, CircuitBreakerMode, HealthStatus, PredictionRequest, PredictionResponse, ProductionDSpyService, ServiceConfig,
 };
+use futures::stream;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -93,6 +96,9 @@ impl IntoResponse for AppError {
             e if e.to_string().contains("timeout") => {
                 (StatusCode::REQUEST_TIMEOUT, "timeout")
             }
+            e if e.to_string().contains("budget exceeded") => {
+                (StatusCode::TOO_MANY_REQUESTS, "budget_exceeded")
+            }
             _ => (StatusCode::INTERNAL_SERVER_ERROR, "internal_error"),
         };
 
@@ -151,6 +157,218 @@ async fn predict_handler(
     Ok(Json(response))
 }
 
+// =============================================================================
+// OpenAI-Compatible API (interop layer over PredictionRequest/PredictionResponse)
+// =============================================================================
+
+/// A single message in an OpenAI-style chat completion request or response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OpenAiChatMessage {
+    role: String,
+    content: String,
+}
+
+/// Subset of the OpenAI `/v1/chat/completions` request body we understand.
+/// Unrecognized fields sent by real OpenAI SDK clients are ignored, since
+/// this is a thin interop layer over `PredictionRequest`, not a full
+/// reimplementation of the OpenAI API surface.
+#[derive(Debug, Clone, Deserialize)]
+struct OpenAiChatCompletionRequest {
+    model: String,
+    messages: Vec<OpenAiChatMessage>,
+    #[serde(default)]
+    stream: bool,
+    /// Non-standard field letting OpenAI-SDK callers still pick an A/B
+    /// testing variant; maps directly onto `PredictionRequest::variant`.
+    #[serde(default)]
+    variant: Option<String>,
+    /// Mapped onto `PredictionRequest::correlation_id` so per-end-user
+    /// tracing survives the translation, same as OpenAI's own `user` field.
+    #[serde(default)]
+    user: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OpenAiUsage {
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    total_tokens: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OpenAiChatChoice {
+    index: u32,
+    message: OpenAiChatMessage,
+    finish_reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OpenAiChatCompletionResponse {
+    id: String,
+    object: String,
+    created: i64,
+    model: String,
+    choices: Vec<OpenAiChatChoice>,
+    usage: OpenAiUsage,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct OpenAiChatDelta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OpenAiChatChunkChoice {
+    index: u32,
+    delta: OpenAiChatDelta,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OpenAiChatCompletionChunk {
+    id: String,
+    object: String,
+    created: i64,
+    model: String,
+    choices: Vec<OpenAiChatChunkChoice>,
+}
+
+/// Flattens an OpenAI-style message list into the single `input` string
+/// `PredictionRequest` expects, since the underlying service has no notion
+/// of multi-turn chat history.
+fn flatten_messages(messages: &[OpenAiChatMessage]) -> String {
+    messages
+        .iter()
+        .map(|m| format!("{}: {}", m.role, m.content))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+impl From<OpenAiChatCompletionRequest> for PredictionRequest {
+    fn from(request: OpenAiChatCompletionRequest) -> Self {
+        PredictionRequest {
+            request_id: Uuid::new_v4().to_string(),
+            model: request.model,
+            variant: request.variant,
+            input: flatten_messages(&request.messages),
+            parameters: HashMap::new(),
+            use_cache: false,
+            priority: 0,
+            correlation_id: request.user,
+        }
+    }
+}
+
+fn to_openai_response(response: &PredictionResponse) -> OpenAiChatCompletionResponse {
+    let prompt_tokens = response.metadata.input_tokens;
+    let completion_tokens = response.metadata.output_tokens;
+    OpenAiChatCompletionResponse {
+        id: format!("chatcmpl-{}", response.request_id),
+        object: "chat.completion".to_string(),
+        created: response.metadata.timestamp.timestamp(),
+        model: response.model.clone(),
+        choices: vec![OpenAiChatChoice {
+            index: 0,
+            message: OpenAiChatMessage {
+                role: "assistant".to_string(),
+                content: response.output.clone(),
+            },
+            finish_reason: "stop".to_string(),
+        }],
+        usage: OpenAiUsage {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens: prompt_tokens + completion_tokens,
+        },
+    }
+}
+
+/// Builds the three SSE chunks OpenAI-SDK streaming clients expect: a
+/// role-only opening delta, one delta carrying the full output (the
+/// underlying service doesn't produce token-level increments, so this is
+/// the one real content chunk), and a closing delta with `finish_reason`.
+fn to_openai_stream_chunks(response: &PredictionResponse) -> Vec<Result<Event, Infallible>> {
+    let id = format!("chatcmpl-{}", response.request_id);
+    let created = response.metadata.timestamp.timestamp();
+
+    let chunk = |delta: OpenAiChatDelta, finish_reason: Option<String>| OpenAiChatCompletionChunk {
+        id: id.clone(),
+        object: "chat.completion.chunk".to_string(),
+        created,
+        model: response.model.clone(),
+        choices: vec![OpenAiChatChunkChoice {
+            index: 0,
+            delta,
+            finish_reason,
+        }],
+    };
+
+    vec![
+        Ok(Event::default()
+            .json_data(chunk(
+                OpenAiChatDelta {
+                    role: Some("assistant".to_string()),
+                    content: None,
+                },
+                None,
+            ))
+            .unwrap()),
+        Ok(Event::default()
+            .json_data(chunk(
+                OpenAiChatDelta {
+                    role: None,
+                    content: Some(response.output.clone()),
+                },
+                None,
+            ))
+            .unwrap()),
+        Ok(Event::default()
+            .json_data(chunk(OpenAiChatDelta::default(), Some("stop".to_string())))
+            .unwrap()),
+        Ok(Event::default().data("[DONE]")),
+    ]
+}
+
+/// POST /v1/chat/completions - OpenAI-compatible chat completions endpoint
+///
+/// Translates an OpenAI chat request into a `PredictionRequest`, runs it
+/// through the same `ProductionDSpyService::predict` path as `/v1/predict`,
+/// and formats the result as an OpenAI chat completion — or, when the
+/// request sets `stream: true`, as an SSE stream of chat completion chunks.
+/// This is an interop layer over the existing service, not a
+/// reimplementation: prediction and circuit breaking behave exactly as they
+/// do for `/v1/predict`, except that caching is off, since the translated
+/// `PredictionRequest` always sets `use_cache: false`.
+async fn chat_completions_handler(
+    State(state): State<AppState>,
+    Json(request): Json<OpenAiChatCompletionRequest>,
+) -> Result<Response, AppError> {
+    let want_stream = request.stream;
+    let model = request.model.clone();
+    let prediction_request: PredictionRequest = request.into();
+
+    info!(
+        request_id = %prediction_request.request_id,
+        model = %model,
+        stream = want_stream,
+        "Received OpenAI-compatible chat completion request"
+    );
+
+    let response = state.service.predict(prediction_request).await?;
+
+    if want_stream {
+        let events = to_openai_stream_chunks(&response);
+        Ok(Sse::new(stream::iter(events))
+            .keep_alive(KeepAlive::default())
+            .into_response())
+    } else {
+        Ok(Json(to_openai_response(&response)).into_response())
+    }
+}
+
 /// GET /health - Health check endpoint
 async fn health_handler(State(state): State<AppState>) -> Json<HealthStatus> {
     let status = state.service.health().await;
@@ -208,6 +426,7 @@ async fn root_handler() -> Json<serde_json::Value> {
         "version": env!("CARGO_PKG_VERSION"),
         "endpoints": {
             "predict": "POST /v1/predict",
+            "chat_completions": "POST /v1/chat/completions",
             "health": "GET /health",
             "ready": "GET /ready",
             "metrics": "GET /metrics",
@@ -225,6 +444,7 @@ fn build_router(state: AppState) -> Router {
     Router::new()
         .route("/", get(root_handler))
         .route("/v1/predict", post(predict_handler))
+        .route("/v1/chat/completions", post(chat_completions_handler))
         .route("/health", get(health_handler))
         .route("/ready", get(ready_handler))
         .route("/metrics", get(metrics_handler))
@@ -311,6 +531,12 @@ fn create_default_service_config() -> anyhow::Result<ServiceConfig> {
         models,
         ab_testing_enabled: false,
         default_variant: "baseline".to_string(),
+        audit_log_path: std::env::var("AUDIT_LOG_PATH").ok(),
+        max_concurrent_predictions: 100,
+        max_queued_predictions: 500,
+        circuit_breaker_mode: CircuitBreakerMode::ConsecutiveFailures,
+        cache_codec: CacheCodec::Json,
+        redaction_enabled: true,
     })
 }
 
@@ -386,12 +612,24 @@ async fn main() -> anyhow::Result<()> {
 
     info!("Server listening on http://{}", addr);
 
-    // Setup graceful shutdown
-    let shutdown_signal = async {
+    // Setup graceful shutdown: stop accepting new predictions and drain
+    // in-flight ones before axum stops accepting new HTTP connections.
+    let shutdown_service = Arc::clone(&state.service);
+    let shutdown_grace = Duration::from_secs(server_config.request_timeout_secs.max(5));
+    let shutdown_signal = async move {
         tokio::signal::ctrl_c()
             .await
             .expect("Failed to install CTRL+C signal handler");
         info!("Received shutdown signal");
+        match shutdown_service.shutdown(shutdown_grace).await {
+            Ok(summary) => info!(
+                completed = summary.completed,
+                abandoned = summary.abandoned,
+                cache_entries_persisted = ?summary.cache_entries_persisted,
+                "Graceful service shutdown finished"
+            ),
+            Err(e) => error!(error = %e, "Error during graceful service shutdown"),
+        }
     };
 
     // Start server
@@ -513,4 +751,76 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_chat_completions_endpoint_non_streaming() {
+        let app = create_test_app().await;
+
+        let request_body = serde_json::json!({
+            "model": "gpt-3.5-turbo",
+            "messages": [
+                {"role": "system", "content": "You are a helpful assistant."},
+                {"role": "user", "content": "test input"}
+            ],
+            "stream": false
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/v1/chat/completions")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed["object"], "chat.completion");
+        assert_eq!(parsed["choices"][0]["message"]["role"], "assistant");
+        assert!(parsed["usage"]["total_tokens"].is_u64());
+    }
+
+    #[tokio::test]
+    async fn test_chat_completions_endpoint_streaming() {
+        let app = create_test_app().await;
+
+        let request_body = serde_json::json!({
+            "model": "gpt-3.5-turbo",
+            "messages": [{"role": "user", "content": "test input"}],
+            "stream": true
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/v1/chat/completions")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "text/event-stream"
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("chat.completion.chunk"));
+        assert!(body.contains("[DONE]"));
+    }
 }
@@ -0,0 +1,132 @@
+//! Interactive REPL for the Production DSpy Service
+//!
+//! Reads lines from stdin, sends each as a `PredictionRequest` to a single
+//! shared `ProductionDSpyService`, and prints the output plus latency/cost
+//! metadata. A handful of `:`-prefixed commands expose the rest of the
+//! service's public API for quick local smoke-testing.
+
+use complete_production_service::{
+    CacheCodec, CircuitBreakerMode, ModelConfig, PredictionRequest, ProductionDSpyService, ServiceConfig,
+};
+use std::collections::HashMap;
+use std::io::Write;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use uuid::Uuid;
+
+const DEFAULT_MODEL: &str = "gpt-3.5-turbo";
+
+fn default_service_config() -> ServiceConfig {
+    let mut models = HashMap::new();
+    models.insert(
+        DEFAULT_MODEL.to_string(),
+        ModelConfig {
+            name: DEFAULT_MODEL.to_string(),
+            cost_per_1k_input_tokens: 0.0015,
+            cost_per_1k_output_tokens: 0.002,
+            max_retries: 3,
+            request_timeout_secs: 30,
+        },
+    );
+
+    ServiceConfig {
+        service_name: "complete-production-service-repl".to_string(),
+        service_version: env!("CARGO_PKG_VERSION").to_string(),
+        redis_url: std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://localhost:6379".to_string()),
+        memory_cache_size: 10_000,
+        memory_cache_ttl_secs: 300,
+        redis_cache_ttl_secs: 3600,
+        circuit_breaker_failure_threshold: 5,
+        circuit_breaker_success_threshold: 2,
+        circuit_breaker_timeout_secs: 60,
+        models,
+        ab_testing_enabled: false,
+        default_variant: "baseline".to_string(),
+        audit_log_path: std::env::var("AUDIT_LOG_PATH").ok(),
+        max_concurrent_predictions: 100,
+        max_queued_predictions: 500,
+        circuit_breaker_mode: CircuitBreakerMode::ConsecutiveFailures,
+        cache_codec: CacheCodec::Json,
+        redaction_enabled: true,
+    }
+}
+
+fn print_prompt() {
+    print!("dspy> ");
+    let _ = std::io::stdout().flush();
+}
+
+fn print_help() {
+    println!("Commands:");
+    println!("  :health   show service health status");
+    println!("  :metrics  dump Prometheus metrics");
+    println!("  :cost     show cost metrics per model/variant");
+    println!("  :help     show this message");
+    println!("  Ctrl-D    exit");
+    println!("Anything else is sent to the service as a prediction input.");
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let service = ProductionDSpyService::new(default_service_config()).await?;
+
+    println!("Connected to complete-production-service REPL. Type :help for commands.");
+    print_prompt();
+
+    let stdin = tokio::io::stdin();
+    let mut lines = BufReader::new(stdin).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        let line = line.trim();
+
+        if line.is_empty() {
+            print_prompt();
+            continue;
+        }
+
+        match line {
+            ":help" => print_help(),
+            ":health" => {
+                let status = service.health().await;
+                println!("{}", serde_json::to_string_pretty(&status)?);
+            }
+            ":metrics" => match service.metrics() {
+                Ok(metrics) => println!("{metrics}"),
+                Err(e) => eprintln!("Failed to gather metrics: {e}"),
+            },
+            ":cost" => {
+                let metrics = service.cost_metrics();
+                println!("{}", serde_json::to_string_pretty(&metrics)?);
+            }
+            _ => {
+                let request = PredictionRequest {
+                    request_id: Uuid::new_v4().to_string(),
+                    model: DEFAULT_MODEL.to_string(),
+                    variant: None,
+                    input: line.to_string(),
+                    parameters: HashMap::new(),
+                    use_cache: true,
+                    priority: 0,
+                    correlation_id: None,
+                };
+
+                match service.predict(request).await {
+                    Ok(response) => {
+                        println!("{}", response.output);
+                        println!(
+                            "  [latency={}ms cached={} cost=${:.6}]",
+                            response.metadata.latency_ms,
+                            response.metadata.cached,
+                            response.metadata.cost_usd
+                        );
+                    }
+                    Err(e) => eprintln!("Prediction failed: {e}"),
+                }
+            }
+        }
+
+        print_prompt();
+    }
+
+    println!("\nGoodbye.");
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use anyhow::{anyhow, Context, Result};
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::path::Path;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
 /// Model pricing information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +46,18 @@ impl ModelPricing {
     }
 }
 
+/// Whether a budget period is a rolling window relative to now, or aligned
+/// to the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BudgetResetPeriod {
+    /// A rolling window relative to the current moment, e.g. "cost in the
+    /// last 24 hours".
+    Rolling,
+    /// A window aligned to the calendar: midnight UTC for daily budgets,
+    /// the 1st of the month UTC for monthly budgets.
+    Calendar,
+}
+
 /// Budget configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CostBudget {
@@ -53,6 +67,14 @@ pub struct CostBudget {
     pub monthly_limit_usd: f64,
     /// Per-user daily limit in USD
     pub per_user_daily_limit_usd: f64,
+    /// Per-user monthly limit in USD
+    pub per_user_monthly_limit_usd: f64,
+    /// Whether the per-user daily/monthly windows reported by
+    /// `CostTracker::user_budget_status` are rolling or calendar-aligned.
+    /// `CostTracker::check_budget` does not read it: it checks the per-user
+    /// daily limit over a rolling 24 hours and does not check the per-user
+    /// monthly limit.
+    pub reset_period: BudgetResetPeriod,
     /// Alert threshold as percentage (0.0-1.0)
     pub alert_threshold_percent: f64,
 }
@@ -63,23 +85,32 @@ impl CostBudget {
     }
 
     /// Check if daily limit is exceeded
+    ///
+    /// Compares in fixed point (see [`UsdCents`]) rather than as raw `f64`s,
+    /// so a cost that is exactly at the limit isn't misjudged due to float
+    /// representation error.
     pub fn is_daily_exceeded(&self, current_cost: f64) -> bool {
-        current_cost >= self.daily_limit_usd
+        UsdCents::from_usd(current_cost) >= UsdCents::from_usd(self.daily_limit_usd)
     }
 
     /// Check if monthly limit is exceeded
     pub fn is_monthly_exceeded(&self, current_cost: f64) -> bool {
-        current_cost >= self.monthly_limit_usd
+        UsdCents::from_usd(current_cost) >= UsdCents::from_usd(self.monthly_limit_usd)
     }
 
     /// Check if user daily limit is exceeded
     pub fn is_user_daily_exceeded(&self, current_cost: f64) -> bool {
-        current_cost >= self.per_user_daily_limit_usd
+        UsdCents::from_usd(current_cost) >= UsdCents::from_usd(self.per_user_daily_limit_usd)
+    }
+
+    /// Check if user monthly limit is exceeded
+    pub fn is_user_monthly_exceeded(&self, current_cost: f64) -> bool {
+        UsdCents::from_usd(current_cost) >= UsdCents::from_usd(self.per_user_monthly_limit_usd)
     }
 
     /// Check if alert threshold is reached
     pub fn should_alert(&self, current_cost: f64, limit: f64) -> bool {
-        current_cost >= (limit * self.alert_threshold_percent)
+        UsdCents::from_usd(current_cost) >= UsdCents::from_usd(limit * self.alert_threshold_percent)
     }
 
     /// Get remaining budget
@@ -107,6 +138,8 @@ pub struct CostBudgetBuilder {
     daily_limit: Option<f64>,
     monthly_limit: Option<f64>,
     per_user_daily_limit: Option<f64>,
+    per_user_monthly_limit: Option<f64>,
+    reset_period: Option<BudgetResetPeriod>,
     alert_threshold: Option<f64>,
 }
 
@@ -126,6 +159,16 @@ impl CostBudgetBuilder {
         self
     }
 
+    pub fn per_user_monthly_limit(mut self, limit: f64) -> Self {
+        self.per_user_monthly_limit = Some(limit);
+        self
+    }
+
+    pub fn reset_period(mut self, period: BudgetResetPeriod) -> Self {
+        self.reset_period = Some(period);
+        self
+    }
+
     pub fn alert_threshold(mut self, threshold: f64) -> Self {
         self.alert_threshold = Some(threshold);
         self
@@ -136,13 +179,15 @@ impl CostBudgetBuilder {
             daily_limit_usd: self.daily_limit.unwrap_or(100.0),
             monthly_limit_usd: self.monthly_limit.unwrap_or(2000.0),
             per_user_daily_limit_usd: self.per_user_daily_limit.unwrap_or(10.0),
+            per_user_monthly_limit_usd: self.per_user_monthly_limit.unwrap_or(200.0),
+            reset_period: self.reset_period.unwrap_or(BudgetResetPeriod::Rolling),
             alert_threshold_percent: self.alert_threshold.unwrap_or(0.80),
         }
     }
 }
 
 /// Alert level for budget notifications
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AlertLevel {
     Warning,
     Critical,
@@ -179,6 +224,105 @@ impl BudgetAlert {
     }
 }
 
+/// A user's current budget usage and time until each period resets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBudgetStatus {
+    pub daily_cost: f64,
+    pub daily_limit: f64,
+    pub daily_remaining: f64,
+    pub seconds_until_daily_reset: i64,
+    pub monthly_cost: f64,
+    pub monthly_limit: f64,
+    pub monthly_remaining: f64,
+    pub seconds_until_monthly_reset: i64,
+}
+
+/// How often a caller driving periodic snapshots (e.g. a background
+/// scheduler) should call [`CostTracker::snapshot`], and how many snapshots
+/// [`CostTracker::snapshot_history`] retains before the oldest are dropped.
+/// `CostTracker` doesn't schedule snapshots itself; `interval` is advisory
+/// for the caller's loop.
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotConfig {
+    pub interval: Duration,
+    pub retention: usize,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::minutes(5),
+            retention: 288, // a day of 5-minute snapshots
+        }
+    }
+}
+
+/// A point-in-time rollup of aggregate cost state, cheap enough to compute
+/// and retain at a fixed interval for dashboards that want a time series
+/// without replaying every [`CostRecord`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostSnapshot {
+    pub timestamp: DateTime<Utc>,
+    pub daily_cost_usd: f64,
+    pub monthly_cost_usd: f64,
+    pub daily_budget_utilization_percent: f64,
+    pub monthly_budget_utilization_percent: f64,
+    pub by_model: HashMap<String, f64>,
+    pub by_user: HashMap<String, f64>,
+}
+
+/// A USD amount stored as an integer count of micro-cents (1e-6 of a cent,
+/// i.e. 1e-8 of a dollar), rather than an `f64` dollar value.
+///
+/// Summing millions of tiny per-request `f64` costs drifts away from the
+/// exact billing total because each addition rounds to the nearest
+/// representable float. Converting to this fixed-point representation at
+/// the point of accumulation and back to `f64` only at API boundaries
+/// (reports, CLI output, logging) keeps aggregate totals exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct UsdCents(i64);
+
+impl UsdCents {
+    pub const ZERO: Self = Self(0);
+
+    /// Convert a dollar amount (e.g. `0.0034` for $0.0034) to fixed point,
+    /// rounding to the nearest micro-cent.
+    pub fn from_usd(usd: f64) -> Self {
+        Self((usd * 100_000_000.0).round() as i64)
+    }
+
+    /// Convert back to a dollar amount for display or external APIs.
+    pub fn to_usd(self) -> f64 {
+        self.0 as f64 / 100_000_000.0
+    }
+}
+
+impl std::ops::Add for UsdCents {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::AddAssign for UsdCents {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl std::ops::Sub for UsdCents {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl std::iter::Sum for UsdCents {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
 /// Individual cost record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CostRecord {
@@ -219,7 +363,10 @@ impl CostRecord {
 /// Aggregated cost statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CostStats {
-    pub total_cost: f64,
+    /// Total cost, accumulated in fixed point to avoid `f64` drift over many
+    /// small additions. Use [`CostStats::total_cost_usd`] to read it back as
+    /// a dollar amount.
+    pub total_cost: UsdCents,
     pub total_requests: usize,
     pub total_input_tokens: usize,
     pub total_output_tokens: usize,
@@ -230,7 +377,7 @@ pub struct CostStats {
 impl CostStats {
     pub fn new() -> Self {
         Self {
-            total_cost: 0.0,
+            total_cost: UsdCents::ZERO,
             total_requests: 0,
             total_input_tokens: 0,
             total_output_tokens: 0,
@@ -239,14 +386,35 @@ impl CostStats {
         }
     }
 
+    /// Total cost as a dollar amount.
+    pub fn total_cost_usd(&self) -> f64 {
+        self.total_cost.to_usd()
+    }
+
     pub fn add_record(&mut self, record: &CostRecord) {
-        self.total_cost += record.cost_usd;
+        self.total_cost += UsdCents::from_usd(record.cost_usd);
         self.total_requests += 1;
         self.total_input_tokens += record.input_tokens;
         self.total_output_tokens += record.output_tokens;
 
         if self.total_requests > 0 {
-            self.average_cost_per_request = self.total_cost / self.total_requests as f64;
+            self.average_cost_per_request = self.total_cost.to_usd() / self.total_requests as f64;
+            self.average_tokens_per_request =
+                (self.total_input_tokens + self.total_output_tokens) as f64
+                / self.total_requests as f64;
+        }
+    }
+
+    /// Fold another bucket's totals into this one, e.g. combining a live
+    /// in-memory bucket with a compacted historical one.
+    pub fn merge(&mut self, other: &CostStats) {
+        self.total_cost += other.total_cost;
+        self.total_requests += other.total_requests;
+        self.total_input_tokens += other.total_input_tokens;
+        self.total_output_tokens += other.total_output_tokens;
+
+        if self.total_requests > 0 {
+            self.average_cost_per_request = self.total_cost.to_usd() / self.total_requests as f64;
             self.average_tokens_per_request =
                 (self.total_input_tokens + self.total_output_tokens) as f64
                 / self.total_requests as f64;
@@ -298,7 +466,7 @@ impl CostReport {
              \n\
              Top 5 Users:\n{}",
             duration.num_days(),
-            self.overall_stats.total_cost,
+            self.overall_stats.total_cost_usd(),
             self.overall_stats.total_requests,
             self.overall_stats.total_input_tokens + self.overall_stats.total_output_tokens,
             self.overall_stats.total_input_tokens,
@@ -331,12 +499,101 @@ struct PricingDatabase {
     default_budget: CostBudget,
 }
 
+/// On-disk representation round-tripped by `CostTracker::persist`/`load`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedCostData {
+    records: Vec<CostRecord>,
+    compacted: HashMap<NaiveDate, CostStats>,
+}
+
+/// A single registered alert handler.
+type AlertHandler = Box<dyn Fn(&BudgetAlert) + Send + Sync>;
+/// Handlers shared between the dispatching thread and the background
+/// thread that actually invokes them.
+type SharedAlertHandlers = Arc<Mutex<Vec<AlertHandler>>>;
+/// Last-fired timestamp per (level, limit) threshold, used for cooldown
+/// de-duplication.
+type AlertCooldowns = Arc<Mutex<HashMap<(AlertLevel, u64), DateTime<Utc>>>>;
+
+/// Dispatches budget alerts to registered handlers on a dedicated
+/// background thread, so a slow handler (e.g. an HTTP webhook) never adds
+/// latency to the request that tripped the alert. Repeated alerts for the
+/// same (level, limit) threshold are deduplicated within `cooldown`, so a
+/// sustained overage doesn't fire a handler on every single request.
+struct AlertDispatcher {
+    sender: mpsc::Sender<BudgetAlert>,
+    handlers: SharedAlertHandlers,
+    last_fired: AlertCooldowns,
+    cooldown: Duration,
+}
+
+impl AlertDispatcher {
+    fn new() -> Self {
+        Self::with_cooldown(Duration::minutes(5))
+    }
+
+    fn with_cooldown(cooldown: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel::<BudgetAlert>();
+        let handlers: SharedAlertHandlers = Arc::new(Mutex::new(Vec::new()));
+        let worker_handlers = Arc::clone(&handlers);
+
+        thread::spawn(move || {
+            while let Ok(alert) = receiver.recv() {
+                for handler in worker_handlers.lock().unwrap().iter() {
+                    handler(&alert);
+                }
+            }
+        });
+
+        Self {
+            sender,
+            handlers,
+            last_fired: Arc::new(Mutex::new(HashMap::new())),
+            cooldown,
+        }
+    }
+
+    fn register<F>(&self, handler: F)
+    where
+        F: Fn(&BudgetAlert) + Send + Sync + 'static,
+    {
+        self.handlers.lock().unwrap().push(Box::new(handler));
+    }
+
+    /// Hand `alert` off to the background thread unless an alert for the
+    /// same threshold already fired within `cooldown`.
+    fn dispatch(&self, alert: BudgetAlert) {
+        let key = (alert.level, alert.limit.to_bits());
+        {
+            let mut last_fired = self.last_fired.lock().unwrap();
+            if let Some(last) = last_fired.get(&key) {
+                if alert.timestamp - *last < self.cooldown {
+                    return;
+                }
+            }
+            last_fired.insert(key, alert.timestamp);
+        }
+        // The channel is unbounded, so this never blocks on a slow handler
+        // even if the background thread is still working through a backlog.
+        let _ = self.sender.send(alert);
+    }
+}
+
 /// Main cost tracker
 pub struct CostTracker {
     pricing: HashMap<String, ModelPricing>,
     budget: CostBudget,
     records: Vec<CostRecord>,
-    alert_handlers: Vec<Box<dyn Fn(&BudgetAlert) + Send + Sync>>,
+    /// Daily totals for records pruned by `compact`, keyed by UTC date.
+    /// Historical totals survive compaction; per-model/user/endpoint
+    /// breakdowns do not.
+    compacted: HashMap<NaiveDate, CostStats>,
+    alert_dispatcher: AlertDispatcher,
+    snapshot_config: SnapshotConfig,
+    /// Kept behind its own lock, separate from everything else on
+    /// `CostTracker`, so recording a snapshot never contends with the
+    /// record-tracking path.
+    snapshot_history: Mutex<VecDeque<CostSnapshot>>,
 }
 
 impl CostTracker {
@@ -352,7 +609,10 @@ impl CostTracker {
             pricing: db.models,
             budget: db.default_budget,
             records: Vec::new(),
-            alert_handlers: Vec::new(),
+            compacted: HashMap::new(),
+            alert_dispatcher: AlertDispatcher::new(),
+            snapshot_config: SnapshotConfig::default(),
+            snapshot_history: Mutex::new(VecDeque::new()),
         })
     }
 
@@ -386,12 +646,20 @@ impl CostTracker {
         self.pricing.get(model)
     }
 
-    /// Register alert handler
+    /// Register alert handler. Handlers run on a dedicated background
+    /// thread, off the request path that tripped the alert, so a slow
+    /// handler (e.g. an HTTP webhook) never adds latency to `check_budget`.
     pub fn on_alert<F>(&mut self, handler: F)
     where
         F: Fn(&BudgetAlert) + Send + Sync + 'static,
     {
-        self.alert_handlers.push(Box::new(handler));
+        self.alert_dispatcher.register(handler);
+    }
+
+    /// Override the default 5-minute de-duplication window for repeated
+    /// alerts on the same (level, limit) threshold.
+    pub fn set_alert_cooldown(&mut self, cooldown: Duration) {
+        self.alert_dispatcher.cooldown = cooldown;
     }
 
     /// Track a prediction and return its cost
@@ -417,8 +685,10 @@ impl CostTracker {
             ));
         }
 
-        // Calculate cost
-        let cost = pricing.calculate_cost(input_tokens, output_tokens);
+        // Calculate cost, quantizing to the nearest micro-cent so this
+        // record lands on the same fixed-point grid used when accumulating
+        // many records together (see `UsdCents`).
+        let cost = UsdCents::from_usd(pricing.calculate_cost(input_tokens, output_tokens)).to_usd();
 
         // Record
         let record = CostRecord::new(
@@ -491,20 +761,23 @@ impl CostTracker {
     }
 
     fn trigger_alert(&self, alert: BudgetAlert) {
-        for handler in &self.alert_handlers {
-            handler(&alert);
-        }
+        self.alert_dispatcher.dispatch(alert);
     }
 
     /// Get total cost for a time period
+    ///
+    /// Sums in fixed point (see [`UsdCents`]) so the budget comparisons that
+    /// consume this total don't drift from the exact billing amount over
+    /// many small records, then converts back to dollars at the boundary.
     fn get_cost_for_period(&self, duration: Duration, user_id: Option<&str>) -> f64 {
         let cutoff = Utc::now() - duration;
         self.records
             .iter()
             .filter(|r| r.timestamp >= cutoff)
             .filter(|r| user_id.map_or(true, |u| r.user_id == u))
-            .map(|r| r.cost_usd)
-            .sum()
+            .map(|r| UsdCents::from_usd(r.cost_usd))
+            .sum::<UsdCents>()
+            .to_usd()
     }
 
     /// Get daily cost
@@ -517,6 +790,94 @@ impl CostTracker {
         self.get_cost_for_period(Duration::days(30), user_id)
     }
 
+    /// Get a user's budget usage and time until each period resets.
+    /// `CostBudget::reset_period` controls whether the daily/monthly windows
+    /// are rolling (relative to now) or calendar-aligned (midnight UTC for
+    /// daily, the 1st of the month UTC for monthly).
+    pub fn user_budget_status(&self, user_id: &str) -> UserBudgetStatus {
+        let now = Utc::now();
+
+        let (daily_cutoff, daily_reset_at) = match self.budget.reset_period {
+            BudgetResetPeriod::Rolling => (now - Duration::days(1), now + Duration::days(1)),
+            BudgetResetPeriod::Calendar => {
+                let start = now.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc();
+                (start, start + Duration::days(1))
+            }
+        };
+
+        let (monthly_cutoff, monthly_reset_at) = match self.budget.reset_period {
+            BudgetResetPeriod::Rolling => (now - Duration::days(30), now + Duration::days(30)),
+            BudgetResetPeriod::Calendar => {
+                let start = NaiveDate::from_ymd_opt(now.year(), now.month(), 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_utc();
+                let (next_year, next_month) = if now.month() == 12 {
+                    (now.year() + 1, 1)
+                } else {
+                    (now.year(), now.month() + 1)
+                };
+                let next_start = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_utc();
+                (start, next_start)
+            }
+        };
+
+        let cost_since = |cutoff: DateTime<Utc>| -> f64 {
+            self.records
+                .iter()
+                .filter(|r| r.user_id == user_id && r.timestamp >= cutoff)
+                .map(|r| r.cost_usd)
+                .sum()
+        };
+
+        let daily_cost = cost_since(daily_cutoff);
+        let monthly_cost = cost_since(monthly_cutoff);
+
+        UserBudgetStatus {
+            daily_cost,
+            daily_limit: self.budget.per_user_daily_limit_usd,
+            daily_remaining: (self.budget.per_user_daily_limit_usd - daily_cost).max(0.0),
+            seconds_until_daily_reset: (daily_reset_at - now).num_seconds().max(0),
+            monthly_cost,
+            monthly_limit: self.budget.per_user_monthly_limit_usd,
+            monthly_remaining: (self.budget.per_user_monthly_limit_usd - monthly_cost).max(0.0),
+            seconds_until_monthly_reset: (monthly_reset_at - now).num_seconds().max(0),
+        }
+    }
+
+    /// Get cost per bucket over a window, e.g. hourly or weekly rollups for
+    /// dashboards. Buckets are aligned to bucket-size boundaries (hourly
+    /// buckets start on the hour) and empty buckets are zero-filled so
+    /// chart x-axes stay continuous. Returned in ascending time order.
+    pub fn cost_time_series(&self, bucket: Duration, window: Duration) -> Vec<(DateTime<Utc>, f64)> {
+        let bucket_secs = bucket.num_seconds().max(1);
+        let now = Utc::now();
+        let window_start = now - window;
+
+        let aligned_start_secs = (window_start.timestamp().div_euclid(bucket_secs)) * bucket_secs;
+        let aligned_start = DateTime::from_timestamp(aligned_start_secs, 0).unwrap_or(window_start);
+
+        let mut series = Vec::new();
+        let mut bucket_start = aligned_start;
+        while bucket_start < now {
+            let bucket_end = bucket_start + bucket;
+            let cost: f64 = self.records
+                .iter()
+                .filter(|r| r.timestamp >= bucket_start && r.timestamp < bucket_end)
+                .map(|r| r.cost_usd)
+                .sum();
+            series.push((bucket_start, cost));
+            bucket_start = bucket_end;
+        }
+
+        series
+    }
+
     /// Aggregate costs by model
     pub fn aggregate_by_model(&self, duration: Duration) -> HashMap<String, f64> {
         let cutoff = Utc::now() - duration;
@@ -559,6 +920,37 @@ impl CostTracker {
         user_id: Option<&str>,
         model: Option<&str>,
         duration: Duration,
+    ) -> Result<CostReport> {
+        self.generate_report_impl(user_id, model, duration, None)
+    }
+
+    /// Generate a cost report whose model-downgrade recommendations are
+    /// informed by per-model quality scores, rather than cost alone. A
+    /// downgrade is only recommended when a cheaper model's quality score is
+    /// within `quality_tolerance` of the expensive model's score; savings are
+    /// projected from `aggregate_by_model` and the pricing table.
+    pub fn generate_report_with_quality(
+        &self,
+        user_id: Option<&str>,
+        model: Option<&str>,
+        duration: Duration,
+        quality_scores: &HashMap<String, f64>,
+        quality_tolerance: f64,
+    ) -> Result<CostReport> {
+        self.generate_report_impl(
+            user_id,
+            model,
+            duration,
+            Some((quality_scores, quality_tolerance)),
+        )
+    }
+
+    fn generate_report_impl(
+        &self,
+        user_id: Option<&str>,
+        model: Option<&str>,
+        duration: Duration,
+        quality: Option<(&HashMap<String, f64>, f64)>,
     ) -> Result<CostReport> {
         let cutoff = Utc::now() - duration;
         let period_start = cutoff;
@@ -579,6 +971,17 @@ impl CostTracker {
             report.overall_stats.add_record(record);
         }
 
+        // Fold in compacted historical buckets within the window. Compacted
+        // buckets have no per-model/user/endpoint breakdown, so they can
+        // only contribute to the unfiltered overall total.
+        if user_id.is_none() && model.is_none() {
+            for (day, stats) in &self.compacted {
+                if day.and_hms_opt(0, 0, 0).unwrap().and_utc() >= cutoff {
+                    report.overall_stats.merge(stats);
+                }
+            }
+        }
+
         // By model
         for record in &filtered_records {
             report.by_model
@@ -606,7 +1009,7 @@ impl CostTracker {
         // Top users
         let mut user_costs: Vec<_> = report.by_user
             .iter()
-            .map(|(k, v)| (k.clone(), v.total_cost))
+            .map(|(k, v)| (k.clone(), v.total_cost_usd()))
             .collect();
         user_costs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
         report.top_users = user_costs;
@@ -614,32 +1017,49 @@ impl CostTracker {
         // Top models
         let mut model_costs: Vec<_> = report.by_model
             .iter()
-            .map(|(k, v)| (k.clone(), v.total_cost))
+            .map(|(k, v)| (k.clone(), v.total_cost_usd()))
             .collect();
         model_costs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
         report.top_models = model_costs;
 
         // Generate recommendations
-        report.recommendations = self.generate_recommendations(&report);
+        report.recommendations = self.generate_recommendations(&report, quality);
 
         Ok(report)
     }
 
-    fn generate_recommendations(&self, report: &CostReport) -> Vec<String> {
+    fn generate_recommendations(
+        &self,
+        report: &CostReport,
+        quality: Option<(&HashMap<String, f64>, f64)>,
+    ) -> Vec<String> {
         let mut recommendations = Vec::new();
 
         // Check for expensive models
         if let Some((expensive_model, cost)) = report.top_models.first() {
-            if *cost > report.overall_stats.total_cost * 0.5 {
-                if expensive_model.contains("gpt-4") && !expensive_model.contains("turbo") {
-                    recommendations.push(format!(
-                        "Consider switching from {} to GPT-4 Turbo for a 67% cost reduction",
-                        expensive_model
-                    ));
-                } else if expensive_model.contains("claude-3-opus") {
-                    recommendations.push(
-                        "Consider using Claude 3 Sonnet for less complex tasks (80% cost reduction)".to_string()
-                    );
+            if *cost > report.overall_stats.total_cost_usd() * 0.5 {
+                match quality {
+                    Some((quality_scores, tolerance)) => {
+                        if let Some(rec) = self.quality_aware_downgrade_recommendation(
+                            expensive_model,
+                            quality_scores,
+                            tolerance,
+                        ) {
+                            recommendations.push(rec);
+                        }
+                    }
+                    None => {
+                        if expensive_model.contains("gpt-4") && !expensive_model.contains("turbo") {
+                            recommendations.push(format!(
+                                "Consider switching from {} to GPT-4 Turbo for a 67% cost reduction (cost-only heuristic, no quality data provided)",
+                                expensive_model
+                            ));
+                        } else if expensive_model.contains("claude-3-opus") {
+                            recommendations.push(
+                                "Consider using Claude 3 Sonnet for less complex tasks (80% cost reduction) (cost-only heuristic, no quality data provided)".to_string()
+                            );
+                        }
+                    }
                 }
             }
         }
@@ -653,11 +1073,11 @@ impl CostTracker {
 
         // Check user distribution
         if let Some((top_user, cost)) = report.top_users.first() {
-            if *cost > report.overall_stats.total_cost * 0.7 {
+            if *cost > report.overall_stats.total_cost_usd() * 0.7 {
                 recommendations.push(format!(
                     "User '{}' accounts for {:.0}% of costs. Consider implementing user-specific quotas",
                     top_user,
-                    (*cost / report.overall_stats.total_cost) * 100.0
+                    (*cost / report.overall_stats.total_cost_usd()) * 100.0
                 ));
             }
         }
@@ -685,6 +1105,47 @@ impl CostTracker {
         recommendations
     }
 
+    /// Find the cheapest model whose quality score is within `tolerance` of
+    /// `expensive_model`'s score and recommend it, quantifying the projected
+    /// monthly savings using the last 30 days of `aggregate_by_model` and the
+    /// pricing table. Returns `None` if either model has no quality score,
+    /// no pricing, or no cheaper-and-good-enough alternative exists.
+    fn quality_aware_downgrade_recommendation(
+        &self,
+        expensive_model: &str,
+        quality_scores: &HashMap<String, f64>,
+        tolerance: f64,
+    ) -> Option<String> {
+        let expensive_quality = *quality_scores.get(expensive_model)?;
+        let expensive_pricing = self.pricing.get(expensive_model)?;
+
+        let (cheaper_model, cheaper_pricing) = self.pricing
+            .iter()
+            .filter(|(name, _)| name.as_str() != expensive_model)
+            .filter(|(name, _)| {
+                quality_scores
+                    .get(name.as_str())
+                    .is_some_and(|q| *q >= expensive_quality - tolerance)
+            })
+            .filter(|(_, pricing)| pricing.cost_per_token() < expensive_pricing.cost_per_token())
+            .min_by(|(_, a), (_, b)| a.cost_per_token().partial_cmp(&b.cost_per_token()).unwrap())?;
+
+        let monthly_costs = self.aggregate_by_model(Duration::days(30));
+        let expensive_monthly_cost = *monthly_costs.get(expensive_model).unwrap_or(&0.0);
+        let savings_ratio = 1.0 - (cheaper_pricing.cost_per_token() / expensive_pricing.cost_per_token());
+        let projected_monthly_savings = expensive_monthly_cost * savings_ratio;
+
+        Some(format!(
+            "Consider switching from {} (quality {:.2}) to {} (quality {:.2}, within tolerance {:.2}) for a projected ${:.2}/month savings",
+            expensive_model,
+            expensive_quality,
+            cheaper_model,
+            quality_scores.get(cheaper_model).copied().unwrap_or(0.0),
+            tolerance,
+            projected_monthly_savings
+        ))
+    }
+
     /// Forecast monthly cost based on current trend
     pub fn forecast_monthly_cost(&self) -> Result<f64> {
         let last_7_days_cost = self.get_cost_for_period(Duration::days(7), None);
@@ -724,6 +1185,47 @@ impl CostTracker {
         self.records.retain(|r| r.timestamp >= cutoff);
     }
 
+    /// Collapse records older than `older_than` into daily `CostStats`
+    /// buckets so historical totals survive while raw per-request detail is
+    /// pruned. Safe to call repeatedly; buckets accumulate across calls.
+    pub fn compact(&mut self, older_than: Duration) {
+        let cutoff = Utc::now() - older_than;
+        let (old, kept): (Vec<_>, Vec<_>) = self.records.drain(..).partition(|r| r.timestamp < cutoff);
+        self.records = kept;
+
+        for record in &old {
+            self.compacted
+                .entry(record.timestamp.date_naive())
+                .or_insert_with(CostStats::new)
+                .add_record(record);
+        }
+    }
+
+    /// Write all live records and compacted daily buckets to `path` so they
+    /// survive a restart.
+    pub fn persist<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let data = PersistedCostData {
+            records: self.records.clone(),
+            compacted: self.compacted.clone(),
+        };
+
+        let json = serde_json::to_string_pretty(&data).context("Failed to serialize cost data")?;
+        fs::write(path, json).context("Failed to write cost data file")?;
+        Ok(())
+    }
+
+    /// Load records and compacted daily buckets previously written by
+    /// `persist`, replacing whatever this tracker currently holds.
+    pub fn load<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let content = fs::read_to_string(path.as_ref()).context("Failed to read cost data file")?;
+        let data: PersistedCostData = serde_json::from_str(&content)
+            .context("Failed to parse cost data file")?;
+
+        self.records = data.records;
+        self.compacted = data.compacted;
+        Ok(())
+    }
+
     /// Get current budget
     pub fn get_budget(&self) -> &CostBudget {
         &self.budget
@@ -733,11 +1235,61 @@ impl CostTracker {
     pub fn set_budget(&mut self, budget: CostBudget) {
         self.budget = budget;
     }
+
+    /// Get the current snapshot interval/retention configuration
+    pub fn get_snapshot_config(&self) -> SnapshotConfig {
+        self.snapshot_config
+    }
+
+    /// Update the snapshot interval/retention configuration, trimming
+    /// `snapshot_history` immediately if the new retention is smaller.
+    pub fn set_snapshot_config(&mut self, config: SnapshotConfig) {
+        self.snapshot_config = config;
+        let mut history = self.snapshot_history.lock().unwrap();
+        while history.len() > config.retention {
+            history.pop_front();
+        }
+    }
+
+    /// Capture a [`CostSnapshot`] of current daily/monthly totals, per-model
+    /// and per-user aggregates, and budget utilization, and append it to
+    /// `snapshot_history`, evicting the oldest entry if that would exceed
+    /// `snapshot_config.retention`. Call this on a timer (e.g. every
+    /// `snapshot_config.interval`) to build up a time series for dashboards
+    /// without serializing every raw `CostRecord`.
+    pub fn snapshot(&self) -> CostSnapshot {
+        let daily_cost = self.get_daily_cost(None);
+        let monthly_cost = self.get_monthly_cost(None);
+
+        let snapshot = CostSnapshot {
+            timestamp: Utc::now(),
+            daily_cost_usd: daily_cost,
+            monthly_cost_usd: monthly_cost,
+            daily_budget_utilization_percent: self.budget.utilization_daily(daily_cost),
+            monthly_budget_utilization_percent: self.budget.utilization_monthly(monthly_cost),
+            by_model: self.aggregate_by_model(Duration::days(1)),
+            by_user: self.aggregate_by_user(Duration::days(1)),
+        };
+
+        let mut history = self.snapshot_history.lock().unwrap();
+        history.push_back(snapshot.clone());
+        while history.len() > self.snapshot_config.retention {
+            history.pop_front();
+        }
+
+        snapshot
+    }
+
+    /// All retained snapshots, oldest first.
+    pub fn snapshot_history(&self) -> Vec<CostSnapshot> {
+        self.snapshot_history.lock().unwrap().iter().cloned().collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Timelike;
 
     #[test]
     fn test_model_pricing_calculation() {
@@ -746,6 +1298,29 @@ mod tests {
         assert!((cost - 0.025).abs() < 0.001);
     }
 
+    #[test]
+    fn test_usd_cents_roundtrips_through_f64() {
+        assert_eq!(UsdCents::from_usd(0.0).to_usd(), 0.0);
+        assert_eq!(UsdCents::from_usd(5.5), UsdCents::from_usd(5.5));
+        assert!((UsdCents::from_usd(0.000001).to_usd() - 0.000001).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_usd_cents_sums_a_million_sub_cent_costs_exactly() {
+        // Each cost is a tenth of a cent ($0.001); summing a million of them
+        // as raw f64 drifts away from the exact total, but fixed-point
+        // summation does not.
+        let per_record = 0.001;
+        let expected = UsdCents::from_usd(per_record * 1_000_000.0);
+
+        let total: UsdCents = (0..1_000_000)
+            .map(|_| UsdCents::from_usd(per_record))
+            .sum();
+
+        assert_eq!(total, expected);
+        assert_eq!(total.to_usd(), 1000.0);
+    }
+
     #[test]
     fn test_budget_limits() {
         let budget = CostBudget::builder()
@@ -786,8 +1361,449 @@ mod tests {
         stats.add_record(&record);
 
         assert_eq!(stats.total_requests, 1);
-        assert!((stats.total_cost - 0.045).abs() < 0.001);
+        assert_eq!(stats.total_cost, UsdCents::from_usd(0.045));
         assert_eq!(stats.total_input_tokens, 1000);
         assert_eq!(stats.total_output_tokens, 500);
     }
+
+    fn tracker_with_records(records: Vec<CostRecord>) -> CostTracker {
+        tracker_with_budget(CostBudget::builder().build(), records)
+    }
+
+    fn tracker_with_budget(budget: CostBudget, records: Vec<CostRecord>) -> CostTracker {
+        CostTracker {
+            pricing: HashMap::new(),
+            budget,
+            records,
+            compacted: HashMap::new(),
+            alert_dispatcher: AlertDispatcher::new(),
+            snapshot_config: SnapshotConfig::default(),
+            snapshot_history: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    #[test]
+    fn test_cost_time_series_zero_fills_empty_buckets() {
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            1.0,
+        );
+        record.timestamp = Utc::now() - Duration::hours(1);
+        let tracker = tracker_with_records(vec![record]);
+
+        let series = tracker.cost_time_series(Duration::hours(1), Duration::hours(4));
+
+        // Aligning the window start down to an hour boundary can add one
+        // extra leading bucket depending on where "now" falls within the
+        // current hour.
+        assert!(series.len() == 4 || series.len() == 5, "got {} buckets", series.len());
+        let total: f64 = series.iter().map(|(_, cost)| cost).sum();
+        assert!((total - 1.0).abs() < 0.001);
+        assert_eq!(series.iter().filter(|(_, cost)| *cost > 0.0).count(), 1);
+    }
+
+    #[test]
+    fn test_cost_time_series_sorted_ascending_and_aligned() {
+        let tracker = tracker_with_records(Vec::new());
+
+        let series = tracker.cost_time_series(Duration::hours(1), Duration::hours(3));
+
+        for (timestamp, _) in &series {
+            assert_eq!(timestamp.timestamp() % 3600, 0, "bucket should start on the hour");
+        }
+        for window in series.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+    }
+
+    #[test]
+    fn test_user_budget_status_rolling_tracks_remaining_and_reset() {
+        let budget = CostBudget::builder()
+            .per_user_daily_limit(10.0)
+            .per_user_monthly_limit(100.0)
+            .reset_period(BudgetResetPeriod::Rolling)
+            .build();
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            4.0,
+        );
+        record.timestamp = Utc::now() - Duration::hours(2);
+        let tracker = tracker_with_budget(budget, vec![record]);
+
+        let status = tracker.user_budget_status("user1");
+
+        assert!((status.daily_cost - 4.0).abs() < 0.001);
+        assert!((status.daily_remaining - 6.0).abs() < 0.001);
+        assert!(status.seconds_until_daily_reset > 0);
+        assert!((status.monthly_cost - 4.0).abs() < 0.001);
+        assert!(status.seconds_until_monthly_reset > status.seconds_until_daily_reset);
+    }
+
+    #[test]
+    fn test_user_budget_status_calendar_reset_is_midnight_utc() {
+        let budget = CostBudget::builder()
+            .reset_period(BudgetResetPeriod::Calendar)
+            .build();
+        let tracker = tracker_with_budget(budget, Vec::new());
+
+        let status = tracker.user_budget_status("user1");
+        // `seconds_until_daily_reset` is floored, so adding it back lands
+        // up to a second short of the exact boundary; round up to compare.
+        let reset_at = Utc::now() + Duration::seconds(status.seconds_until_daily_reset + 1);
+
+        assert_eq!(reset_at.hour(), 0);
+        assert_eq!(reset_at.minute(), 0);
+    }
+
+    #[test]
+    fn test_user_budget_status_ignores_other_users() {
+        let mut record = CostRecord::new(
+            "other-user".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            9.0,
+        );
+        record.timestamp = Utc::now();
+        let tracker = tracker_with_records(vec![record]);
+
+        let status = tracker.user_budget_status("user1");
+        assert_eq!(status.daily_cost, 0.0);
+    }
+
+    #[test]
+    fn test_compact_collapses_old_records_and_keeps_total() {
+        let mut old_record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            5.0,
+        );
+        old_record.timestamp = Utc::now() - Duration::days(10);
+        let mut recent_record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            100,
+            50,
+            0.5,
+        );
+        recent_record.timestamp = Utc::now();
+        let mut tracker = tracker_with_records(vec![old_record, recent_record]);
+
+        tracker.compact(Duration::days(7));
+
+        assert_eq!(tracker.records.len(), 1, "recent record should stay live");
+        assert_eq!(tracker.compacted.len(), 1, "old record should be bucketed by day");
+
+        let report = tracker
+            .generate_report(None, None, Duration::days(30))
+            .expect("report should succeed");
+        assert_eq!(report.overall_stats.total_cost, UsdCents::from_usd(5.5));
+        assert_eq!(report.overall_stats.total_requests, 2);
+    }
+
+    #[test]
+    fn test_compact_excluded_from_filtered_reports() {
+        let mut old_record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            5.0,
+        );
+        old_record.timestamp = Utc::now() - Duration::days(10);
+        let mut tracker = tracker_with_records(vec![old_record]);
+        tracker.compact(Duration::days(7));
+
+        // A per-user report can't attribute a compacted bucket to anyone,
+        // so it should only see live records (none, here).
+        let report = tracker
+            .generate_report(Some("user1"), None, Duration::days(30))
+            .expect("report should succeed");
+        assert_eq!(report.overall_stats.total_requests, 0);
+    }
+
+    #[test]
+    fn test_persist_and_load_round_trips_records_and_compacted_buckets() {
+        let mut old_record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            5.0,
+        );
+        old_record.timestamp = Utc::now() - Duration::days(10);
+        let mut recent_record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            100,
+            50,
+            0.5,
+        );
+        recent_record.timestamp = Utc::now();
+        let mut tracker = tracker_with_records(vec![old_record, recent_record]);
+        tracker.compact(Duration::days(7));
+
+        let path = std::env::temp_dir().join(format!(
+            "cost-tracking-test-{}.json",
+            std::process::id()
+        ));
+        tracker.persist(&path).expect("persist should succeed");
+
+        let mut loaded = tracker_with_records(Vec::new());
+        loaded.load(&path).expect("load should succeed");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.records.len(), tracker.records.len());
+        assert_eq!(loaded.compacted.len(), tracker.compacted.len());
+        let report = loaded
+            .generate_report(None, None, Duration::days(30))
+            .expect("report should succeed");
+        assert_eq!(report.overall_stats.total_cost, UsdCents::from_usd(5.5));
+    }
+
+    #[test]
+    fn test_snapshot_captures_current_totals_and_utilization() {
+        let budget = CostBudget::builder().daily_limit(10.0).monthly_limit(100.0).build();
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            5.0,
+        );
+        record.timestamp = Utc::now();
+        let tracker = tracker_with_budget(budget, vec![record]);
+
+        let snapshot = tracker.snapshot();
+
+        assert!((snapshot.daily_cost_usd - 5.0).abs() < 0.001);
+        assert!((snapshot.daily_budget_utilization_percent - 50.0).abs() < 0.001);
+        assert_eq!(snapshot.by_model.get("gpt-4").copied(), Some(5.0));
+        assert_eq!(snapshot.by_user.get("user1").copied(), Some(5.0));
+        let history = tracker.snapshot_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].timestamp, snapshot.timestamp);
+    }
+
+    #[test]
+    fn test_snapshot_history_is_a_ring_buffer_bounded_by_retention() {
+        let mut tracker = tracker_with_records(Vec::new());
+        tracker.set_snapshot_config(SnapshotConfig {
+            interval: Duration::minutes(1),
+            retention: 3,
+        });
+
+        for _ in 0..5 {
+            tracker.snapshot();
+        }
+
+        let history = tracker.snapshot_history();
+        assert_eq!(history.len(), 3, "oldest snapshots should be evicted past retention");
+        for window in history.windows(2) {
+            assert!(window[0].timestamp <= window[1].timestamp, "history should stay oldest-first");
+        }
+    }
+
+    #[test]
+    fn test_set_snapshot_config_trims_existing_history_to_new_retention() {
+        let mut tracker = tracker_with_records(Vec::new());
+        for _ in 0..5 {
+            tracker.snapshot();
+        }
+        assert_eq!(tracker.snapshot_history().len(), 5);
+
+        tracker.set_snapshot_config(SnapshotConfig {
+            interval: Duration::minutes(1),
+            retention: 2,
+        });
+
+        assert_eq!(tracker.snapshot_history().len(), 2);
+    }
+
+    fn tracker_with_pricing(
+        pricing: HashMap<String, ModelPricing>,
+        records: Vec<CostRecord>,
+    ) -> CostTracker {
+        CostTracker {
+            pricing,
+            budget: CostBudget::builder().build(),
+            records,
+            compacted: HashMap::new(),
+            alert_dispatcher: AlertDispatcher::new(),
+            snapshot_config: SnapshotConfig::default(),
+            snapshot_history: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    #[test]
+    fn test_quality_aware_recommendation_prefers_cheaper_equal_quality_model() {
+        let mut pricing = HashMap::new();
+        pricing.insert("gpt-4".to_string(), ModelPricing::new(0.03, 0.06, 8192));
+        pricing.insert("gpt-4-turbo".to_string(), ModelPricing::new(0.01, 0.03, 128000));
+
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            100.0,
+        );
+        record.timestamp = Utc::now();
+        let tracker = tracker_with_pricing(pricing, vec![record]);
+
+        let mut quality_scores = HashMap::new();
+        quality_scores.insert("gpt-4".to_string(), 0.95);
+        quality_scores.insert("gpt-4-turbo".to_string(), 0.94);
+
+        let report = tracker
+            .generate_report_with_quality(None, None, Duration::days(30), &quality_scores, 0.05)
+            .expect("report should succeed");
+
+        assert!(
+            report.recommendations.iter().any(|r| r.contains("gpt-4-turbo") && r.contains("savings")),
+            "expected a quality-aware downgrade recommendation, got {:?}",
+            report.recommendations
+        );
+    }
+
+    #[test]
+    fn test_quality_aware_recommendation_skips_model_below_tolerance() {
+        let mut pricing = HashMap::new();
+        pricing.insert("gpt-4".to_string(), ModelPricing::new(0.03, 0.06, 8192));
+        pricing.insert("gpt-3.5-turbo".to_string(), ModelPricing::new(0.0005, 0.0015, 16385));
+
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            100.0,
+        );
+        record.timestamp = Utc::now();
+        let tracker = tracker_with_pricing(pricing, vec![record]);
+
+        let mut quality_scores = HashMap::new();
+        quality_scores.insert("gpt-4".to_string(), 0.95);
+        quality_scores.insert("gpt-3.5-turbo".to_string(), 0.60);
+
+        let report = tracker
+            .generate_report_with_quality(None, None, Duration::days(30), &quality_scores, 0.05)
+            .expect("report should succeed");
+
+        assert!(
+            !report.recommendations.iter().any(|r| r.contains("gpt-3.5-turbo")),
+            "should not recommend a model whose quality is far below tolerance, got {:?}",
+            report.recommendations
+        );
+    }
+
+    #[test]
+    fn test_generate_report_without_quality_labels_cost_only_heuristic() {
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            100.0,
+        );
+        record.timestamp = Utc::now();
+        let tracker = tracker_with_records(vec![record]);
+
+        let report = tracker
+            .generate_report(None, None, Duration::days(30))
+            .expect("report should succeed");
+
+        assert!(
+            report.recommendations.iter().any(|r| r.contains("cost-only heuristic")),
+            "expected the fallback recommendation to label itself, got {:?}",
+            report.recommendations
+        );
+    }
+
+    #[test]
+    fn test_alert_dispatch_runs_off_request_path() {
+        let budget = CostBudget::builder()
+            .daily_limit(10.0)
+            .alert_threshold(0.5)
+            .build();
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            6.0,
+        );
+        record.timestamp = Utc::now();
+        let mut tracker = tracker_with_budget(budget, vec![record]);
+
+        let (tx, rx) = mpsc::channel();
+        tracker.on_alert(move |alert| {
+            tx.send(alert.level).unwrap();
+        });
+
+        tracker.check_budget("user1").expect("budget should not be exceeded yet");
+
+        let level = rx
+            .recv_timeout(std::time::Duration::from_secs(1))
+            .expect("alert handler should run on the background dispatch thread");
+        assert_eq!(level, AlertLevel::Warning);
+    }
+
+    #[test]
+    fn test_alert_dispatch_deduplicates_within_cooldown() {
+        let budget = CostBudget::builder()
+            .daily_limit(10.0)
+            .alert_threshold(0.5)
+            .build();
+        let mut record = CostRecord::new(
+            "user1".to_string(),
+            "api/chat".to_string(),
+            "gpt-4".to_string(),
+            1000,
+            500,
+            6.0,
+        );
+        record.timestamp = Utc::now();
+        let mut tracker = tracker_with_budget(budget, vec![record]);
+        tracker.set_alert_cooldown(Duration::minutes(5));
+
+        let (tx, rx) = mpsc::channel();
+        tracker.on_alert(move |_| {
+            tx.send(()).unwrap();
+        });
+
+        tracker.check_budget("user1").unwrap();
+        tracker.check_budget("user1").unwrap();
+        tracker.check_budget("user1").unwrap();
+
+        assert!(
+            rx.recv_timeout(std::time::Duration::from_secs(1)).is_ok(),
+            "the first alert should still fire"
+        );
+        assert!(
+            rx.recv_timeout(std::time::Duration::from_millis(100)).is_err(),
+            "repeated alerts for the same threshold within the cooldown should be deduplicated"
+        );
+    }
 }
@@ -299,7 +299,7 @@ fn demo_user_analysis(tracker: &CostTracker) -> Result<()> {
             Duration::days(1),
         )?;
 
-        println!("  Total Cost: ${:.4}", report.overall_stats.total_cost);
+        println!("  Total Cost: ${:.4}", report.overall_stats.total_cost_usd());
         println!("  Requests: {}", report.overall_stats.total_requests);
         println!("  Avg Cost/Request: ${:.4}", report.overall_stats.average_cost_per_request);
         println!("  Total Tokens: {}",
@@ -67,6 +67,8 @@ struct Counters {
     cache_misses: CounterVec,
     /// Total number of errors
     errors: CounterVec,
+    /// Total number of tokens processed
+    tokens: CounterVec,
 }
 
 impl Counters {
@@ -99,16 +101,25 @@ impl Counters {
         )
         .context("Failed to create errors counter")?;
 
+        let tokens = CounterVec::new(
+            Opts::new("dspy_tokens_total", "Total number of tokens processed")
+                .const_label("service", service),
+            &["prediction_type", "direction"],
+        )
+        .context("Failed to create tokens counter")?;
+
         REGISTRY.register(Box::new(predictions.clone()))?;
         REGISTRY.register(Box::new(cache_hits.clone()))?;
         REGISTRY.register(Box::new(cache_misses.clone()))?;
         REGISTRY.register(Box::new(errors.clone()))?;
+        REGISTRY.register(Box::new(tokens.clone()))?;
 
         Ok(Self {
             predictions,
             cache_hits,
             cache_misses,
             errors,
+            tokens,
         })
     }
 }
@@ -156,6 +167,8 @@ struct Histograms {
     prediction_duration: HistogramVec,
     /// API request latency in seconds
     api_latency: HistogramVec,
+    /// Output tokens per prediction
+    output_tokens: HistogramVec,
 }
 
 impl Histograms {
@@ -188,12 +201,30 @@ impl Histograms {
         )
         .context("Failed to create api_latency histogram")?;
 
+        // Buckets for output tokens per prediction (1 to ~8k tokens)
+        let output_tokens_buckets = vec![
+            1.0, 8.0, 32.0, 128.0, 512.0, 1024.0, 2048.0, 4096.0, 8192.0,
+        ];
+
+        let output_tokens = HistogramVec::new(
+            HistogramOpts::new(
+                "dspy_output_tokens",
+                "Output tokens per prediction",
+            )
+            .const_label("service", service)
+            .buckets(output_tokens_buckets),
+            &["prediction_type"],
+        )
+        .context("Failed to create output_tokens histogram")?;
+
         REGISTRY.register(Box::new(prediction_duration.clone()))?;
         REGISTRY.register(Box::new(api_latency.clone()))?;
+        REGISTRY.register(Box::new(output_tokens.clone()))?;
 
         Ok(Self {
             prediction_duration,
             api_latency,
+            output_tokens,
         })
     }
 }
@@ -212,6 +243,7 @@ pub struct DSpyMetrics {
     counters: Arc<Counters>,
     gauges: Arc<Gauges>,
     histograms: Arc<Histograms>,
+    route_normalizer: RouteNormalizer,
 }
 
 impl DSpyMetrics {
@@ -238,9 +270,18 @@ impl DSpyMetrics {
             counters,
             gauges,
             histograms,
+            route_normalizer: RouteNormalizer::default(),
         })
     }
 
+    /// Override the route normalizer `MetricsMiddleware::track_request` uses
+    /// to bound the `endpoint` label's cardinality. See [`RouteNormalizer`]
+    /// for how to register custom patterns.
+    pub fn with_route_normalizer(mut self, normalizer: RouteNormalizer) -> Self {
+        self.route_normalizer = normalizer;
+        self
+    }
+
     // ------------------------------------------------------------------------
     // Counter Operations
     // ------------------------------------------------------------------------
@@ -325,6 +366,40 @@ impl DSpyMetrics {
         self.counters.errors.with_label_values(&[error_type]).inc();
     }
 
+    /// Record tokens processed by a prediction
+    ///
+    /// Increments `dspy_tokens_total{prediction_type, direction}` for both the
+    /// input and output token counts, and observes the output token count in
+    /// the `dspy_output_tokens` histogram.
+    ///
+    /// # Arguments
+    ///
+    /// * `prediction_type` - Type of prediction (e.g., "cot", "react")
+    /// * `input` - Number of input (prompt) tokens
+    /// * `output` - Number of output (completion) tokens
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use prometheus_metrics::DSpyMetrics;
+    /// # let metrics = DSpyMetrics::new("test").unwrap();
+    /// metrics.record_tokens("cot", 120, 48);
+    /// ```
+    pub fn record_tokens(&self, prediction_type: &str, input: u64, output: u64) {
+        self.counters
+            .tokens
+            .with_label_values(&[prediction_type, "input"])
+            .inc_by(input as f64);
+        self.counters
+            .tokens
+            .with_label_values(&[prediction_type, "output"])
+            .inc_by(output as f64);
+        self.histograms
+            .output_tokens
+            .with_label_values(&[prediction_type])
+            .observe(output as f64);
+    }
+
     // ------------------------------------------------------------------------
     // Gauge Operations
     // ------------------------------------------------------------------------
@@ -545,6 +620,112 @@ impl ApiTimer {
     }
 }
 
+// ============================================================================
+// Route Normalization
+// ============================================================================
+
+/// A single normalization rule: if a path segment `matches`, it's replaced
+/// with `placeholder` before the path is used as a metrics label.
+#[derive(Clone, Copy)]
+struct SegmentPattern {
+    matches: fn(&str) -> bool,
+    placeholder: &'static str,
+}
+
+/// Collapses high-cardinality path segments (numeric IDs, UUIDs, ...) into
+/// bounded placeholders before a path is used as the `endpoint` label on
+/// [`DSpyMetrics`]'s latency histograms — without this, one histogram series
+/// per distinct ID would make cardinality grow without bound.
+///
+/// The default (`RouteNormalizer::default()`, also what `DSpyMetrics::new`
+/// starts with) replaces purely-numeric segments and UUID-shaped segments
+/// (`8-4-4-4-12` hex groups) with `:id`, so `/predict/42` and
+/// `/predict/9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d` both normalize to
+/// `/predict/:id`.
+///
+/// Register additional patterns for application-specific identifiers (e.g.
+/// order numbers, slugs) with [`with_pattern`](Self::with_pattern):
+///
+/// ```
+/// use prometheus_metrics::{DSpyMetrics, RouteNormalizer};
+///
+/// // Collapse segments like "ord_4f2c" to ":order_id"
+/// let normalizer = RouteNormalizer::default()
+///     .with_pattern(|segment| segment.starts_with("ord_"), ":order_id");
+///
+/// let metrics = DSpyMetrics::new("my_service")
+///     .unwrap()
+///     .with_route_normalizer(normalizer);
+/// ```
+///
+/// Patterns are tried in registration order, with the default numeric/UUID
+/// patterns tried first; the first match wins.
+#[derive(Clone)]
+pub struct RouteNormalizer {
+    patterns: Arc<Vec<SegmentPattern>>,
+}
+
+impl RouteNormalizer {
+    /// A normalizer with no patterns; every path is left unchanged
+    pub fn new() -> Self {
+        Self {
+            patterns: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Register a custom pattern: any segment for which `matches` returns
+    /// `true` is replaced with `placeholder`
+    pub fn with_pattern(mut self, matches: fn(&str) -> bool, placeholder: &'static str) -> Self {
+        let mut patterns = (*self.patterns).clone();
+        patterns.push(SegmentPattern { matches, placeholder });
+        self.patterns = Arc::new(patterns);
+        self
+    }
+
+    /// Normalize a path by replacing each matching segment with its
+    /// pattern's placeholder
+    pub fn normalize(&self, path: &str) -> String {
+        path.split('/')
+            .map(|segment| {
+                if segment.is_empty() {
+                    return segment;
+                }
+                self.patterns
+                    .iter()
+                    .find(|pattern| (pattern.matches)(segment))
+                    .map(|pattern| pattern.placeholder)
+                    .unwrap_or(segment)
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+impl Default for RouteNormalizer {
+    /// Collapses numeric and UUID-shaped segments to `:id`
+    fn default() -> Self {
+        Self::new()
+            .with_pattern(is_numeric_segment, ":id")
+            .with_pattern(is_uuid_segment, ":id")
+    }
+}
+
+fn is_numeric_segment(segment: &str) -> bool {
+    !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Matches the canonical `8-4-4-4-12` hex-group UUID shape
+fn is_uuid_segment(segment: &str) -> bool {
+    let groups: Vec<&str> = segment.split('-').collect();
+    let expected_lens = [8, 4, 4, 4, 12];
+
+    groups.len() == expected_lens.len()
+        && groups
+            .iter()
+            .zip(expected_lens)
+            .all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
 // ============================================================================
 // Middleware
 // ============================================================================
@@ -586,7 +767,7 @@ impl MetricsMiddleware {
     ) -> Response {
         let start = Instant::now();
         let method = req.method().to_string();
-        let path = req.uri().path().to_string();
+        let path = metrics.route_normalizer.normalize(req.uri().path());
 
         // Process request
         let response = next.run(req).await;
@@ -633,6 +814,58 @@ pub async fn metrics_handler(State(metrics): State<DSpyMetrics>) -> impl IntoRes
     }
 }
 
+// ============================================================================
+// MetricsHub
+// ============================================================================
+
+/// Combines Prometheus registries from multiple, otherwise-independent
+/// metric sources (e.g. `DSpyMetrics`'s global [`REGISTRY`], `AgentMetrics`'s
+/// per-instance registry, `PerformanceMonitor::registry()`) so a single
+/// process running several of them can expose one combined `/metrics`
+/// scrape endpoint instead of one per source.
+#[derive(Default)]
+pub struct MetricsHub {
+    registries: Vec<Registry>,
+}
+
+impl MetricsHub {
+    /// Create an empty hub with no adopted registries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adopts `registry` into this hub's combined output.
+    pub fn add_registry(&mut self, registry: Registry) {
+        self.registries.push(registry);
+    }
+
+    /// Gathers every adopted registry's metric families into one combined
+    /// Prometheus text-exposition payload.
+    ///
+    /// Families with the same name (e.g. the process-collector metrics each
+    /// registry may separately carry) are de-duplicated, keeping the first
+    /// copy encountered in registration order.
+    pub fn gather(&self) -> Result<String> {
+        let mut seen = std::collections::HashSet::new();
+        let mut combined = Vec::new();
+
+        for registry in &self.registries {
+            for family in registry.gather() {
+                if seen.insert(family.get_name().to_string()) {
+                    combined.push(family);
+                }
+            }
+        }
+
+        let encoder = TextEncoder::new();
+        let mut buffer = Vec::new();
+        encoder
+            .encode(&combined, &mut buffer)
+            .context("Failed to encode combined metrics")?;
+        String::from_utf8(buffer).context("Failed to convert combined metrics to UTF-8")
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -647,6 +880,69 @@ mod tests {
         assert_eq!(metrics.service_name(), "test_service");
     }
 
+    #[test]
+    fn test_route_normalizer_default_patterns() {
+        let normalizer = RouteNormalizer::default();
+
+        assert_eq!(normalizer.normalize("/predict/abc123"), "/predict/abc123");
+        assert_eq!(normalizer.normalize("/predict/12345"), "/predict/:id");
+        assert_eq!(
+            normalizer.normalize("/predict/9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d"),
+            "/predict/:id"
+        );
+        assert_eq!(
+            normalizer.normalize("/users/42/orders/99"),
+            "/users/:id/orders/:id"
+        );
+        assert_eq!(normalizer.normalize("/predict"), "/predict");
+        assert_eq!(normalizer.normalize("/"), "/");
+        // Not a valid UUID shape (wrong group lengths) - left alone
+        assert_eq!(
+            normalizer.normalize("/predict/not-a-uuid-at-all"),
+            "/predict/not-a-uuid-at-all"
+        );
+    }
+
+    #[test]
+    fn test_route_normalizer_custom_pattern() {
+        let normalizer = RouteNormalizer::default()
+            .with_pattern(|segment| segment.starts_with("ord_"), ":order_id");
+
+        assert_eq!(
+            normalizer.normalize("/orders/ord_4f2c/items/7"),
+            "/orders/:order_id/items/:id"
+        );
+    }
+
+    #[test]
+    fn test_route_normalizer_bounds_endpoint_label_cardinality() {
+        let metrics = DSpyMetrics::new("test_normalization")
+            .unwrap()
+            .with_route_normalizer(RouteNormalizer::default());
+
+        for id in [111, 222, 333] {
+            let path = metrics.route_normalizer.normalize(&format!("/predict/{}", id));
+            metrics.record_api_latency(&path, "GET", "200", 0.01);
+        }
+
+        let output = metrics.gather().unwrap();
+        // All three ids normalize to the same label, so they collapse into a single
+        // time series (one `_count` line) with 3 accumulated observations, instead of
+        // three distinct high-cardinality series.
+        assert_eq!(
+            output
+                .matches(r#"dspy_api_latency_seconds_count{endpoint="/predict/:id""#)
+                .count(),
+            1
+        );
+        assert!(output.contains(
+            r#"dspy_api_latency_seconds_count{endpoint="/predict/:id",method="GET",service="test_normalization",status_code="200"} 3"#
+        ));
+        assert!(!output.contains(r#"endpoint="/predict/111""#));
+        assert!(!output.contains(r#"endpoint="/predict/222""#));
+        assert!(!output.contains(r#"endpoint="/predict/333""#));
+    }
+
     #[test]
     fn test_counter_operations() {
         let metrics = DSpyMetrics::new("test_counters").unwrap();
@@ -667,6 +963,25 @@ mod tests {
         assert!(output.contains("dspy_errors_total"));
     }
 
+    #[test]
+    fn test_record_tokens() {
+        let metrics = DSpyMetrics::new("test_tokens").unwrap();
+
+        metrics.record_tokens("cot", 120, 48);
+        metrics.record_tokens("cot", 80, 32);
+
+        let output = metrics.gather().unwrap();
+
+        assert!(output.contains("dspy_tokens_total"));
+        assert!(output.contains("dspy_output_tokens"));
+        assert!(output.contains(
+            r#"dspy_tokens_total{direction="input",prediction_type="cot",service="test_tokens"} 200"#
+        ));
+        assert!(output.contains(
+            r#"dspy_tokens_total{direction="output",prediction_type="cot",service="test_tokens"} 80"#
+        ));
+    }
+
     #[test]
     fn test_gauge_operations() {
         let metrics = DSpyMetrics::new("test_gauges").unwrap();
@@ -724,4 +1039,39 @@ mod tests {
         assert!(output.contains(r#"prediction_type="cot""#));
         assert!(output.contains(r#"cache_type="redis""#));
     }
+
+    fn counter_registry(name: &str) -> Registry {
+        let registry = Registry::new();
+        let counter = prometheus::Counter::new(name, "test counter").unwrap();
+        counter.inc();
+        registry.register(Box::new(counter)).unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_metrics_hub_combines_distinct_registries() {
+        let mut hub = MetricsHub::new();
+        hub.add_registry(counter_registry("hub_source_a_total"));
+        hub.add_registry(counter_registry("hub_source_b_total"));
+
+        let output = hub.gather().unwrap();
+        assert!(output.contains("hub_source_a_total"));
+        assert!(output.contains("hub_source_b_total"));
+    }
+
+    #[test]
+    fn test_metrics_hub_deduplicates_same_family_name() {
+        let mut hub = MetricsHub::new();
+        hub.add_registry(counter_registry("hub_duplicate_total"));
+        hub.add_registry(counter_registry("hub_duplicate_total"));
+
+        let output = hub.gather().unwrap();
+        assert_eq!(output.matches("hub_duplicate_total").count(), 3);
+    }
+
+    #[test]
+    fn test_metrics_hub_with_no_registries_is_empty() {
+        let hub = MetricsHub::new();
+        assert_eq!(hub.gather().unwrap(), "");
+    }
 }
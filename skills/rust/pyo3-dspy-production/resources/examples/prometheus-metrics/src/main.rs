@@ -297,6 +297,7 @@ async fn print_metrics_summary(metrics: &DSpyMetrics) {
             if line.contains("dspy_predictions_total")
                 || line.contains("dspy_cache_hits_total")
                 || line.contains("dspy_errors_total")
+                || line.contains("dspy_tokens_total")
             {
                 println!("  {}", line);
             }
@@ -312,6 +313,7 @@ async fn print_metrics_summary(metrics: &DSpyMetrics) {
             if line.contains("_count") || line.contains("_sum") {
                 if line.contains("dspy_prediction_duration_seconds")
                     || line.contains("dspy_api_latency_seconds")
+                    || line.contains("dspy_output_tokens")
                 {
                     println!("  {}", line);
                 }
@@ -7,11 +7,32 @@
 //! - Custom error messages and context
 //! - Using anyhow for error handling
 
+use pyo3::create_exception;
 use pyo3::prelude::*;
 use pyo3::exceptions::{
     PyValueError, PyTypeError, PyZeroDivisionError,
     PyIndexError, PyKeyError, PyRuntimeError
 };
+use pyo3::types::PyDict;
+
+// Structured validation error carrying a machine-readable `code` and an
+// optional `context` dict, on top of the usual message.
+//
+// Subclasses `ValueError` so existing `except ValueError` callers keep
+// working; callers that want to branch on failure kind can instead do
+// `except DspyError as e: e.code`.
+create_exception!(error_handling, DspyError, PyValueError);
+
+/// Builds a `DspyError` with the given `code` and `message`, attaching an
+/// optional `context` dict as an attribute for callers that want structured
+/// detail beyond the message text.
+fn dspy_error(py: Python, code: &str, message: &str, context: Option<&PyDict>) -> PyErr {
+    let err = DspyError::new_err(message.to_string());
+    let value = err.value(py);
+    let _ = value.setattr("code", code);
+    let _ = value.setattr("context", context);
+    err
+}
 
 /// Divides two numbers, handling division by zero.
 ///
@@ -101,15 +122,16 @@ fn get_at_index(items: Vec<String>, index: usize) -> PyResult<String> {
 ///     The validated age
 ///
 /// Raises:
-///     ValueError: If age is negative or unreasonably large
+///     DspyError (ValueError): If age is negative or unreasonably large;
+///         `e.code` is one of "NEGATIVE_AGE", "AGE_TOO_LARGE"
 ///     TypeError: If age is not an integer (handled by PyO3)
 #[pyfunction]
-fn validate_age(age: i32) -> PyResult<i32> {
+fn validate_age(py: Python, age: i32) -> PyResult<i32> {
     if age < 0 {
-        return Err(PyValueError::new_err("Age cannot be negative"));
+        return Err(dspy_error(py, "NEGATIVE_AGE", "Age cannot be negative", None));
     }
     if age > 150 {
-        return Err(PyValueError::new_err("Age seems unreasonably large"));
+        return Err(dspy_error(py, "AGE_TOO_LARGE", "Age seems unreasonably large", None));
     }
     Ok(age)
 }
@@ -125,33 +147,59 @@ fn validate_age(age: i32) -> PyResult<i32> {
 ///     Validated and trimmed value
 ///
 /// Raises:
-///     ValueError: If validation fails with detailed context
+///     DspyError (ValueError): If validation fails; `e.code` is one of
+///         "INVALID_RANGE", "STRING_TOO_SHORT", "STRING_TOO_LONG" and
+///         `e.context` holds the offending lengths
 #[pyfunction]
-fn validate_string(value: &str, min_length: usize, max_length: usize) -> PyResult<String> {
+fn validate_string(py: Python, value: &str, min_length: usize, max_length: usize) -> PyResult<String> {
     // Validation: min < max
     if min_length > max_length {
-        return Err(PyValueError::new_err(format!(
-            "Invalid range: min_length ({}) > max_length ({})",
-            min_length, max_length
-        )));
+        let context = PyDict::new(py);
+        context.set_item("min_length", min_length)?;
+        context.set_item("max_length", max_length)?;
+        return Err(dspy_error(
+            py,
+            "INVALID_RANGE",
+            &format!(
+                "Invalid range: min_length ({}) > max_length ({})",
+                min_length, max_length
+            ),
+            Some(context),
+        ));
     }
 
     let trimmed = value.trim();
 
     // Validation: minimum length
     if trimmed.len() < min_length {
-        return Err(PyValueError::new_err(format!(
-            "String too short: expected at least {} characters, got {}",
-            min_length, trimmed.len()
-        )));
+        let context = PyDict::new(py);
+        context.set_item("min_length", min_length)?;
+        context.set_item("actual_length", trimmed.len())?;
+        return Err(dspy_error(
+            py,
+            "STRING_TOO_SHORT",
+            &format!(
+                "String too short: expected at least {} characters, got {}",
+                min_length, trimmed.len()
+            ),
+            Some(context),
+        ));
     }
 
     // Validation: maximum length
     if trimmed.len() > max_length {
-        return Err(PyValueError::new_err(format!(
-            "String too long: expected at most {} characters, got {}",
-            max_length, trimmed.len()
-        )));
+        let context = PyDict::new(py);
+        context.set_item("max_length", max_length)?;
+        context.set_item("actual_length", trimmed.len())?;
+        return Err(dspy_error(
+            py,
+            "STRING_TOO_LONG",
+            &format!(
+                "String too long: expected at most {} characters, got {}",
+                max_length, trimmed.len()
+            ),
+            Some(context),
+        ));
     }
 
     Ok(trimmed.to_string())
@@ -256,7 +304,8 @@ fn process_value(py: Python, value: &PyAny) -> PyResult<f64> {
 }
 
 #[pymodule]
-fn error_handling(_py: Python, m: &PyModule) -> PyResult<()> {
+fn error_handling(py: Python, m: &PyModule) -> PyResult<()> {
+    m.add("DspyError", py.get_type::<DspyError>())?;
     m.add_function(wrap_pyfunction!(divide, m)?)?;
     m.add_function(wrap_pyfunction!(sqrt, m)?)?;
     m.add_function(wrap_pyfunction!(parse_int, m)?)?;